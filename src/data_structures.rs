@@ -0,0 +1,7 @@
+//! Module for data structures used by the algorithms of this crate.
+
+pub mod avl;
+pub mod trie;
+
+pub use avl::AvlTree;
+pub use trie::Trie;
@@ -0,0 +1,342 @@
+//! AVL tree module.
+//!
+//! A self-balancing binary search tree that allows repeated elements.
+
+use std::{cmp::Ordering, iter::FromIterator};
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value:  T,
+    height: usize,
+    left:   Link<T>,
+    right:  Link<T>,
+}
+
+/// A self-balancing binary search tree, where the heights of the two child subtrees of
+/// any node differ by at most one.
+///
+/// Repeated elements are allowed and are kept in insertion order, so iterating over the
+/// tree gives the elements sorted in a stable way.
+///
+/// |  Operation  | Time complexity |
+/// |:------------|:---------------:|
+/// | `insert`    | O(log(n))       |
+/// | `contains`  | O(log(n))       |
+/// | `min`/`max` | O(log(n))       |
+/// | `iter`      | O(n)            |
+///
+/// # Example
+/// ```rust
+/// use algos::data_structures::AvlTree;
+///
+/// let mut tree = AvlTree::new();
+/// tree.insert(5);
+/// tree.insert(1);
+/// tree.insert(3);
+///
+/// assert!(tree.contains(&3));
+/// assert_eq!(tree.min(), Some(&1));
+/// assert_eq!(tree.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AvlTree<T> {
+    root: Link<T>,
+    len:  usize,
+}
+
+impl<T> AvlTree<T> {
+    /// Creates a new empty tree.
+    pub const fn new() -> Self { AvlTree { root: None, len: 0 } }
+
+    /// Returns the number of elements in the tree.
+    pub fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the tree have no elements.
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns the height of the tree, `0` if it is empty.
+    pub fn height(&self) -> usize { height(&self.root) }
+
+    /// Inserts `value` in the tree, according to the [`Ordering`] returned by the
+    /// `compare` function. It is inserted after the elements equal to it.
+    ///
+    /// Every insertion in the same tree must use the same order.
+    ///
+    /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::data_structures::AvlTree;
+    ///
+    /// let mut tree = AvlTree::new();
+    /// for &word in &["pear", "fig", "banana"] {
+    ///     tree.insert_by(word, |a, b| a.len().cmp(&b.len()));
+    /// }
+    /// assert_eq!(tree.iter().copied().collect::<Vec<_>>(), vec!["fig", "pear", "banana"]);
+    /// ```
+    pub fn insert_by<F: FnMut(&T, &T) -> Ordering>(&mut self, value: T, mut compare: F) {
+        self.root = Some(insert(self.root.take(), value, &mut compare));
+        self.len += 1;
+    }
+
+    /// Returns the least element of the tree.
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = node.left.as_ref() {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns the greatest element of the tree.
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = node.right.as_ref() {
+            node = right;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns an iterator over the elements of the tree, in order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::with_capacity(self.height()) };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// Consumes the tree returning its elements in order.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut v = Vec::with_capacity(self.len);
+        into_vec(self.root, &mut v);
+        v
+    }
+}
+
+impl<T: Ord> AvlTree<T> {
+    /// Inserts `value` in the tree. It is inserted after the elements equal to it.
+    pub fn insert(&mut self, value: T) { self.insert_by(value, T::cmp) }
+
+    /// Returns `true` if the tree contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+}
+
+impl<T> Default for AvlTree<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Ord> FromIterator<T> for AvlTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for AvlTree<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AvlTree<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// In-order iterator over the elements of an [`AvlTree`].
+///
+/// [`AvlTree`]: ./struct.AvlTree.html
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+fn height<T>(link: &Link<T>) -> usize { link.as_ref().map_or(0, |node| node.height) }
+
+fn insert<T, F: FnMut(&T, &T) -> Ordering>(
+    link: Link<T>, value: T, compare: &mut F,
+) -> Box<Node<T>> {
+    match link {
+        None => Box::new(Node { value, height: 1, left: None, right: None }),
+        Some(mut node) => {
+            if compare(&value, &node.value) == Ordering::Less {
+                node.left = Some(insert(node.left.take(), value, compare));
+            } else {
+                node.right = Some(insert(node.right.take(), value, compare));
+            }
+            rebalance(node)
+        },
+    }
+}
+
+fn update_height<T>(node: &mut Node<T>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+}
+
+/// Rotates `node` to the left, its right child becomes the new root of the subtree.
+fn rotate_left<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    match node.right.take() {
+        Some(mut right) => {
+            node.right = right.left.take();
+            update_height(&mut node);
+            right.left = Some(node);
+            update_height(&mut right);
+            right
+        },
+        None => node,
+    }
+}
+
+/// Rotates `node` to the right, its left child becomes the new root of the subtree.
+fn rotate_right<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    match node.left.take() {
+        Some(mut left) => {
+            node.left = left.right.take();
+            update_height(&mut node);
+            left.right = Some(node);
+            update_height(&mut left);
+            left
+        },
+        None => node,
+    }
+}
+
+/// Restores the balance of `node`, where the heights of its children differ by at most
+/// two.
+fn rebalance<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    update_height(&mut node);
+    let (left, right) = (height(&node.left), height(&node.right));
+
+    if left > right + 1 {
+        if let Some(l) = node.left.take() {
+            // Left-right case.
+            let l = if height(&l.left) < height(&l.right) { rotate_left(l) } else { l };
+            node.left = Some(l);
+        }
+        rotate_right(node)
+    } else if right > left + 1 {
+        if let Some(r) = node.right.take() {
+            // Right-left case.
+            let r = if height(&r.right) < height(&r.left) { rotate_right(r) } else { r };
+            node.right = Some(r);
+        }
+        rotate_left(node)
+    } else {
+        node
+    }
+}
+
+fn into_vec<T>(link: Link<T>, v: &mut Vec<T>) {
+    if let Some(node) = link {
+        let Node { value, left, right, .. } = *node;
+        into_vec(left, v);
+        v.push(value);
+        into_vec(right, v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Checks the AVL invariants, returning the height of the subtree.
+    fn check<T: Ord>(link: &Link<T>) -> usize {
+        match link {
+            None => 0,
+            Some(node) => {
+                let (left, right) = (check(&node.left), check(&node.right));
+                assert!(left.max(right) - left.min(right) <= 1, "unbalanced node");
+                assert_eq!(node.height, 1 + left.max(right));
+                node.height
+            },
+        }
+    }
+
+    #[test]
+    fn insert_test() {
+        let tree: AvlTree<i32> = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12].iter().copied().collect();
+
+        assert_eq!(tree.len(), 11);
+        assert_eq!(tree.min(), Some(&3));
+        assert_eq!(tree.max(), Some(&99));
+        assert!(tree.contains(&23));
+        assert!(!tree.contains(&4));
+        assert_eq!(tree.iter().copied().collect::<Vec<_>>(), [
+            3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99
+        ]);
+        check(&tree.root);
+    }
+
+    #[test]
+    fn empty_test() {
+        let tree: AvlTree<i32> = AvlTree::default();
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.iter().next(), None);
+        assert!(tree.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn balance_test() {
+        // Sorted insertions make an unbalanced tree degenerate into a list.
+        let tree: AvlTree<u32> = (0..1000).collect();
+        check(&tree.root);
+        assert!(tree.height() <= 15);
+        assert_eq!(tree.into_sorted_vec(), (0..1000).collect::<Vec<_>>());
+
+        let mut rng = thread_rng();
+        let v: Vec<u32> = (0..1000).map(|_| rng.gen_range(0..100)).collect();
+        let tree: AvlTree<u32> = v.iter().copied().collect();
+        check(&tree.root);
+
+        let mut p = v;
+        p.sort();
+        assert_eq!(tree.into_sorted_vec(), p);
+    }
+
+    #[test]
+    fn stable_test() {
+        let mut tree = AvlTree::new();
+        for (i, &k) in [2, 1, 2, 1, 2].iter().enumerate() {
+            tree.insert_by((k, i), |a, b| a.0.cmp(&b.0));
+        }
+        assert_eq!(tree.into_sorted_vec(), [(1, 1), (1, 3), (2, 0), (2, 2), (2, 4)]);
+    }
+}
@@ -0,0 +1,355 @@
+//! Trie module.
+//!
+//! A prefix tree that maps byte strings to values.
+
+use std::{iter::FromIterator, slice};
+
+#[derive(Debug, Clone)]
+struct Node<V> {
+    value:    Option<V>,
+    /// The children by the byte of their edge, in crescent order of the bytes.
+    children: Vec<(u8, Box<Node<V>>)>,
+}
+
+impl<V> Node<V> {
+    const fn new() -> Self { Node { value: None, children: Vec::new() } }
+
+    fn child(&self, c: u8) -> Option<&Node<V>> {
+        let i = self.children.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+        Some(&self.children[i].1)
+    }
+
+    fn child_mut(&mut self, c: u8) -> Option<&mut Node<V>> {
+        let i = self.children.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+        Some(&mut self.children[i].1)
+    }
+}
+
+/// A prefix tree, where each node is a prefix of the keys and each edge is a byte, that
+/// maps byte strings to values.
+///
+/// Besides finding a key, it finds all the keys that start with a prefix, in crescent
+/// order, and the longest key that is a prefix of a byte string.
+///
+/// |      Operation     | Time complexity |
+/// |:-------------------|:---------------:|
+/// | `insert`           | O(m)            |
+/// | `get`              | O(m)            |
+/// | `remove`           | O(m)            |
+/// | `longest_prefix`   | O(m)            |
+/// | `iter_prefix`      | O(m + k)        |
+///
+/// Where `m` is the size of the key and `k` is the size of the keys found.
+///
+/// # Example
+/// ```rust
+/// use algos::data_structures::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert(b"car", 1);
+/// trie.insert(b"cart", 2);
+/// trie.insert(b"dog", 3);
+///
+/// assert_eq!(trie.get(b"car"), Some(&1));
+/// assert_eq!(trie.get(b"ca"), None);
+///
+/// let keys: Vec<_> = trie.iter_prefix(b"car").map(|(key, _)| key).collect();
+/// assert_eq!(keys, [b"car".to_vec(), b"cart".to_vec()]);
+///
+/// assert_eq!(trie.longest_prefix(b"cartoon"), Some((&b"cart"[..], &2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trie<V> {
+    root: Node<V>,
+    len:  usize,
+}
+
+impl<V> Trie<V> {
+    /// Creates a new empty trie.
+    pub const fn new() -> Self { Trie { root: Node::new(), len: 0 } }
+
+    /// Returns the number of keys in the trie.
+    pub fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the trie have no keys.
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Inserts `key` with `value` in the trie, returning the value it had before, if any.
+    pub fn insert(&mut self, key: &[u8], value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for &c in key {
+            let i = match node.children.binary_search_by_key(&c, |&(c, _)| c) {
+                Ok(i) => i,
+                Err(i) => {
+                    node.children.insert(i, (c, Box::new(Node::new())));
+                    i
+                },
+            };
+            node = &mut node.children[i].1;
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns the value of `key`, or `None` if it is not in the trie.
+    pub fn get(&self, key: &[u8]) -> Option<&V> { self.node(key)?.value.as_ref() }
+
+    /// Returns a mutable reference to the value of `key`, or `None` if it is not in the
+    /// trie.
+    pub fn get_mut(&mut self, key: &[u8]) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for &c in key {
+            node = node.child_mut(c)?;
+        }
+        node.value.as_mut()
+    }
+
+    /// Returns `true` if `key` is in the trie.
+    pub fn contains_key(&self, key: &[u8]) -> bool { self.get(key).is_some() }
+
+    /// Removes `key` from the trie, returning its value, or `None` if it was not in the
+    /// trie.
+    ///
+    /// The nodes left without keys below them are removed as well.
+    pub fn remove(&mut self, key: &[u8]) -> Option<V> {
+        let value = remove(&mut self.root, key)?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns the longest key in the trie that is a prefix of `text`, with its value, or
+    /// `None` if no key is a prefix of it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::data_structures::Trie;
+    ///
+    /// let routes: Trie<&str> =
+    ///     vec![(&b"/"[..], "root"), (b"/api", "api"), (b"/api/users", "users")]
+    ///         .into_iter()
+    ///         .collect();
+    ///
+    /// assert_eq!(routes.longest_prefix(b"/api/users/42"), Some((&b"/api/users"[..], &"users")));
+    /// assert_eq!(routes.longest_prefix(b"/apis"), Some((&b"/api"[..], &"api")));
+    /// assert_eq!(routes.longest_prefix(b"api"), None);
+    /// ```
+    pub fn longest_prefix<'t>(&self, text: &'t [u8]) -> Option<(&'t [u8], &V)> {
+        let mut node = &self.root;
+        let mut found = node.value.as_ref().map(|value| (0, value));
+        for (i, &c) in text.iter().enumerate() {
+            node = match node.child(c) {
+                Some(child) => child,
+                None => break,
+            };
+            if let Some(value) = &node.value {
+                found = Some((i + 1, value));
+            }
+        }
+        found.map(|(len, value)| (&text[..len], value))
+    }
+
+    /// Returns an iterator over the keys that start with `prefix` and their values, in
+    /// crescent order of the keys.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::data_structures::Trie;
+    ///
+    /// let words: Trie<()> = ["tea", "ten", "to", "inn"].iter().map(|w| (w, ())).collect();
+    ///
+    /// let completions: Vec<_> = words.iter_prefix(b"te").map(|(key, _)| key).collect();
+    /// assert_eq!(completions, [b"tea".to_vec(), b"ten".to_vec()]);
+    /// assert_eq!(words.iter_prefix(b"x").count(), 0);
+    /// ```
+    pub fn iter_prefix(&self, prefix: &[u8]) -> Iter<'_, V> {
+        Iter { key: prefix.to_vec(), root: self.node(prefix), stack: Vec::new() }
+    }
+
+    /// Returns an iterator over the keys and their values, in crescent order of the keys.
+    pub fn iter(&self) -> Iter<'_, V> { self.iter_prefix(&[]) }
+
+    /// Returns the node of `key`, even if it has no value.
+    fn node(&self, key: &[u8]) -> Option<&Node<V>> {
+        key.iter().try_fold(&self.root, |node, &c| node.child(c))
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<K: AsRef<[u8]>, V> Extend<(K, V)> for Trie<V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key.as_ref(), value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a Trie<V> {
+    type IntoIter = Iter<'a, V>;
+    type Item = (Vec<u8>, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// Iterator over the keys and values of a [`Trie`], in crescent order of the keys.
+///
+/// [`Trie`]: ./struct.Trie.html
+#[derive(Debug, Clone)]
+pub struct Iter<'a, V> {
+    /// The key of the last node visited.
+    key:   Vec<u8>,
+    /// The first node to visit, until it is visited.
+    root:  Option<&'a Node<V>>,
+    /// The children left to visit of each node in the path to the last node visited.
+    stack: Vec<slice::Iter<'a, (u8, Box<Node<V>>)>>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            self.stack.push(root.children.iter());
+            if let Some(value) = &root.value {
+                return Some((self.key.clone(), value));
+            }
+        }
+
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some((c, child)) => {
+                    self.key.push(*c);
+                    self.stack.push(child.children.iter());
+                    if let Some(value) = &child.value {
+                        return Some((self.key.clone(), value));
+                    }
+                },
+                None => {
+                    self.stack.pop();
+                    // The key of the first node is the prefix, that is kept.
+                    if !self.stack.is_empty() {
+                        self.key.pop();
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Removes `key` from the subtree of `node`, and the nodes below it left without keys.
+fn remove<V>(node: &mut Node<V>, key: &[u8]) -> Option<V> {
+    match key.split_first() {
+        None => node.value.take(),
+        Some((&c, rest)) => {
+            let i = node.children.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+            let child = &mut node.children[i].1;
+            let value = remove(child, rest)?;
+            if child.value.is_none() && child.children.is_empty() {
+                node.children.remove(i);
+            }
+            Some(value)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+    use std::collections::BTreeMap;
+
+    /// Checks that every leaf has a value, returning the number of values.
+    fn check<V>(node: &Node<V>) -> usize {
+        assert!(node.children.windows(2).all(|w| w[0].0 < w[1].0));
+        let below: usize = node.children.iter().map(|(_, child)| check(child)).sum();
+        assert!(node.children.is_empty() || below > 0);
+        below + node.value.is_some() as usize
+    }
+
+    #[test]
+    fn trie_test() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert_eq!(trie.insert(b"", 0), None);
+        assert_eq!(trie.insert(b"ab", 1), None);
+        assert_eq!(trie.insert(b"abc", 2), None);
+        assert_eq!(trie.insert(b"ab", 3), Some(1));
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.get(b""), Some(&0));
+        assert_eq!(trie.get(b"a"), None);
+        assert!(trie.contains_key(b"abc"));
+
+        *trie.get_mut(b"abc").unwrap() += 10;
+        assert_eq!(trie.get(b"abc"), Some(&12));
+        assert_eq!(trie.get_mut(b"abcd"), None);
+
+        assert_eq!(trie.remove(b"a"), None);
+        assert_eq!(trie.remove(b"abcd"), None);
+        assert_eq!(trie.remove(b"abc"), Some(12));
+        assert_eq!(trie.remove(b"abc"), None);
+        assert_eq!(trie.len(), 2);
+        assert_eq!(check(&trie.root), 2);
+        assert_eq!(trie.root.children[0].1.children[0].1.children.len(), 0);
+    }
+
+    #[test]
+    fn longest_prefix_test() {
+        let mut trie = Trie::new();
+        assert_eq!(trie.longest_prefix(b"abc"), None);
+        trie.insert(b"a", 1);
+        trie.insert(b"abcd", 2);
+        assert_eq!(trie.longest_prefix(b""), None);
+        assert_eq!(trie.longest_prefix(b"abc"), Some((&b"a"[..], &1)));
+        assert_eq!(trie.longest_prefix(b"abcde"), Some((&b"abcd"[..], &2)));
+        trie.insert(b"", 0);
+        assert_eq!(trie.longest_prefix(b"x"), Some((&b""[..], &0)));
+    }
+
+    #[test]
+    fn random_test() {
+        let mut rng = thread_rng();
+        let mut trie = Trie::new();
+        let mut map = BTreeMap::new();
+        let key = |rng: &mut rand::rngs::ThreadRng| -> Vec<u8> {
+            (0..rng.gen_range(0..6)).map(|_| rng.gen_range(b'a'..b'd')).collect()
+        };
+
+        for i in 0..2000 {
+            let k = key(&mut rng);
+            if rng.gen_bool(0.6) {
+                assert_eq!(trie.insert(&k, i), map.insert(k, i));
+            } else {
+                assert_eq!(trie.remove(&k), map.remove(&k));
+            }
+            assert_eq!(trie.len(), map.len());
+
+            let prefix = key(&mut rng);
+            let found: Vec<_> = trie.iter_prefix(&prefix).map(|(k, &v)| (k, v)).collect();
+            let expected: Vec<_> = map
+                .iter()
+                .filter(|(k, _)| k.starts_with(&prefix))
+                .map(|(k, &v)| (k.clone(), v))
+                .collect();
+            assert_eq!(found, expected);
+
+            let longest =
+                map.iter().filter(|(k, _)| prefix.starts_with(k)).max_by_key(|(k, _)| k.len());
+            assert_eq!(trie.longest_prefix(&prefix), longest.map(|(k, v)| (&prefix[..k.len()], v)));
+        }
+        assert_eq!(check(&trie.root), map.len());
+        assert!(trie.iter().map(|(k, _)| k).eq(map.keys().cloned()));
+    }
+}
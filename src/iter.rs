@@ -0,0 +1,134 @@
+//! A module for using the algorithms of this crate in iterator pipelines.
+
+use std::{cmp::Ordering, vec::IntoIter};
+
+use crate::sort::{self, Sorter};
+
+/// Extension trait to sort the items of any iterator.
+///
+/// Every method collects the items into a `Vec`, sorts it with one of the algorithms of
+/// the [`sort`] module and returns an iterator over the sorted items. The ones that don't
+/// take a [`Sorter`] use [`sort::merge`], so they are stable.
+///
+/// [`sort`]: ../sort/index.html
+/// [`Sorter`]: ../sort/trait.Sorter.html
+/// [`sort::merge`]: ../sort/fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::iter::SortedIteratorExt;
+///
+/// let words = ["banana", "Cherry", "apple"];
+/// let sorted: Vec<_> = words.iter().sorted_by_key(|w| w.to_lowercase()).collect();
+/// assert_eq!(sorted, [&"apple", &"banana", &"Cherry"]);
+/// ```
+pub trait SortedIteratorExt: Iterator + Sized {
+    /// Returns an iterator over the items in crescent order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::iter::SortedIteratorExt;
+    ///
+    /// let v: Vec<_> = vec![9, 3, 5, 7, 8, 7].into_iter().sorted().collect();
+    /// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+    /// ```
+    fn sorted(self) -> IntoIter<Self::Item>
+    where Self::Item: Ord {
+        self.sorted_by(Ord::cmp)
+    }
+
+    /// Returns an iterator over the items in the order of the [`Ordering`] returned by
+    /// the `compare` function.
+    ///
+    /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::iter::SortedIteratorExt;
+    ///
+    /// // Decrescent sorting
+    /// let v: Vec<_> = [9, 3, 5, 7, 8, 7].iter().sorted_by(|a, b| b.cmp(a)).collect();
+    /// assert_eq!(v, [&9, &8, &7, &7, &5, &3]);
+    /// ```
+    fn sorted_by<F>(self, compare: F) -> IntoIter<Self::Item>
+    where F: FnMut(&Self::Item, &Self::Item) -> Ordering {
+        let mut v: Vec<Self::Item> = self.collect();
+        sort::merge_by(&mut v, compare);
+        v.into_iter()
+    }
+
+    /// Returns an iterator over the items in the order of the keys extracted by the `key`
+    /// function.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::iter::SortedIteratorExt;
+    ///
+    /// let v = vec![-9, 3, -5, 7, 8, -7];
+    /// let sorted: Vec<i32> = v.into_iter().sorted_by_key(|a: &i32| a.abs()).collect();
+    /// assert_eq!(sorted, [3, -5, 7, -7, 8, -9]);
+    /// ```
+    fn sorted_by_key<K, F>(self, key: F) -> IntoIter<Self::Item>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v: Vec<Self::Item> = self.collect();
+        sort::merge_by_key(&mut v, key);
+        v.into_iter()
+    }
+
+    /// Returns an iterator over the items in crescent order, sorted with the `sorter`
+    /// algorithm.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::{iter::SortedIteratorExt, sort::Heap};
+    ///
+    /// let v: Vec<_> = "algos".chars().sorted_with(Heap).collect();
+    /// assert_eq!(v, ['a', 'g', 'l', 'o', 's']);
+    /// ```
+    fn sorted_with<S: Sorter>(self, sorter: S) -> IntoIter<Self::Item>
+    where Self::Item: Ord {
+        let mut v: Vec<Self::Item> = self.collect();
+        sorter.sort(&mut v);
+        v.into_iter()
+    }
+}
+
+impl<I: Iterator> SortedIteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::Algorithm;
+
+    #[test]
+    fn sorted_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        assert!(v.iter().copied().sorted().eq(p.iter().copied()));
+        for &algorithm in Algorithm::ALL {
+            assert!(v.iter().sorted_with(algorithm).eq(p.iter()), "{}", algorithm);
+        }
+    }
+
+    #[test]
+    fn sorted_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        let sorted: Vec<f64> = v.iter().copied().sorted_by(f64::total_cmp).collect();
+        assert_eq!(sorted, p);
+    }
+
+    #[test]
+    fn sorted_stable_test() {
+        let v = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')];
+        let p = [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (3, 'e')];
+
+        let sorted: Vec<_> = v.iter().copied().sorted_by_key(|x| x.0).collect();
+        assert_eq!(sorted, p);
+    }
+}
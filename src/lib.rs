@@ -4,15 +4,30 @@
 //!  - Several sort algorithms
 //!  - Some search algorithms
 //!  - Some pattern algorithms
+//!  - Some string algorithms
 //!  - Some numeric sequence algorithms
+//!  - Some combinatorial problems solvers
+//!  - Some data structures
+//!  - Iterator adapters to sort with the algorithms above
 //!
 //! ## Features
 //! This crate have a feature called `big_num` and it is active by default.
 //!
 //! In case your needs don't require using big numbers and you want to reduce the crate
 //! numbers to be compiled and the compile time, you can disactivate the default features.
+//!
+//! There is also a feature called `parallel`, not active by default, that adds sorting
+//! algorithms that run in multiple threads, using [`rayon`].
+//!
+//! The `simd` feature, also not active by default, adds a sort for slices of `i32`, `u32`
+//! and `f32` that uses vector instructions (AVX2) when the CPU supports them.
+//!
+//! [`rayon`]: https://docs.rs/rayon
 
+pub mod data_structures;
+pub mod iter;
 pub mod numerics;
 pub mod pattern;
 pub mod search;
 pub mod sort;
+pub mod strings;
@@ -1,8 +1,13 @@
 //! Module for numeric algorithms ans some iterators
 
+pub mod consecutive;
 #[cfg(feature = "big_num")]
 pub mod factorial;
 pub mod fibonacci;
+pub mod josephus;
+pub mod majority;
+pub mod matrix;
+pub mod numeric_key;
 pub mod prime;
 pub mod primorial;
 
@@ -14,4 +19,5 @@ pub use fibonacci::BigFib;
 pub use primorial::BigPrimorial;
 
 pub use fibonacci::Fib;
+pub use numeric_key::NumericKey;
 pub use prime::IsPrime;
@@ -0,0 +1,58 @@
+//! Consecutive sequences module.
+
+use std::collections::HashSet;
+
+/// Returns the length of the longest run of consecutive integers present in `a`, in any
+/// order.
+///
+/// Every number is put in a hash set and the counting only starts on the numbers that are
+/// the beginning of a run (i.e. the predecessor is not in the set), so each number is
+/// visited at most twice.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::consecutive::longest_consecutive;
+///
+/// assert_eq!(longest_consecutive(&[100, 4, 200, 1, 3, 2]), 4);
+/// ```
+pub fn longest_consecutive(a: &[i64]) -> usize {
+    let set: HashSet<i64> = a.iter().copied().collect();
+
+    set.iter()
+        .filter(|&&x| x == i64::MIN || !set.contains(&(x - 1)))
+        .map(|&start| {
+            let mut len = 1;
+            let mut current = start;
+            while current < i64::MAX && set.contains(&(current + 1)) {
+                current += 1;
+                len += 1;
+            }
+            len
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_consecutive_test() {
+        assert_eq!(4, longest_consecutive(&[100, 4, 200, 1, 3, 2]));
+        assert_eq!(9, longest_consecutive(&[0, 3, 7, 2, 5, 8, 4, 6, 0, 1]));
+        assert_eq!(1, longest_consecutive(&[42]));
+        assert_eq!(3, longest_consecutive(&[i64::MAX, i64::MAX - 1, i64::MAX - 2, i64::MIN]));
+    }
+
+    #[test]
+    fn longest_consecutive_empty_test() {
+        assert_eq!(0, longest_consecutive(&[]));
+    }
+}
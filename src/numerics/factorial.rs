@@ -42,6 +42,10 @@ impl BigFactorial {
     }
 }
 
+impl Default for BigFactorial {
+    fn default() -> Self { Self::new() }
+}
+
 impl Iterator for BigFactorial {
     type Item = BigUint;
 
@@ -58,6 +62,7 @@ impl Iterator for BigFactorial {
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
     use super::*;
 
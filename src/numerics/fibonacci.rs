@@ -160,7 +160,7 @@ const fn _fib(nth: u128) -> (u128, u128) {
         let c = a * (b * 2 - a);
         let d = a * a + b * b;
 
-        if nth % 2 == 0 { (c, d) } else { (d, c + d) }
+        if nth & 1 == 0 { (c, d) } else { (d, c + d) }
     }
 }
 
@@ -0,0 +1,118 @@
+//! Josephus problem.
+//!
+//! `n` people stand in a circle and, starting from the position zero, every `k`-th person
+//! is removed until only one remains. The functions in this module return the position
+//! (0-indexed) of the survivor.
+
+/// Return the 0-indexed position of the survivor of the Josephus problem with `n` people
+/// where every `k`-th person is removed, using the recurrence `J(1) = 0` and
+/// `J(i) = (J(i-1) + k) mod i`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            | Ω(1)             |
+/// | Avrg:     | Θ(n)            | Θ(1)             |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Panics
+/// This function panics if `n` or `k` is zero.
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::josephus::josephus;
+///
+/// assert_eq!(josephus(7, 3), 3);
+/// ```
+pub fn josephus(n: u64, k: u64) -> u64 {
+    assert!(n > 0 && k > 0, "`n` and `k` must be greater than zero");
+
+    (2..=n).fold(0, |survivor, i| (survivor + k % i) % i)
+}
+
+/// Return the 0-indexed position of the survivor of the Josephus problem with `n` people
+/// where every `k`-th person is removed.
+///
+/// Instead of removing one person per step, it removes `n / k` people per step, so it
+/// is much faster than [`josephus`] when `k` is small and `n` is huge.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(k·log(n))     | Ω(k·log(n))      |
+/// | Avrg:     | Θ(k·log(n))     | Θ(k·log(n))      |
+/// | Worst:    | O(k·log(n))     | O(k·log(n))      |
+///
+/// **Obs.:** The space complexity comes from the recursion depth.
+///
+/// # Panics
+/// This function panics if `n` or `k` is zero.
+///
+/// [`josephus`]: ./fn.josephus.html
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::josephus::josephus_small_k;
+///
+/// assert_eq!(josephus_small_k(7, 3), 3);
+/// assert_eq!(josephus_small_k(1_000_000_000_000, 2), 900_488_372_224);
+/// ```
+pub fn josephus_small_k(n: u64, k: u64) -> u64 {
+    assert!(n > 0 && k > 0, "`n` and `k` must be greater than zero");
+
+    match (n, k) {
+        (1, _) => 0,
+        (_, 1) => n - 1,
+        _ if k > n => (josephus_small_k(n - 1, k) + k) % n,
+        _ => {
+            // Remove every k-th person of a full lap at once, then map the survivor of the
+            // smaller circle back to the positions of the original circle.
+            let removed = n / k;
+            let survivor = josephus_small_k(n - removed, k);
+            let rest = n % k;
+
+            if survivor < rest {
+                survivor + n - rest
+            } else {
+                let survivor = survivor - rest;
+                survivor + survivor / (k - 1)
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Closed form for `k = 2`: `2 * (n - 2^⌊log2(n)⌋)`.
+    fn closed_form_k2(n: u64) -> u64 {
+        let highest_power = 1 << (63 - n.leading_zeros());
+        2 * (n - highest_power)
+    }
+
+    #[test]
+    fn josephus_test() {
+        assert_eq!(josephus(1, 3), 0);
+        assert_eq!(josephus(7, 3), 3);
+        assert_eq!(josephus(41, 3), 30);
+        assert_eq!(josephus(5, 1), 4);
+    }
+
+    #[test]
+    fn josephus_k2_test() {
+        for n in 1..200 {
+            assert_eq!(josephus(n, 2), closed_form_k2(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn josephus_small_k_test() {
+        assert_eq!(josephus_small_k(7, 3), 3);
+        assert_eq!(josephus_small_k(1_000_000_000_000, 2), closed_form_k2(1_000_000_000_000));
+
+        for n in 1..200 {
+            for k in 1..10 {
+                assert_eq!(josephus_small_k(n, k), josephus(n, k), "n = {}, k = {}", n, k);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Majority element module.
+//!
+//! Algorithms based on the Boyer-Moore majority vote algorithm.
+
+/// Returns the element that appears more than `n/2` times in `a`, if there is one.
+///
+/// It uses the Boyer-Moore majority vote algorithm followed by a verification pass.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::majority::majority;
+///
+/// assert_eq!(majority(&[2, 2, 1, 1, 1, 2, 2]), Some(2));
+/// assert_eq!(majority(&[1, 2, 3]), None);
+/// ```
+pub fn majority<T: Eq + Clone>(a: &[T]) -> Option<T> { majority_k(a, 2).pop() }
+
+/// Returns all elements that appear more than `n/k` times in `a`, in no particular order.
+///
+/// It uses the generalized Boyer-Moore majority vote algorithm (also known as
+/// Misra-Gries), keeping track of `k-1` candidates, followed by a verification pass,
+/// since there is at most `k-1` elements that can appear more than `n/k` times.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n·k)          |                  |
+/// | Avrg:     | Θ(n·k)          |                  |
+/// | Worst:    | O(n·k)          | O(k)             |
+///
+/// # Panics
+/// This function panics if `k` is less than 2.
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::majority::majority_k;
+///
+/// // Elements that appear more than a third of the time.
+/// let mut found = majority_k(&[1, 2, 3, 1, 2, 1, 2, 4], 3);
+/// found.sort();
+/// assert_eq!(found, vec![1, 2]);
+/// ```
+pub fn majority_k<T: Eq + Clone>(a: &[T], k: usize) -> Vec<T> {
+    assert!(k >= 2, "`k` must be at least 2");
+
+    // Candidate election.
+    let mut candidates: Vec<(&T, usize)> = Vec::with_capacity(k - 1);
+    for x in a {
+        if let Some(candidate) = candidates.iter_mut().find(|(c, _)| *c == x) {
+            candidate.1 += 1;
+        } else if candidates.len() < k - 1 {
+            candidates.push((x, 1));
+        } else {
+            // Decrement every candidate, removing the ones that reached zero.
+            candidates.iter_mut().for_each(|(_, count)| *count -= 1);
+            candidates.retain(|&(_, count)| count > 0);
+        }
+    }
+
+    // Verification.
+    candidates.iter_mut().for_each(|(_, count)| *count = 0);
+    for x in a {
+        if let Some(candidate) = candidates.iter_mut().find(|(c, _)| *c == x) {
+            candidate.1 += 1;
+        }
+    }
+
+    let threshold = a.len() / k;
+    candidates.into_iter().filter(|&(_, count)| count > threshold).map(|(c, _)| c.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn majority_test() {
+        assert_eq!(Some(3), majority(&[3, 2, 3]));
+        assert_eq!(Some('a'), majority(&['a', 'b', 'a', 'c', 'a']));
+        assert_eq!(None, majority(&[1, 1, 2, 2]));
+        assert_eq!(None::<i32>, majority(&[]));
+    }
+
+    #[test]
+    fn majority_k_test() {
+        let mut found = majority_k(&[1, 2, 3, 1, 2, 1, 2, 4], 3);
+        found.sort();
+        assert_eq!(vec![1, 2], found);
+
+        let mut found = majority_k(&[4, 4, 5, 5, 6, 6, 4, 5, 7], 3);
+        found.sort();
+        assert_eq!(Vec::<i32>::new(), found);
+
+        assert_eq!(vec![7], majority_k(&[7, 1, 7, 2, 7], 4));
+    }
+
+    #[test]
+    fn majority_k_none_test() {
+        assert!(majority_k(&[1, 2, 3, 4, 5, 6], 3).is_empty());
+        assert!(majority_k::<u8>(&[], 3).is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! Matrix module.
+//!
+//! A simple dense matrix type and some algorithms that operate over it.
+
+use std::ops::{Index, IndexMut};
+
+/// A dense matrix stored in row-major order.
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::matrix::Matrix;
+///
+/// let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// assert_eq!(m.rows(), 2);
+/// assert_eq!(m.cols(), 3);
+/// assert_eq!(m[(1, 0)], 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a new matrix with `rows` rows and `cols` columns from `data` in row-major
+    /// order.
+    ///
+    /// # Panics
+    /// This function panics if `data` length is different from `rows * cols`.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(rows * cols, data.len(), "`data` length must be `rows * cols`");
+        Matrix { rows, cols, data }
+    }
+
+    /// Creates a new matrix from a vector of rows.
+    ///
+    /// # Panics
+    /// This function panics if the rows don't have all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        let n_rows = rows.len();
+        let mut data = Vec::with_capacity(n_rows * cols);
+
+        for row in rows {
+            assert_eq!(row.len(), cols, "all rows must have the same length");
+            data.extend(row);
+        }
+
+        Matrix { rows: n_rows, cols, data }
+    }
+
+    /// Returns the number of rows of the matrix.
+    pub fn rows(&self) -> usize { self.rows }
+
+    /// Returns the number of columns of the matrix.
+    pub fn cols(&self) -> usize { self.cols }
+
+    /// Returns `true` if the matrix have the same number of rows and columns.
+    pub fn is_square(&self) -> bool { self.rows == self.cols }
+
+    /// Returns a reference to the element at `row` and `col`, or `None` if out of
+    /// bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols { self.data.get(row * self.cols + col) } else { None }
+    }
+
+    /// Returns the `row`-th row of the matrix as a slice.
+    ///
+    /// # Panics
+    /// This function panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] { &self.data[row * self.cols..(row + 1) * self.cols] }
+
+    /// Returns the underlying data in row-major order.
+    pub fn as_slice(&self) -> &[T] { &self.data }
+
+    /// Consumes the matrix returning the underlying data in row-major order.
+    pub fn into_vec(self) -> Vec<T> { self.data }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// Transpose the square matrix `m` in place.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n²)           |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// # Panics
+/// This function panics if `m` is not a square matrix.
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::matrix::{self, Matrix};
+///
+/// let mut m = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]);
+/// matrix::transpose(&mut m);
+/// assert_eq!(m, Matrix::from_rows(vec![vec![1, 3], vec![2, 4]]));
+/// ```
+pub fn transpose<T>(m: &mut Matrix<T>) {
+    assert!(m.is_square(), "matrix must be square to be transposed in place");
+
+    let n = m.rows;
+    for i in 0..n {
+        for j in i + 1..n {
+            m.data.swap(i * n + j, j * n + i);
+        }
+    }
+}
+
+/// Rotate the square matrix `m` 90° clockwise in place.
+///
+/// It is done by transposing the matrix and then reversing each row.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n²)           |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// # Panics
+/// This function panics if `m` is not a square matrix.
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::matrix::{self, Matrix};
+///
+/// let mut m = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]);
+/// matrix::rotate90(&mut m);
+/// assert_eq!(m, Matrix::from_rows(vec![vec![3, 1], vec![4, 2]]));
+/// ```
+pub fn rotate90<T>(m: &mut Matrix<T>) {
+    transpose(m);
+
+    let cols = m.cols;
+    if cols > 0 {
+        m.data.chunks_mut(cols).for_each(<[T]>::reverse);
+    }
+}
+
+/// Returns a new matrix that is `m` rotated 90° clockwise.
+///
+/// Unlike [`rotate90`], `m` doesn't need to be a square matrix.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n·m)          |                  |
+/// | Avrg:     | Θ(n·m)          |                  |
+/// | Worst:    | O(n·m)          | O(n·m)           |
+///
+/// [`rotate90`]: ./fn.rotate90.html
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::matrix::{self, Matrix};
+///
+/// let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// let r = matrix::rotated90(&m);
+/// assert_eq!(r, Matrix::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]));
+/// ```
+pub fn rotated90<T: Clone>(m: &Matrix<T>) -> Matrix<T> {
+    let (rows, cols) = (m.rows, m.cols);
+    let mut data = Vec::with_capacity(rows * cols);
+
+    for col in 0..cols {
+        for row in (0..rows).rev() {
+            data.push(m[(row, col)].clone());
+        }
+    }
+
+    Matrix { rows: cols, cols: rows, data }
+}
+
+/// Returns the elements of `m` in clockwise spiral order, starting at the top-left corner
+/// and going from the outer ring inward.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n·m)          |                  |
+/// | Avrg:     | Θ(n·m)          |                  |
+/// | Worst:    | O(n·m)          | O(n·m)           |
+///
+/// # Example
+/// ```rust
+/// use algos::numerics::matrix::{self, Matrix};
+///
+/// let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+/// assert_eq!(matrix::spiral_order(&m), vec![1, 2, 3, 6, 9, 8, 7, 4, 5]);
+/// ```
+pub fn spiral_order<T: Clone>(m: &Matrix<T>) -> Vec<T> {
+    let mut spiral = Vec::with_capacity(m.data.len());
+    if m.data.is_empty() {
+        return spiral;
+    }
+
+    // Bounds of the ring being traversed, all of them inclusive.
+    let (mut top, mut bottom, mut left, mut right) = (0, m.rows - 1, 0, m.cols - 1);
+    while top <= bottom && left <= right {
+        spiral.extend((left..=right).map(|col| m[(top, col)].clone()));
+        spiral.extend((top + 1..=bottom).map(|row| m[(row, right)].clone()));
+
+        // Single row or column left, it was already traversed.
+        if top == bottom || left == right {
+            break;
+        }
+
+        spiral.extend((left..right).rev().map(|col| m[(bottom, col)].clone()));
+        spiral.extend((top + 1..bottom).rev().map(|row| m[(row, left)].clone()));
+
+        top += 1;
+        left += 1;
+        bottom -= 1;
+        right -= 1;
+    }
+
+    spiral
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labeled_3x3() -> Matrix<char> {
+        Matrix::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f'], vec!['g', 'h', 'i']])
+    }
+
+    #[test]
+    fn rotate90_test() {
+        let mut m = labeled_3x3();
+        rotate90(&mut m);
+
+        let sure =
+            Matrix::from_rows(vec![vec!['g', 'd', 'a'], vec!['h', 'e', 'b'], vec!['i', 'f', 'c']]);
+        assert_eq!(sure, m);
+    }
+
+    #[test]
+    fn rotate90_four_times_test() {
+        let original = labeled_3x3();
+        let mut m = original.clone();
+
+        for _ in 0..4 {
+            rotate90(&mut m);
+        }
+        assert_eq!(original, m);
+    }
+
+    #[test]
+    fn rotated90_test() {
+        let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let r = rotated90(&m);
+
+        assert_eq!(3, r.rows());
+        assert_eq!(2, r.cols());
+        assert_eq!(Matrix::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]), r);
+
+        let square = labeled_3x3();
+        let mut in_place = square.clone();
+        rotate90(&mut in_place);
+        assert_eq!(in_place, rotated90(&square));
+    }
+
+    #[test]
+    fn spiral_order_test() {
+        let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        assert_eq!(vec![1, 2, 3, 6, 9, 8, 7, 4, 5], spiral_order(&m));
+    }
+
+    #[test]
+    fn spiral_order_single_row_column_test() {
+        let row = Matrix::from_rows(vec![vec![1, 2, 3, 4]]);
+        assert_eq!(vec![1, 2, 3, 4], spiral_order(&row));
+
+        let col = Matrix::from_rows(vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(vec![1, 2, 3], spiral_order(&col));
+
+        let empty: Matrix<i32> = Matrix::from_rows(vec![]);
+        assert!(spiral_order(&empty).is_empty());
+    }
+
+    #[test]
+    fn spiral_order_rectangular_test() {
+        let wide = Matrix::from_rows(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]]);
+        assert_eq!(vec![1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7], spiral_order(&wide));
+
+        let tall = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]]);
+        assert_eq!(vec![1, 2, 4, 6, 8, 7, 5, 3], spiral_order(&tall));
+    }
+}
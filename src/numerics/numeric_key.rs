@@ -0,0 +1,27 @@
+//! Primitive numbers as keys of the algorithms that work on their values.
+
+/// Primitive numbers that can be converted to `f64`, used by algorithms that work on the
+/// values of the numbers instead of only comparing them, like [`search::interpolation`]
+/// and [`sort::flash`].
+///
+/// The conversion of big integers may be rounded, but it keeps the order of the numbers.
+///
+/// [`search::interpolation`]: ../../search/fn.interpolation.html
+/// [`sort::flash`]: ../../sort/fn.flash.html
+pub trait NumericKey: Copy + PartialOrd {
+    /// Returns the number as a `f64`, possibly rounded.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric_key {
+    ($($t:ty)+) => {
+        $(
+        impl NumericKey for $t {
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+        )+
+    };
+}
+
+impl_numeric_key!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
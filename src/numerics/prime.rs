@@ -95,6 +95,7 @@ impl_is_prime_signed!(i8 i16 i32 i64 i128);
 impl_is_prime_unsigned!(u8 u16 u32 u64 u128);
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::useless_vec)]
 mod tests {
     use super::*;
 
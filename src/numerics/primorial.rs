@@ -40,6 +40,11 @@ impl BigPrimorial {
     }
 }
 
+#[cfg(feature = "big_num")]
+impl Default for BigPrimorial {
+    fn default() -> Self { Self::new() }
+}
+
 #[cfg(feature = "big_num")]
 impl Iterator for BigPrimorial {
     type Item = BigUint;
@@ -110,6 +115,7 @@ pub fn recursive_primorial(index: u128) -> u128 {
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
     use super::*;
     use num::BigUint;
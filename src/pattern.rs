@@ -1,10 +1,42 @@
 //! A module for using pattern matching algorithms.
+//!
+//! Most of the single pattern algorithms also have a type that does the preprocessing of
+//! the pattern once, like [`KmpPattern`], that implements [`Matcher`], to search for the
+//! same pattern in many slices.
+//!
+//! The algorithms that only compare the characters for equality, like [`kmp`], also work
+//! with slices of any type that implements [`Eq`], not only bytes.
+//!
+//! [`KmpPattern`]: ./struct.KmpPattern.html
+//! [`Matcher`]: ./trait.Matcher.html
+//! [`kmp`]: ./fn.kmp.html
+//! [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+
+use std::io::{self, Read};
+
+use crate::strings::RollingHash;
+
+mod aho_corasick;
+mod bitap;
+mod kmp;
+mod matcher;
+mod wildcard;
+mod z;
+
+pub use aho_corasick::{AhoCorasick, AhoCorasickStream, MultiMatches};
+pub use bitap::{bitap, bitap_approx, BitapPattern};
+pub use kmp::{failure_function, kmp, kmp_by_key, KmpPattern, KmpPatternByKey, KmpStream};
+pub use matcher::{Matcher, Matches};
+pub use wildcard::wildcard;
+pub use z::{z_array, z_search, ZPattern};
 
 /// **Brute Force:** Search for the pattern in the `find` parameter in a slice.
 ///
 /// It returns `Some` holding the index of the first character of `find` that was found
 /// or `None` if not find.
 ///
+/// It works with slices of any type that can be compared for equality.
+///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
 /// | Best:     | Ω(m*(n-m+1))    |                  |
@@ -20,15 +52,71 @@
 /// let find = pattern::bruteforce(p, b"TTT");
 /// assert_eq!(find, Some(6));
 /// ```
-pub fn bruteforce(pattern: &[u8], find: &[u8]) -> Option<usize> {
-    let (size_patt, size_find) = (pattern.len(), find.len());
-    for i in 0..=size_patt - size_find {
-        if &pattern[i..(i + size_find)] == find {
-            return Some(i);
+pub fn bruteforce<T: Eq + Clone>(pattern: &[T], find: &[T]) -> Option<usize> {
+    BruteForcePattern::new(find).find(pattern)
+}
+
+/// A pattern for the Brute Force algorithm, see [`bruteforce`].
+///
+/// There is no preprocessing, it is here to be used as a [`Matcher`].
+///
+/// [`bruteforce`]: ./fn.bruteforce.html
+/// [`Matcher`]: ./trait.Matcher.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BruteForcePattern<T = u8> {
+    find: Vec<T>,
+}
+
+impl<T: Eq + Clone> BruteForcePattern<T> {
+    /// Creates a new `BruteForcePattern` for `find`.
+    pub fn new(find: &[T]) -> Self { BruteForcePattern { find: find.to_vec() } }
+}
+
+impl<T: Eq + Clone> Matcher<T> for BruteForcePattern<T> {
+    fn compile(find: &[T]) -> Self { Self::new(find) }
+
+    fn pattern_len(&self) -> usize { self.find.len() }
+
+    fn find(&self, text: &[T]) -> Option<usize> {
+        let (size_patt, size_find) = (text.len(), self.find.len());
+        for i in 0..=size_patt.checked_sub(size_find)? {
+            if text[i..(i + size_find)] == self.find[..] {
+                return Some(i);
+            }
         }
+
+        None
     }
+}
 
-    None
+/// **Brute Force:** Search for the pattern in the `find` parameter in a slice, using the
+/// `eq` function to know if two characters are equivalent.
+///
+/// It returns `Some` holding the index of the first character of `find` that was found
+/// or `None` if not find.
+///
+/// Same as [`bruteforce`], but `eq` can be any relation, even one that is not transitive,
+/// like a wildcard that matches every character. The first parameter of `eq` is a
+/// character of the slice and the second one a character of `find`.
+///
+/// [`bruteforce`]: ./fn.bruteforce.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"ATCGGATTTCAGAAGCT";
+///
+/// // `N` matches any nucleotide.
+/// let find = pattern::bruteforce_by(p, b"CNGA", |&a, &b| b == b'N' || a == b);
+/// assert_eq!(find, Some(2));
+/// ```
+pub fn bruteforce_by<T, F: FnMut(&T, &T) -> bool>(
+    pattern: &[T], find: &[T], mut eq: F,
+) -> Option<usize> {
+    let (size_patt, size_find) = (pattern.len(), find.len());
+    (0..=size_patt.checked_sub(size_find)?)
+        .find(|&i| pattern[i..(i + size_find)].iter().zip(find).all(|(a, b)| eq(a, b)))
 }
 
 /// **Karp-Rabin:** Search for the pattern in the `find` parameter in a slice.
@@ -36,11 +124,17 @@ pub fn bruteforce(pattern: &[u8], find: &[u8]) -> Option<usize> {
 /// It returns `Some` holding the index of the first character of `find` that was found
 /// or `None` if not find.
 ///
+/// It compares the hash of `find`, the same as the one of a [`RollingHash`], with the
+/// hash of each substring of the slice of the same size, updating it as the window moves
+/// forward, and only compares the elements when they are equal.
+///
+/// [`RollingHash`]: ../strings/struct.RollingHash.html
+///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
-/// | Best:     | Ω(n+m)          |                  |
+/// | Best:     | Ω(m)            |                  |
 /// | Avrg:     | Θ(n+m)          |                  |
-/// | Worst:    | O(m*(n+m))      | O(1)             |
+/// | Worst:    | O(m*(n+m))      | O(m)             |
 ///
 /// # Example
 /// ```rust
@@ -52,41 +146,52 @@ pub fn bruteforce(pattern: &[u8], find: &[u8]) -> Option<usize> {
 /// assert_eq!(find, Some(6));
 /// ```
 pub fn karp_rabin(pattern: &[u8], find: &[u8]) -> Option<usize> {
-    let (size_patt, size_find) = (pattern.len() - 1, find.len());
-
-    // Preprocessing
-    // TODO: There is a way to do the preprocessing using dynamic programming, making the
-    // preprocessing time linear, making the worst case O(n+m)
-    let rehash = |a, b, hash, base| (((hash - a * base) << 1) + b);
-
-    // 2^(m-1)
-    let base: u64 = 1 << (size_find - 1);
-
-    // Calculate the hashes
-    let (hash_find, mut hash_patt) = find.iter().take(size_find).zip(pattern.iter()).fold(
-        (0_u64, 0_u64),
-        |(hash_find, hash_patt), (&find, &patt)| {
-            ((hash_find << 1) + u64::from(find), (hash_patt << 1) + u64::from(patt))
-        },
-    );
-
-    // Searching
-    let mut i = 0;
-    while i <= size_patt - size_find {
-        if hash_patt == hash_find && &pattern[i..(i + size_find)] == find {
-            return Some(i);
-        }
+    KarpRabinPattern::new(find).find(pattern)
+}
 
-        hash_patt =
-            rehash(u64::from(pattern[i]), u64::from(pattern[i + size_find]), hash_patt, base);
-        i += 1;
-    }
-    // Check again after the loops end.
-    if hash_patt == hash_find && &pattern[i..(i + size_find)] == find {
-        return Some(i);
+/// A pattern for the Karp-Rabin algorithm with its hash already computed, see
+/// [`karp_rabin`].
+///
+/// [`karp_rabin`]: ./fn.karp_rabin.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KarpRabinPattern {
+    find:  Vec<u8>,
+    hash:  [u64; 2],
+    /// The power of the bases that multiplies the first element of a window.
+    power: [u64; 2],
+}
+
+impl KarpRabinPattern {
+    /// Creates a new `KarpRabinPattern` for `find`, computing its hash.
+    pub fn new(find: &[u8]) -> Self {
+        let hash = RollingHash::new(find).hash_of(0..find.len());
+        let power = RollingHash::fixed_power(find.len().saturating_sub(1));
+        KarpRabinPattern { find: find.to_vec(), hash, power }
     }
+}
+
+impl Matcher for KarpRabinPattern {
+    fn compile(find: &[u8]) -> Self { Self::new(find) }
 
-    None
+    fn pattern_len(&self) -> usize { self.find.len() }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let size_find = self.find.len();
+        let last = text.len().checked_sub(size_find)?;
+
+        // Searching
+        let mut hash = RollingHash::fixed_hash(&text[..size_find]);
+        for i in 0..=last {
+            if hash == self.hash && text[i..(i + size_find)] == self.find[..] {
+                return Some(i);
+            }
+            if i < last {
+                hash = RollingHash::fixed_roll(hash, text[i], text[i + size_find], self.power);
+            }
+        }
+
+        None
+    }
 }
 
 /// **Boyer-Moore:** Search for the pattern in the `find` parameter in a slice.
@@ -94,11 +199,19 @@ pub fn karp_rabin(pattern: &[u8], find: &[u8]) -> Option<usize> {
 /// It returns `Some` holding the index of the first character of `find` that was found
 /// or `None` if not find.
 ///
+/// It compares `find` from its end, and on a mismatch it moves forward the most between
+/// the bad character rule, that aligns the character of the slice with its last
+/// occurrence in `find`, and the good suffix rule, that aligns the part already matched
+/// with its previous occurrence in `find`. To search for the same `find` many times, use
+/// [`BoyerMoorePattern`], that also finds all the occurrences in linear time.
+///
+/// [`BoyerMoorePattern`]: ./struct.BoyerMoorePattern.html
+///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
 /// | Best:     | Ω(n/m)          |                  |
 /// | Avrg:     | Θ(n+m)          |                  |
-/// | Worst:    | O(nm)           | O(m+δ)           |
+/// | Worst:    | O(n+m)          | O(m+δ)           |
 ///
 ///
 /// **Obs.:** δ is the max size of u8.
@@ -113,33 +226,105 @@ pub fn karp_rabin(pattern: &[u8], find: &[u8]) -> Option<usize> {
 /// assert_eq!(find, Some(6));
 /// ```
 pub fn boyer_moore(pattern: &[u8], find: &[u8]) -> Option<usize> {
-    let (size_patt, size_find) = (pattern.len() - 1, find.len());
-    let mut good_sufix_table = vec![0_usize; size_find];
-    let mut bad_char_table = [0_usize; 256];
-
-    // Preprocessing
-    preprocess_good_sufix(find, &mut good_sufix_table[..]);
-    preprocess_bad_char(find, &mut bad_char_table[..]);
-
-    // Searching
-    let mut i = 0;
-    while i <= size_patt - size_find {
-        let mut j = size_find - 1;
-        while find[j] == pattern[j + i] && j > 0 {
-            j -= 1;
-        }
-        if j == 0 {
-            if &pattern[i..(i + size_find)] == find {
-                return Some(i);
+    BoyerMoorePattern::new(find).find(pattern)
+}
+
+/// A pattern for the Boyer-Moore algorithm with its good suffix and bad character tables
+/// already computed, see [`boyer_moore`].
+///
+/// It uses the Galil rule to search for all the occurrences: after one is found, it moves
+/// forward by the smallest period of the pattern, and the part of the pattern that
+/// overlaps the occurrence found is not compared again. So even with periodic patterns,
+/// like `"aaaa"`, [`find_all`] is linear, instead of quadratic.
+///
+/// [`boyer_moore`]: ./fn.boyer_moore.html
+/// [`find_all`]: ./trait.Matcher.html#method.find_all
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::{BoyerMoorePattern, Matcher};
+///
+/// let text = vec![b'a'; 1000];
+/// let pattern = BoyerMoorePattern::new(&[b'a'; 100]);
+/// assert_eq!(pattern.find_all(&text).len(), 901);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoyerMoorePattern {
+    find:             Vec<u8>,
+    good_sufix_table: Vec<usize>,
+    bad_char_table:   [usize; 256],
+}
+
+impl BoyerMoorePattern {
+    /// Creates a new `BoyerMoorePattern` for `find`, computing its good suffix and bad
+    /// character tables.
+    pub fn new(find: &[u8]) -> Self {
+        let mut good_sufix_table = vec![0_usize; find.len()];
+        let mut bad_char_table = [0_usize; 256];
+        preprocess_good_sufix(find, &mut good_sufix_table[..]);
+        preprocess_bad_char(find, &mut bad_char_table[..]);
+        BoyerMoorePattern { find: find.to_vec(), good_sufix_table, bad_char_table }
+    }
+
+    /// Calls `report` with each occurrence of the pattern in `text`, in crescent order,
+    /// while it returns `true`. The pattern must not be empty.
+    fn search<F: FnMut(usize) -> bool>(&self, text: &[u8], mut report: F) {
+        let (size_patt, size_find) = (text.len(), self.find.len());
+        // The smallest period, the shift after an occurrence is found.
+        let period = self.good_sufix_table[0];
+
+        // Searching
+        let mut i = 0;
+        // The size of the prefix of the window known to match, by the Galil rule.
+        let mut known = 0;
+        while i + size_find <= size_patt {
+            let mut j = size_find;
+            while j > known && self.find[j - 1] == text[i + j - 1] {
+                j -= 1;
             }
-            i += good_sufix_table[0];
-        } else {
-            i += good_sufix_table[j]
-                .max(bad_char_table[pattern[j + i] as usize - size_find + 1 + j]);
+            if j == known {
+                if !report(i) {
+                    return;
+                }
+                i += period;
+                known = size_find - period;
+            } else {
+                let bad_char = self.bad_char_table[text[i + j - 1] as usize];
+                i += self.good_sufix_table[j - 1].max(bad_char.saturating_sub(size_find - j));
+                known = 0;
+            }
+        }
+    }
+}
+
+impl Matcher for BoyerMoorePattern {
+    fn compile(find: &[u8]) -> Self { Self::new(find) }
+
+    fn pattern_len(&self) -> usize { self.find.len() }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        if self.find.is_empty() {
+            return Some(0);
         }
+        let mut found = None;
+        self.search(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
     }
 
-    None
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        if self.find.is_empty() {
+            return (0..=text.len()).collect();
+        }
+        let mut found = Vec::new();
+        self.search(text, |i| {
+            found.push(i);
+            true
+        });
+        found
+    }
 }
 
 /// **Horspool:** Search for the pattern in the `find` parameter in a slice.
@@ -168,61 +353,101 @@ pub fn boyer_moore(pattern: &[u8], find: &[u8]) -> Option<usize> {
 /// assert_eq!(find, Some(6));
 /// ```
 pub fn horspool(pattern: &[u8], find: &[u8]) -> Option<usize> {
-    let (size_patt, size_find) = (pattern.len(), find.len());
-    let mut bad_char_table = [0_usize; 256];
+    HorspoolPattern::new(find).find(pattern)
+}
+
+/// A pattern for the Horspool algorithm with its bad character table already computed,
+/// see [`horspool`].
+///
+/// [`horspool`]: ./fn.horspool.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HorspoolPattern {
+    find:           Vec<u8>,
+    bad_char_table: [usize; 256],
+}
+
+impl HorspoolPattern {
+    /// Creates a new `HorspoolPattern` for `find`, computing its bad character table.
+    pub fn new(find: &[u8]) -> Self {
+        let mut bad_char_table = [0_usize; 256];
+        preprocess_bad_char(find, &mut bad_char_table[..]);
+        HorspoolPattern { find: find.to_vec(), bad_char_table }
+    }
+}
+
+impl Matcher for HorspoolPattern {
+    fn compile(find: &[u8]) -> Self { Self::new(find) }
 
-    // Preprocessing
-    preprocess_bad_char(find, &mut bad_char_table[..]);
+    fn pattern_len(&self) -> usize { self.find.len() }
 
-    // Searching
-    let mut i = 0;
-    while i <= size_patt - size_find {
-        let c = pattern[i + size_find - 1];
-        if find[size_find - 1] == c && &pattern[i..(i + size_find)] == find {
-            return Some(i);
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let (size_patt, size_find) = (text.len(), self.find.len());
+        if size_find == 0 {
+            return Some(0);
         }
 
-        i += bad_char_table[c as usize];
-    }
+        // Searching
+        let mut i = 0;
+        while i + size_find <= size_patt {
+            let c = text[i + size_find - 1];
+            if self.find[size_find - 1] == c && text[i..(i + size_find)] == self.find[..] {
+                return Some(i);
+            }
+
+            i += self.bad_char_table[c as usize];
+        }
 
-    None
+        None
+    }
 }
 
+/// Computes the shift of the good suffix rule for a mismatch at each index of `find`,
+/// after the elements after it matched. The shift at `0` is also the smallest period of
+/// `find`, the shift after it is found.
 fn preprocess_good_sufix(find: &[u8], good_sufix_table: &mut [usize]) {
     let size = find.len();
+    if size == 0 {
+        return;
+    }
 
-    // Good sufix
+    // `suff[i]` is the size of the longest suffix of `find[..=i]` that is also a suffix of
+    // `find`.
     let mut suff = vec![0; size];
-    let mut good = size - 1;
-    let mut f = 0;
     suff[size - 1] = size;
-    for i in (0..suff.len()).rev() {
-        if i > good && suff[i + size - 1 + f] < i - good {
-            suff[i] = suff[i + size - 1 + f];
+    // The suffix of `find` matched the furthest to the left so far, `find[g + 1..=f]`,
+    // with `g` plus one.
+    let (mut g, mut f) = (size, size - 1);
+    for i in (0..size - 1).rev() {
+        if i + 1 > g && suff[i + size - 1 - f] < i + 1 - g {
+            suff[i] = suff[i + size - 1 - f];
         } else {
-            good = if (good) < i { i } else { good };
+            g = g.min(i + 1);
             f = i;
-            while good == 0 && find[good] == find[good + size - 1 - f] {
-                good -= 1;
+            while g > 0 && find[g - 1] == find[g - 1 + size - 1 - f] {
+                g -= 1;
             }
-            suff[i] = good - f;
+            suff[i] = f + 1 - g;
         }
     }
 
-    for i in good_sufix_table.iter_mut().take(size) {
+    // Aligns the matched suffix with a prefix of `find` that is a suffix of it.
+    for i in good_sufix_table.iter_mut() {
         *i = size;
     }
+    let mut j = 0;
     for i in (0..size).rev() {
         if suff[i] == i + 1 {
-            for j in good_sufix_table.iter_mut().take(size - 1) {
-                if *j == size {
-                    *j = size - i - 1;
+            while j < size - 1 - i {
+                if good_sufix_table[j] == size {
+                    good_sufix_table[j] = size - 1 - i;
                 }
+                j += 1;
             }
         }
     }
-    for i in 0..size {
-        good_sufix_table[size - 1 - suff[i] as usize] = size - 1 - i;
+    // Aligns the matched suffix with its rightmost other occurrence in `find`.
+    for i in 0..size - 1 {
+        good_sufix_table[size - 1 - suff[i]] = size - 1 - i;
     }
 }
 
@@ -232,7 +457,7 @@ fn preprocess_bad_char(find: &[u8], bad_char_table: &mut [usize]) {
     for i in bad_char_table.iter_mut() {
         *i = size;
     }
-    for i in 0..size - 1 {
+    for i in 0..size.saturating_sub(1) {
         bad_char_table[find[i] as usize] = size - i - 1;
     }
 }
@@ -263,23 +488,68 @@ fn preprocess_bad_char(find: &[u8], bad_char_table: &mut [usize]) {
 /// assert_eq!(find, Some(6));
 /// ```
 pub fn quick_matching(pattern: &[u8], find: &[u8]) -> Option<usize> {
-    let (size_patt, size_find) = (pattern.len(), find.len());
-    let mut bad_char_table = [0_usize; 256];
+    QuickPattern::new(find).find(pattern)
+}
+
+/// A pattern for the Quick algorithm with its bad character table already computed, see
+/// [`quick_matching`].
+///
+/// [`quick_matching`]: ./fn.quick_matching.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuickPattern {
+    find:           Vec<u8>,
+    bad_char_table: [usize; 256],
+}
+
+impl QuickPattern {
+    /// Creates a new `QuickPattern` for `find`, computing its bad character table.
+    pub fn new(find: &[u8]) -> Self {
+        let mut bad_char_table = [0_usize; 256];
+        preprocess_quick_bad_char(find, &mut bad_char_table);
+        QuickPattern { find: find.to_vec(), bad_char_table }
+    }
+}
+
+impl Matcher for QuickPattern {
+    fn compile(find: &[u8]) -> Self { Self::new(find) }
 
-    // Preprocessing
-    preprocess_quick_bad_char(&find, &mut bad_char_table);
+    fn pattern_len(&self) -> usize { self.find.len() }
 
-    // Searching
-    let mut i = 0;
-    while i <= size_patt - size_find {
-        if &pattern[i..(i + size_find)] == find {
-            return Some(i);
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let (size_patt, size_find) = (text.len(), self.find.len());
+
+        // Searching
+        let mut i = 0;
+        while i + size_find <= size_patt {
+            if text[i..(i + size_find)] == self.find[..] {
+                return Some(i);
+            }
+            // The character after the window decides the shift.
+            if i + size_find == size_patt {
+                break;
+            }
+
+            i += self.bad_char_table[text[i + size_find] as usize];
         }
 
-        i += bad_char_table[pattern[i + size_find] as usize];
+        None
     }
+}
 
-    None
+/// The size of the chunks read by the streaming matchers.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Calls `f` with each chunk read from `reader` until its end.
+fn read_chunks<R: Read, F: FnMut(&[u8])>(mut reader: R, mut f: F) -> io::Result<()> {
+    let mut buf = vec![0; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => f(&buf[..n]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
 }
 
 fn preprocess_quick_bad_char(find: &[u8], bad_char_table: &mut [usize]) {
@@ -312,6 +582,24 @@ pub mod test {
         assert_eq!(none, None);
     }
 
+    #[test]
+    pub fn bruteforce_by_cases() {
+        let p = b"ATCGGATTTCAGAAGCT";
+        let wildcard = |&a: &u8, &b: &u8| b == b'N' || a == b;
+
+        let start = bruteforce_by(p, b"NTC", wildcard);
+        let middle = bruteforce_by(p, b"TNNC", wildcard);
+        let end = bruteforce_by(p, b"GNN", wildcard);
+        let none = bruteforce_by(p, b"TNNT", wildcard);
+        let all = bruteforce_by(p, b"NNNNNNNNNNNNNNNNN", wildcard);
+        assert_eq!(start, Some(0));
+        assert_eq!(middle, Some(6));
+        assert_eq!(end, Some(3));
+        assert_eq!(none, None);
+        assert_eq!(all, Some(0));
+        assert_eq!(bruteforce_by(b"ATC", b"ATCG", wildcard), None);
+    }
+
     #[test]
     pub fn karp_rabin_cases() {
         let p = b"ATCGGATTTCAGAAGCT";
@@ -329,7 +617,14 @@ pub mod test {
     }
 
     #[test]
-    #[ignore]
+    pub fn karp_rabin_find_all_cases() {
+        // Every search stops at the first window, it must not hash the rest of the text.
+        let text = vec![b'a'; 200_000];
+        assert_eq!(KarpRabinPattern::new(b"a").find_all(&text).len(), 200_000);
+        assert_eq!(KarpRabinPattern::new(b"").find_all(b"ab"), [0, 1, 2]);
+    }
+
+    #[test]
     pub fn boyer_moore_cases() {
         let p = b"ATCGGATTTCAGAAGCT";
 
@@ -345,6 +640,23 @@ pub mod test {
         assert_eq!(none, None);
     }
 
+    #[test]
+    pub fn boyer_moore_periodic_cases() {
+        let text = [b'a'; 1000];
+        let found = BoyerMoorePattern::new(&[b'a'; 10]).find_all(&text);
+        assert_eq!(found, (0..=990).collect::<Vec<_>>());
+
+        // Texts and patterns made of repetitions, to exercise the Galil rule.
+        for root in [&b"ab"[..], b"aab", b"abaab"].iter() {
+            let text: Vec<u8> = root.iter().copied().cycle().take(200).collect();
+            for size in 1..=3 * root.len() {
+                let find: Vec<u8> = root.iter().copied().cycle().take(size).collect();
+                let expected = KmpPattern::new(&find).find_all(&text);
+                assert_eq!(BoyerMoorePattern::new(&find).find_all(&text), expected);
+            }
+        }
+    }
+
     #[test]
     pub fn horspool_cases() {
         let p = b"ATCGGATTTCAGAAGCT";
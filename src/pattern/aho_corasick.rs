@@ -0,0 +1,375 @@
+//! Aho-Corasick implementation.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io::{self, Read},
+};
+
+use super::read_chunks;
+
+/// **Aho-Corasick:** Automaton that searches for many patterns at the same time, in a
+/// single pass over a slice.
+///
+/// It is a trie of the patterns where every node also has a failure link to the node of
+/// the longest proper suffix of it that is also in the trie. On a mismatch it follows the
+/// failure links, so it never goes back in the slice, and it reports every pattern that
+/// ends at a position by following a link to the next node on the failure chain that is
+/// the end of a pattern.
+///
+/// The matches are `(id, index)`, where `id` is the index of the pattern in the order
+/// they were given and `index` is where it was found in the slice.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+z)          |                  |
+/// | Avrg:     | Θ(n+z)          |                  |
+/// | Worst:    | O(n+z)          | O(m)             |
+///
+/// **Obs.:** m is the total size of the patterns and z is the number of matches. Building
+/// the automaton takes O(mlog(δ)), with δ the max size of u8.
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(&["he", "she", "his", "hers"]);
+///
+/// assert_eq!(ac.find_all(b"ushers"), [(1, 1), (0, 2), (3, 2)]);
+/// assert_eq!(ac.find(b"ahishers"), Some((2, 1)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+    /// The size of each pattern.
+    lens:  Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    next:    BTreeMap<u8, usize>,
+    /// The node of the longest proper suffix that is in the trie.
+    fail:    usize,
+    /// The next node on the failure chain that is the end of some pattern.
+    dict:    Option<usize>,
+    /// The patterns that end at this node.
+    matches: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Creates the automaton for the `patterns`.
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let mut nodes = vec![Node::default()];
+        let mut lens = Vec::new();
+
+        // Trie
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            let mut node = 0;
+            for &c in pattern {
+                node = match nodes[node].next.get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(c, next);
+                        next
+                    },
+                };
+            }
+            nodes[node].matches.push(id);
+            lens.push(pattern.len());
+        }
+
+        // Failure links, in breadth-first order so the links of the shallower nodes are
+        // ready first.
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> =
+                nodes[node].next.iter().map(|(&c, &n)| (c, n)).collect();
+            for (c, child) in children {
+                // The failure link of a child of the root is the root itself.
+                let mut fail = nodes[node].fail;
+                let fail = loop {
+                    if node == 0 {
+                        break 0;
+                    } else if let Some(&next) = nodes[fail].next.get(&c) {
+                        break next;
+                    } else if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[child].fail = fail;
+                nodes[child].dict =
+                    if nodes[fail].matches.is_empty() { nodes[fail].dict } else { Some(fail) };
+                queue.push_back(child);
+            }
+        }
+
+        AhoCorasick { nodes, lens }
+    }
+
+    /// Returns the number of patterns.
+    pub fn patterns_len(&self) -> usize { self.lens.len() }
+
+    /// Search for the patterns in `text`.
+    ///
+    /// It returns `Some` holding the pattern that ends first in `text`, and where it was
+    /// found, or `None` if none is found. If many end at the same place, the longest
+    /// one is returned.
+    pub fn find(&self, text: &[u8]) -> Option<(usize, usize)> { self.find_iter(text).next() }
+
+    /// Search for all the occurrences of the patterns in `text`.
+    ///
+    /// The matches are ordered by where they end in `text` and, when they end at the same
+    /// place, from the longest to the shortest pattern.
+    pub fn find_all(&self, text: &[u8]) -> Vec<(usize, usize)> { self.find_iter(text).collect() }
+
+    /// Returns an iterator over all the occurrences of the patterns in `text`, in the same
+    /// order as [`find_all`].
+    ///
+    /// The occurrences are searched as the iterator advances.
+    ///
+    /// [`find_all`]: #method.find_all
+    pub fn find_iter<'a, 't>(&'a self, text: &'t [u8]) -> MultiMatches<'a, 't> {
+        // Empty patterns match before the first character too, so it starts reporting the
+        // patterns of the root.
+        MultiMatches { ac: self, text, read: 0, state: 0, node: Some(0), next_match: 0 }
+    }
+
+    /// Returns an [`AhoCorasickStream`] to search for the patterns in a slice given in
+    /// chunks.
+    ///
+    /// [`AhoCorasickStream`]: ./struct.AhoCorasickStream.html
+    pub fn stream(&self) -> AhoCorasickStream<'_> {
+        AhoCorasickStream { ac: self, state: 0, read: 0 }
+    }
+
+    /// Pushes to `found` the patterns that end after reading `read` characters, when the
+    /// automaton is at `state`.
+    fn push_matches(&self, state: usize, read: u64, found: &mut Vec<(usize, u64)>) {
+        let mut node = Some(state);
+        while let Some(n) = node {
+            for &id in &self.nodes[n].matches {
+                found.push((id, read - self.lens[id] as u64));
+            }
+            node = self.nodes[n].dict;
+        }
+    }
+
+    /// Returns the state of the automaton after reading `c` at `state`.
+    fn step(&self, mut state: usize, c: u8) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[state].next.get(&c) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.nodes[state].fail;
+        }
+    }
+}
+
+/// Iterator over the occurrences of the patterns of an [`AhoCorasick`] in a slice.
+///
+/// This `struct` is created by [`AhoCorasick::find_iter`].
+///
+/// [`AhoCorasick`]: ./struct.AhoCorasick.html
+/// [`AhoCorasick::find_iter`]: ./struct.AhoCorasick.html#method.find_iter
+#[derive(Debug, Clone)]
+pub struct MultiMatches<'a, 't> {
+    ac:         &'a AhoCorasick,
+    text:       &'t [u8],
+    /// How many characters of `text` were read.
+    read:       usize,
+    state:      usize,
+    /// The node on the failure chain of `state` whose patterns are being reported.
+    node:       Option<usize>,
+    /// The next pattern of `node` to report.
+    next_match: usize,
+}
+
+impl<'a, 't> Iterator for MultiMatches<'a, 't> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.node {
+                Some(node) => {
+                    let node = &self.ac.nodes[node];
+                    if let Some(&id) = node.matches.get(self.next_match) {
+                        self.next_match += 1;
+                        return Some((id, self.read - self.ac.lens[id]));
+                    }
+                    self.node = node.dict;
+                    self.next_match = 0;
+                },
+                None => {
+                    let &c = self.text.get(self.read)?;
+                    self.state = self.ac.step(self.state, c);
+                    self.read += 1;
+                    self.node = Some(self.state);
+                },
+            }
+        }
+    }
+}
+
+/// Searches for the patterns of an [`AhoCorasick`] in a slice that is given in chunks,
+/// like a file that does not fit in memory.
+///
+/// It keeps the state of the automaton at the end of the last chunk, so it finds the
+/// occurrences that start in a chunk and end in another one.
+///
+/// This `struct` is created by [`AhoCorasick::stream`].
+///
+/// [`AhoCorasick`]: ./struct.AhoCorasick.html
+/// [`AhoCorasick::stream`]: ./struct.AhoCorasick.html#method.stream
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(&["he", "she", "his", "hers"]);
+/// let mut stream = ac.stream();
+///
+/// assert_eq!(stream.push(b"ush"), []);
+/// assert_eq!(stream.push(b"ers"), [(1, 1), (0, 2), (3, 2)]);
+/// assert_eq!(stream.push_reader(&b"his"[..]).unwrap(), [(2, 6)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AhoCorasickStream<'a> {
+    ac:    &'a AhoCorasick,
+    state: usize,
+    /// How many characters were read.
+    read:  u64,
+}
+
+impl<'a> AhoCorasickStream<'a> {
+    /// Returns how many characters were pushed.
+    pub fn position(&self) -> u64 { self.read }
+
+    /// Search in `chunk`, the continuation of the chunks pushed before.
+    ///
+    /// It returns the occurrences of the patterns that end in `chunk`, as `(id, index)`,
+    /// with `index` counting from the start of the first chunk, in the same order as
+    /// [`AhoCorasick::find_all`]. Empty patterns are found after each character, and
+    /// before the first one.
+    ///
+    /// [`AhoCorasick::find_all`]: ./struct.AhoCorasick.html#method.find_all
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<(usize, u64)> {
+        let mut found = Vec::new();
+        if self.read == 0 && !chunk.is_empty() {
+            self.ac.push_matches(0, 0, &mut found);
+        }
+        for &c in chunk {
+            self.state = self.ac.step(self.state, c);
+            self.read += 1;
+            self.ac.push_matches(self.state, self.read, &mut found);
+        }
+        found
+    }
+
+    /// Search in everything read from `reader`, the continuation of the chunks pushed
+    /// before, reading it in chunks.
+    ///
+    /// It returns the occurrences of the patterns, like [`push`].
+    ///
+    /// [`push`]: #method.push
+    ///
+    /// # Errors
+    /// It returns any error of `reader`, other than [`ErrorKind::Interrupted`].
+    ///
+    /// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+    pub fn push_reader<R: Read>(&mut self, reader: R) -> io::Result<Vec<(usize, u64)>> {
+        let mut found = Vec::new();
+        read_chunks(reader, |chunk| found.extend(self.push(chunk)))?;
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn aho_corasick_cases() {
+        let p = b"ATCGGATTTCAGAAGCT";
+        let ac = AhoCorasick::new(["ATC", "TTT", "AAG", "GCT", "TTTT", "T"]);
+
+        assert_eq!(ac.patterns_len(), 6);
+        assert_eq!(ac.find(p), Some((5, 1)));
+        assert_eq!(ac.find_all(p), [
+            (5, 1),
+            (0, 0),
+            (5, 6),
+            (5, 7),
+            (1, 6),
+            (5, 8),
+            (2, 12),
+            (3, 14),
+            (5, 16)
+        ]);
+        assert_eq!(ac.find_iter(p).skip(4).take(2).collect::<Vec<_>>(), [(1, 6), (5, 8)]);
+        assert_eq!(AhoCorasick::new(["TTTT"]).find(p), None);
+        assert_eq!(AhoCorasick::new(Vec::<&[u8]>::new()).find(p), None);
+    }
+
+    #[test]
+    fn aho_corasick_empty_pattern_test() {
+        let ac = AhoCorasick::new(["", "A"]);
+        assert_eq!(ac.find(b""), Some((0, 0)));
+        assert_eq!(ac.find_all(b"BA"), [(0, 0), (0, 1), (1, 1), (0, 2)]);
+
+        let mut stream = ac.stream();
+        assert_eq!(stream.push(b""), []);
+        assert_eq!(stream.push(b"B"), [(0, 0), (0, 1)]);
+        assert_eq!(stream.push(b"A"), [(1, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn aho_corasick_random_test() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..200)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let patterns: Vec<Vec<u8>> = (0..rng.gen_range(0..10))
+                .map(|_| (0..rng.gen_range(1..5)).map(|_| rng.gen_range(b'a'..b'd')).collect())
+                .collect();
+
+            let mut expected = Vec::new();
+            for (id, pattern) in patterns.iter().enumerate() {
+                for i in 0..text.len() {
+                    if text[i..].starts_with(pattern) {
+                        expected.push((id, i));
+                    }
+                }
+            }
+            expected.sort();
+
+            let ac = AhoCorasick::new(&patterns);
+            let found = ac.find_all(&text);
+            let mut sorted = found.clone();
+            sorted.sort();
+            assert_eq!(sorted, expected);
+
+            let mut stream = ac.stream();
+            let mut streamed = Vec::new();
+            let mut rest = &text[..];
+            while !rest.is_empty() {
+                let (chunk, next) = rest.split_at(rng.gen_range(0..=rest.len().min(7)));
+                streamed.extend(stream.push(chunk).into_iter().map(|(id, i)| (id, i as usize)));
+                rest = next;
+            }
+            assert_eq!(streamed, found);
+            assert_eq!(stream.position(), text.len() as u64);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Bitap (Shift-Or) implementation.
+
+use super::Matcher;
+
+/// The max size of a pattern, the number of bits in the state.
+const MAX_FIND: usize = 64;
+
+/// **Bitap:** Search for the pattern in the `find` parameter in a slice.
+///
+/// It returns `Some` holding the index of the first character of `find` that was found
+/// or `None` if not find.
+///
+/// Also known as Shift-Or. It keeps a bit for each prefix of `find` that is matched by
+/// the end of what was read of the slice, and updates all of them at once with a shift
+/// and an or for each character, so it's very fast for small patterns.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+m)          |                  |
+/// | Avrg:     | Θ(n+m)          |                  |
+/// | Worst:    | O(n+m)          | O(δ)             |
+///
+/// **Obs.:** δ is the max size of u8.
+///
+/// # Panics
+/// This function panics if `find` has more than 64 characters.
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"ATCGGATTTCAGAAGCT";
+///
+/// let find = pattern::bitap(p, b"TTT");
+/// assert_eq!(find, Some(6));
+/// ```
+pub fn bitap(pattern: &[u8], find: &[u8]) -> Option<usize> { BitapPattern::new(find).find(pattern) }
+
+/// **Approximate Bitap:** Search for the pattern in the `find` parameter in a slice,
+/// allowing up to `k` mismatches.
+///
+/// It returns `Some` holding the index of the first character of the first part of the
+/// slice that differs from `find` in at most `k` characters, or `None` if not find.
+///
+/// Same as [`bitap`], but with a state for each number of mismatches up to `k`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nk+m)         |                  |
+/// | Avrg:     | Θ(nk+m)         |                  |
+/// | Worst:    | O(nk+m)         | O(δ+k)           |
+///
+/// **Obs.:** δ is the max size of u8.
+///
+/// [`bitap`]: ./fn.bitap.html
+///
+/// # Panics
+/// This function panics if `find` has more than 64 characters.
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"ATCGGATTTCAGAAGCT";
+///
+/// assert_eq!(pattern::bitap_approx(p, b"TATT", 0), None);
+/// assert_eq!(pattern::bitap_approx(p, b"TATT", 1), Some(4));
+/// ```
+pub fn bitap_approx(pattern: &[u8], find: &[u8], k: usize) -> Option<usize> {
+    BitapPattern::approx(find, k).find(pattern)
+}
+
+/// A pattern for the Bitap algorithm with its masks already computed, see [`bitap`] and
+/// [`bitap_approx`].
+///
+/// [`bitap`]: ./fn.bitap.html
+/// [`bitap_approx`]: ./fn.bitap_approx.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitapPattern {
+    /// A zero bit `i` in the mask of a character means that `find[i]` is that character.
+    masks:     [u64; 256],
+    size_find: usize,
+    /// The number of mismatches allowed.
+    k:         usize,
+}
+
+impl BitapPattern {
+    /// Creates a new `BitapPattern` for `find`, computing its masks.
+    ///
+    /// # Panics
+    /// This function panics if `find` has more than 64 characters.
+    pub fn new(find: &[u8]) -> Self { Self::approx(find, 0) }
+
+    /// Creates a new `BitapPattern` for `find` that allows up to `k` mismatches,
+    /// computing its masks.
+    ///
+    /// # Panics
+    /// This function panics if `find` has more than 64 characters.
+    pub fn approx(find: &[u8], k: usize) -> Self {
+        let size_find = find.len();
+        assert!(
+            size_find <= MAX_FIND,
+            "bitap patterns can't have more than {} characters",
+            MAX_FIND
+        );
+
+        let mut masks = [!0_u64; 256];
+        for (i, &c) in find.iter().enumerate() {
+            masks[c as usize] &= !(1 << i);
+        }
+        BitapPattern { masks, size_find, k: k.min(size_find) }
+    }
+
+    /// Calls `report` with the index of each part of `text` that matches the pattern
+    /// while it returns `true`.
+    fn run<F: FnMut(usize) -> bool>(&self, text: &[u8], mut report: F) {
+        let size_find = self.size_find;
+        if size_find == 0 {
+            for i in 0..=text.len() {
+                if !report(i) {
+                    return;
+                }
+            }
+            return;
+        }
+
+        // A zero bit `i` in `states[d]` means that `find[..=i]` matches the end of what was
+        // read with at most `d` mismatches.
+        let mut states = vec![!0_u64; self.k + 1];
+        let last = 1 << (size_find - 1);
+        for (i, &c) in text.iter().enumerate() {
+            let mask = self.masks[c as usize];
+            let mut prev = states[0];
+            states[0] = (prev << 1) | mask;
+            for state in states.iter_mut().skip(1) {
+                let old = *state;
+                *state = ((old << 1) | mask) & (prev << 1);
+                prev = old;
+            }
+            if states[self.k] & last == 0 && !report(i + 1 - size_find) {
+                return;
+            }
+        }
+    }
+}
+
+impl Matcher for BitapPattern {
+    fn compile(find: &[u8]) -> Self { Self::new(find) }
+
+    fn pattern_len(&self) -> usize { self.size_find }
+
+    fn find(&self, text: &[u8]) -> Option<usize> {
+        let mut found = None;
+        self.run(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[u8]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.run(text, |i| {
+            found.push(i);
+            true
+        });
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn bitap_cases() {
+        let p = b"ATCGGATTTCAGAAGCT";
+
+        assert_eq!(bitap(p, b"ATC"), Some(0));
+        assert_eq!(bitap(p, b"TTT"), Some(6));
+        assert_eq!(bitap(p, b"AAG"), Some(12));
+        assert_eq!(bitap(p, b"GCT"), Some(14));
+        assert_eq!(bitap(p, b"TTTT"), None);
+        assert_eq!(bitap(p, b""), Some(0));
+        assert_eq!(bitap(b"AT", b"ATC"), None);
+
+        let long = [b'A'; 64];
+        assert_eq!(bitap(&[b"T" as &[u8], &long].concat(), &long), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitap_long_test() { bitap(b"ATCG", &[b'A'; 65]); }
+
+    #[test]
+    fn bitap_approx_test() {
+        let p = b"ATCGGATTTCAGAAGCT";
+
+        assert_eq!(bitap_approx(p, b"TTTT", 1), Some(5));
+        assert_eq!(bitap_approx(p, b"GGGG", 1), None);
+        assert_eq!(bitap_approx(p, b"GGGG", 2), Some(1));
+        assert_eq!(bitap_approx(p, b"CCCC", 4), Some(0));
+        assert_eq!(bitap_approx(b"AT", b"ATC", 3), None);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..100)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..8)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let k = rng.gen_range(0..3);
+            let expected = text
+                .windows(find.len())
+                .position(|w| w.iter().zip(&find).filter(|(a, b)| a != b).count() <= k);
+            assert_eq!(bitap_approx(&text, &find, k), expected);
+        }
+    }
+}
@@ -0,0 +1,461 @@
+//! Knuth-Morris-Pratt implementation.
+
+use std::{
+    borrow::Borrow,
+    io::{self, Read},
+};
+
+use super::{read_chunks, Matcher};
+
+/// **Failure Function:** Computes the failure function (also known as prefix function) of
+/// `find`.
+///
+/// The value at `i` is the length of the longest proper prefix of `find[..=i]` that is
+/// also a suffix of it. That is what the Knuth-Morris-Pratt algorithm uses to know how
+/// much of the pattern is still matched after a mismatch, but it is also useful on its
+/// own, e.g. the smallest period of `find` is `find.len() - fail[find.len() - 1]`.
+///
+/// It works with slices of any type that can be compared for equality.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(m)            |                  |
+/// | Avrg:     | Θ(m)            |                  |
+/// | Worst:    | O(m)            | O(m)             |
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let fail = pattern::failure_function(b"ABABCABAB");
+/// assert_eq!(fail, [0, 0, 1, 2, 0, 1, 2, 3, 4]);
+/// ```
+pub fn failure_function<T: Eq>(find: &[T]) -> Vec<usize> {
+    let mut fail = vec![0; find.len()];
+    let mut k = 0;
+    for i in 1..find.len() {
+        while k > 0 && find[i] != find[k] {
+            k = fail[k - 1];
+        }
+        if find[i] == find[k] {
+            k += 1;
+        }
+        fail[i] = k;
+    }
+    fail
+}
+
+/// **Knuth-Morris-Pratt:** Search for the pattern in the `find` parameter in a slice.
+///
+/// It returns `Some` holding the index of the first character of `find` that was found
+/// or `None` if not find.
+///
+/// It never goes back in the slice: on a mismatch it uses the [`failure_function`] of
+/// `find` to know how much of it is still matched. To search for the same `find` many
+/// times, use [`KmpPattern`], so the failure function is only computed once.
+///
+/// It works with slices of any type that can be compared for equality.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+m)          |                  |
+/// | Avrg:     | Θ(n+m)          |                  |
+/// | Worst:    | O(n+m)          | O(m)             |
+///
+/// [`failure_function`]: ./fn.failure_function.html
+/// [`KmpPattern`]: ./struct.KmpPattern.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"ATCGGATTTCAGAAGCT";
+///
+/// let find = pattern::kmp(p, b"TTT");
+/// assert_eq!(find, Some(6));
+///
+/// let events = ["login", "view", "login", "buy", "logout"];
+/// assert_eq!(pattern::kmp(&events, &["login", "buy"]), Some(2));
+/// ```
+pub fn kmp<T: Eq + Clone>(pattern: &[T], find: &[T]) -> Option<usize> {
+    KmpPattern::new(find).find(pattern)
+}
+
+/// **Knuth-Morris-Pratt:** Search for the pattern in the `find` parameter in a slice,
+/// comparing the keys extracted by the `key` function.
+///
+/// It returns `Some` holding the index of the first character of `find` that was found
+/// or `None` if not find.
+///
+/// Same as [`kmp`], but two characters are equivalent when they have the same key, like
+/// the lowercase of a letter for case-insensitive search. To search for the same `find`
+/// many times, use [`KmpPatternByKey`].
+///
+/// [`kmp`]: ./fn.kmp.html
+/// [`KmpPatternByKey`]: ./struct.KmpPatternByKey.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"Hello World";
+/// let find = pattern::kmp_by_key(p, b"WORLD", u8::to_ascii_lowercase);
+/// assert_eq!(find, Some(6));
+///
+/// let p: Vec<char> = "Ünïcödé Text".chars().collect();
+/// let find: Vec<char> = "üNÏ".chars().collect();
+/// assert_eq!(pattern::kmp_by_key(&p, &find, |c| c.to_lowercase().to_string()), Some(0));
+/// ```
+pub fn kmp_by_key<T, K: Eq + Clone, F: FnMut(&T) -> K>(
+    pattern: &[T], find: &[T], mut key: F,
+) -> Option<usize> {
+    let kmp = KmpPattern::new(&find.iter().map(&mut key).collect::<Vec<K>>());
+    let mut found = None;
+    kmp.run(pattern.iter().map(key), |i| {
+        found = Some(i);
+        false
+    });
+    found
+}
+
+/// A pattern for the Knuth-Morris-Pratt algorithm with its failure function already
+/// computed, so it can be searched in many slices.
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::{KmpPattern, Matcher};
+///
+/// let kmp = KmpPattern::new(b"TTT");
+///
+/// assert_eq!(kmp.find(b"ATCGGATTTCAGAAGCT"), Some(6));
+/// assert_eq!(kmp.find(b"TTAGTTCTT"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KmpPattern<T = u8> {
+    find: Vec<T>,
+    fail: Vec<usize>,
+}
+
+impl<T: Eq + Clone> KmpPattern<T> {
+    /// Creates a new `KmpPattern` for `find`, computing its failure function.
+    pub fn new(find: &[T]) -> Self {
+        KmpPattern { find: find.to_vec(), fail: failure_function(find) }
+    }
+
+    /// Returns the pattern.
+    pub fn pattern(&self) -> &[T] { &self.find }
+
+    /// Returns the failure function of the pattern.
+    pub fn failure(&self) -> &[usize] { &self.fail }
+
+    /// Returns a [`KmpStream`] to search for the pattern in a slice given in chunks.
+    ///
+    /// [`KmpStream`]: ./struct.KmpStream.html
+    pub fn stream(&self) -> KmpStream<'_, T> { KmpStream { kmp: self, matched: 0, read: 0 } }
+
+    /// Calls `report` with the index of each occurrence of the pattern in `text` while it
+    /// returns `true`.
+    fn run<B, I, F>(&self, text: I, mut report: F)
+    where
+        B: Borrow<T>,
+        I: IntoIterator<Item = B>,
+        F: FnMut(usize) -> bool,
+    {
+        let size_find = self.find.len();
+        // An empty pattern is found before and after every character.
+        if size_find == 0 && !report(0) {
+            return;
+        }
+
+        let mut k = 0;
+        for (i, c) in text.into_iter().enumerate() {
+            if size_find == 0 {
+                if !report(i + 1) {
+                    return;
+                }
+                continue;
+            }
+            k = self.step(k, c.borrow());
+            if k == size_find && !report(i + 1 - size_find) {
+                return;
+            }
+        }
+    }
+
+    /// Returns how much of the pattern is matched after reading `c`, when `k` characters
+    /// of it were matched before.
+    fn step(&self, mut k: usize, c: &T) -> usize {
+        if k == self.find.len() {
+            k = self.fail[k - 1];
+        }
+        while k > 0 && self.find[k] != *c {
+            k = self.fail[k - 1];
+        }
+        if self.find[k] == *c {
+            k += 1;
+        }
+        k
+    }
+}
+
+impl<T: Eq + Clone> Matcher<T> for KmpPattern<T> {
+    fn compile(find: &[T]) -> Self { Self::new(find) }
+
+    fn pattern_len(&self) -> usize { self.find.len() }
+
+    fn find(&self, text: &[T]) -> Option<usize> {
+        let mut found = None;
+        self.run(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[T]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.run(text, |i| {
+            found.push(i);
+            true
+        });
+        found
+    }
+}
+
+/// A pattern for the Knuth-Morris-Pratt algorithm that compares the keys extracted by a
+/// function, with its failure function already computed, see [`kmp_by_key`].
+///
+/// [`kmp_by_key`]: ./fn.kmp_by_key.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::KmpPatternByKey;
+///
+/// let kmp = KmpPatternByKey::new(b"ttt", u8::to_ascii_lowercase);
+///
+/// assert_eq!(kmp.find(b"atcggaTTTcagaagct"), Some(6));
+/// assert_eq!(kmp.find_all(b"TtTt"), [0, 1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmpPatternByKey<K, F> {
+    kmp: KmpPattern<K>,
+    key: F,
+}
+
+impl<K: Eq + Clone, F> KmpPatternByKey<K, F> {
+    /// Creates a new `KmpPatternByKey` for `find`, computing the failure function of its
+    /// keys.
+    pub fn new<T>(find: &[T], key: F) -> Self
+    where
+        F: Fn(&T) -> K,
+    {
+        KmpPatternByKey { kmp: KmpPattern::new(&find.iter().map(&key).collect::<Vec<K>>()), key }
+    }
+
+    /// Search for the pattern in `text`.
+    ///
+    /// It returns `Some` holding the index of the first character of the pattern that was
+    /// found or `None` if not find.
+    pub fn find<T>(&self, text: &[T]) -> Option<usize>
+    where
+        F: Fn(&T) -> K,
+    {
+        let mut found = None;
+        self.kmp.run(text.iter().map(&self.key), |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    /// Search for all the occurrences of the pattern in `text`, including the ones that
+    /// overlap.
+    ///
+    /// It returns the indexes of the first character of each one, in crescent order.
+    pub fn find_all<T>(&self, text: &[T]) -> Vec<usize>
+    where
+        F: Fn(&T) -> K,
+    {
+        let mut found = Vec::new();
+        self.kmp.run(text.iter().map(&self.key), |i| {
+            found.push(i);
+            true
+        });
+        found
+    }
+}
+
+/// Searches for a [`KmpPattern`] in a slice that is given in chunks, like a file that
+/// does not fit in memory.
+///
+/// It keeps how much of the pattern is matched at the end of the last chunk, so it finds
+/// the occurrences that start in a chunk and end in another one.
+///
+/// This `struct` is created by [`KmpPattern::stream`].
+///
+/// [`KmpPattern`]: ./struct.KmpPattern.html
+/// [`KmpPattern::stream`]: ./struct.KmpPattern.html#method.stream
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::KmpPattern;
+///
+/// let kmp = KmpPattern::new(b"TTT");
+/// let mut stream = kmp.stream();
+///
+/// assert_eq!(stream.push(b"ATCGGAT"), []);
+/// assert_eq!(stream.push(b"TTCAGAAGCTTT"), [6, 16]);
+/// assert_eq!(stream.push_reader(&b"TCTTT"[..]).unwrap(), [17, 21]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmpStream<'a, T = u8> {
+    kmp:     &'a KmpPattern<T>,
+    /// How much of the pattern is matched by the end of what was read.
+    matched: usize,
+    /// How many characters were read.
+    read:    u64,
+}
+
+impl<'a, T: Eq + Clone> KmpStream<'a, T> {
+    /// Returns how many characters were pushed.
+    pub fn position(&self) -> u64 { self.read }
+
+    /// Search in `chunk`, the continuation of the chunks pushed before.
+    ///
+    /// It returns the indexes of the first character of each occurrence of the pattern
+    /// that ends in `chunk`, counting from the start of the first chunk. An empty pattern
+    /// is found before each character.
+    pub fn push(&mut self, chunk: &[T]) -> Vec<u64> {
+        let size_find = self.kmp.find.len();
+        let mut found = Vec::new();
+        for c in chunk {
+            self.read += 1;
+            if size_find == 0 {
+                found.push(self.read - 1);
+                continue;
+            }
+            self.matched = self.kmp.step(self.matched, c);
+            if self.matched == size_find {
+                found.push(self.read - size_find as u64);
+            }
+        }
+        found
+    }
+}
+
+impl<'a> KmpStream<'a, u8> {
+    /// Search in everything read from `reader`, the continuation of the chunks pushed
+    /// before, reading it in chunks.
+    ///
+    /// It returns the indexes of the occurrences, like [`push`].
+    ///
+    /// [`push`]: #method.push
+    ///
+    /// # Errors
+    /// It returns any error of `reader`, other than [`ErrorKind::Interrupted`].
+    ///
+    /// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+    pub fn push_reader<R: Read>(&mut self, reader: R) -> io::Result<Vec<u64>> {
+        let mut found = Vec::new();
+        read_chunks(reader, |chunk| found.extend(self.push(chunk)))?;
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn failure_function_test() {
+        assert_eq!(failure_function(b""), []);
+        assert_eq!(failure_function(b"A"), [0]);
+        assert_eq!(failure_function(b"AAAA"), [0, 1, 2, 3]);
+        assert_eq!(failure_function(b"AABAAAB"), [0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(failure_function(b"ABCDE"), [0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn kmp_cases() {
+        let p = b"ATCGGATTTCAGAAGCT";
+
+        assert_eq!(kmp(p, b"ATC"), Some(0));
+        assert_eq!(kmp(p, b"TTT"), Some(6));
+        assert_eq!(kmp(p, b"AAG"), Some(12));
+        assert_eq!(kmp(p, b"GCT"), Some(14));
+        assert_eq!(kmp(p, b"TTTT"), None);
+        assert_eq!(kmp(p, b""), Some(0));
+        assert_eq!(kmp(b"", b"A"), None);
+        assert_eq!(kmp(b"AT", b"ATC"), None);
+    }
+
+    #[test]
+    fn kmp_random_test() {
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..200)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let expected = text.windows(find.len()).position(|w| w == &find[..]);
+
+            let matcher = KmpPattern::new(&find);
+            assert_eq!(matcher.find(&text), expected);
+            assert_eq!(kmp(&text, &find), expected);
+        }
+    }
+
+    #[test]
+    fn kmp_by_key_test() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            // Random letters with random case.
+            let text: Vec<u8> = (0..rng.gen_range(0..200))
+                .map(|_| rng.gen_range(b'A'..b'D') | rng.gen_range(0..2) << 5)
+                .collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..5)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let lower = text.to_ascii_lowercase();
+            let expected = KmpPattern::new(&find).find_all(&lower);
+
+            assert_eq!(kmp_by_key(&text, &find, u8::to_ascii_lowercase), expected.first().copied());
+            let matcher = KmpPatternByKey::new(&find, u8::to_ascii_uppercase);
+            assert_eq!(matcher.find(&text), expected.first().copied());
+            assert_eq!(matcher.find_all(&text), expected);
+        }
+        assert_eq!(KmpPatternByKey::new(b"", u8::to_ascii_lowercase).find_all(b"ab"), [0, 1, 2]);
+    }
+
+    #[test]
+    fn kmp_stream_test() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..300)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let matcher = KmpPattern::new(&find);
+            let expected: Vec<u64> =
+                matcher.find_all(&text).into_iter().map(|i| i as u64).collect();
+
+            let mut stream = matcher.stream();
+            let mut found = Vec::new();
+            let mut rest = &text[..];
+            while !rest.is_empty() {
+                let (chunk, next) = rest.split_at(rng.gen_range(0..=rest.len().min(7)));
+                found.extend(stream.push(chunk));
+                rest = next;
+            }
+            assert_eq!(found, expected);
+            assert_eq!(stream.position(), text.len() as u64);
+
+            assert_eq!(matcher.stream().push_reader(&text[..]).unwrap(), expected);
+        }
+
+        let empty = KmpPattern::new(b"");
+        let mut stream = empty.stream();
+        assert_eq!(stream.push(b"AB"), [0, 1]);
+        assert_eq!(stream.push(b"C"), [2]);
+    }
+}
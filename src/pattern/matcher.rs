@@ -0,0 +1,208 @@
+//! Pattern matching algorithms as types, to reuse their preprocessing.
+
+/// A pattern matching algorithm with the preprocessing of a pattern already done.
+///
+/// The patterns are slices of `T`, which is `u8` for most of the algorithms, the ones
+/// that only need to compare the elements for equality work with any `T`.
+///
+/// It is implemented by a type for each single pattern algorithm of this module, like
+/// [`KmpPattern`] and [`HorspoolPattern`], that keeps the tables of the pattern, so they
+/// are computed once and can be used to search in many slices.
+///
+/// [`KmpPattern`]: ./struct.KmpPattern.html
+/// [`HorspoolPattern`]: ./struct.HorspoolPattern.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::{HorspoolPattern, KmpPattern, Matcher};
+///
+/// fn count_lines<M: Matcher>(find: &[u8], lines: &[&[u8]]) -> usize {
+///     let matcher = M::compile(find);
+///     lines.iter().filter(|line| matcher.find(line).is_some()).count()
+/// }
+///
+/// let lines: [&[u8]; 3] = [b"ATCGGATTTC", b"AGAAGCT", b"GGATTA"];
+/// assert_eq!(count_lines::<KmpPattern>(b"GAT", &lines), 2);
+/// assert_eq!(count_lines::<HorspoolPattern>(b"GAT", &lines), 2);
+///
+/// let kmp = KmpPattern::compile(b"AA");
+/// assert_eq!(kmp.find_all(b"AAATAA"), [0, 1, 4]);
+/// assert_eq!(kmp.find_iter(b"AAATAA").non_overlapping().collect::<Vec<_>>(), [0, 4]);
+/// ```
+pub trait Matcher<T = u8>: Sized {
+    /// Does the preprocessing of the pattern in `find`.
+    fn compile(find: &[T]) -> Self;
+
+    /// Returns the size of the pattern.
+    fn pattern_len(&self) -> usize;
+
+    /// Search for the pattern in `text`.
+    ///
+    /// It returns `Some` holding the index of the first character of the pattern that was
+    /// found or `None` if not find.
+    fn find(&self, text: &[T]) -> Option<usize>;
+
+    /// Search for all the occurrences of the pattern in `text`, including the ones that
+    /// overlap.
+    ///
+    /// It returns the indexes of the first character of each one, in crescent order.
+    fn find_all(&self, text: &[T]) -> Vec<usize> { self.find_iter(text).collect() }
+
+    /// Returns an iterator over the indexes of the first character of each occurrence of
+    /// the pattern in `text`, in crescent order.
+    ///
+    /// The occurrences are searched as the iterator advances. It includes the ones that
+    /// overlap, use [`Matches::non_overlapping`] to skip them.
+    ///
+    /// [`Matches::non_overlapping`]: ./struct.Matches.html#method.non_overlapping
+    fn find_iter<'m, 't>(&'m self, text: &'t [T]) -> Matches<'m, 't, Self, T> {
+        Matches { matcher: self, text, start: 0, overlapping: true }
+    }
+}
+
+/// Iterator over the occurrences of a pattern in a slice.
+///
+/// This `struct` is created by [`Matcher::find_iter`].
+///
+/// [`Matcher::find_iter`]: ./trait.Matcher.html#method.find_iter
+#[derive(Debug, Clone)]
+pub struct Matches<'m, 't, M, T = u8> {
+    matcher:     &'m M,
+    text:        &'t [T],
+    /// Where the next search starts.
+    start:       usize,
+    overlapping: bool,
+}
+
+impl<'m, 't, T, M: Matcher<T>> Matches<'m, 't, M, T> {
+    /// Makes the iterator skip the occurrences that overlap with the last one returned, so
+    /// the next search starts after the end of it.
+    pub fn non_overlapping(mut self) -> Self {
+        self.overlapping = false;
+        self
+    }
+}
+
+impl<'m, 't, T, M: Matcher<T>> Iterator for Matches<'m, 't, M, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = self.text.get(self.start..).and_then(|text| self.matcher.find(text));
+        let i = match found {
+            Some(i) => self.start + i,
+            None => {
+                self.start = self.text.len() + 1;
+                return None;
+            },
+        };
+
+        // An empty pattern is found at every index, so it has to move forward anyway.
+        self.start = if self.overlapping { i + 1 } else { i + self.matcher.pattern_len().max(1) };
+        Some(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern::{
+        BitapPattern, BoyerMoorePattern, BruteForcePattern, HorspoolPattern, KarpRabinPattern,
+        KmpPattern, QuickPattern, ZPattern,
+    };
+    use rand::prelude::{thread_rng, Rng};
+
+    fn matcher_test<M: Matcher>() {
+        let p = b"ATCGGATTTCAGAAGCT";
+        let matcher = M::compile(b"TTT");
+        assert_eq!(matcher.find(p), Some(6));
+        assert_eq!(matcher.find(b"TTAGTTCTT"), None);
+        assert_eq!(matcher.find(b"TT"), None);
+        assert_eq!(M::compile(b"").find_all(b"AT"), [0, 1, 2]);
+        assert_eq!(M::compile(b"T").find_all(p), [1, 6, 7, 8, 16]);
+        assert_eq!(M::compile(b"").find_iter(b"AT").non_overlapping().collect::<Vec<_>>(), [
+            0, 1, 2
+        ]);
+        assert_eq!(M::compile(b"TT").find_iter(p).non_overlapping().collect::<Vec<_>>(), [6]);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..200)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let expected: Vec<usize> = text
+                .windows(find.len())
+                .enumerate()
+                .filter(|(_, w)| *w == &find[..])
+                .map(|(i, _)| i)
+                .collect();
+
+            let matcher = M::compile(&find);
+            assert_eq!(matcher.pattern_len(), find.len());
+            assert_eq!(matcher.find(&text), expected.first().copied());
+            assert_eq!(matcher.find_all(&text), expected);
+            assert_eq!(matcher.find_iter(&text).collect::<Vec<_>>(), expected);
+
+            let (mut non_overlapping, mut next) = (Vec::new(), 0);
+            for &i in &expected {
+                if i >= next {
+                    non_overlapping.push(i);
+                    next = i + find.len();
+                }
+            }
+            let found: Vec<usize> = matcher.find_iter(&text).non_overlapping().collect();
+            assert_eq!(found, non_overlapping);
+        }
+    }
+
+    fn generic_matcher_test<M: Matcher<Event>>() {
+        use Event::*;
+        let events = [Open, Read(1), Read(2), Close, Open, Read(1), Read(2), Read(2), Close];
+
+        let matcher = M::compile(&[Read(1), Read(2)]);
+        assert_eq!(matcher.find(&events), Some(1));
+        assert_eq!(matcher.find_all(&events), [1, 5]);
+        assert_eq!(M::compile(&[Read(2), Open]).find(&events), None);
+        assert_eq!(M::compile(&[Close]).find_all(&events), [3, 8]);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Event {
+        Open,
+        Read(u32),
+        Close,
+    }
+
+    #[test]
+    fn bruteforce_generic_matcher_test() { generic_matcher_test::<BruteForcePattern<Event>>(); }
+
+    #[test]
+    fn kmp_generic_matcher_test() { generic_matcher_test::<KmpPattern<Event>>(); }
+
+    #[test]
+    fn z_generic_matcher_test() { generic_matcher_test::<ZPattern<Event>>(); }
+
+    #[test]
+    fn bruteforce_matcher_test() { matcher_test::<BruteForcePattern>(); }
+
+    #[test]
+    fn karp_rabin_matcher_test() { matcher_test::<KarpRabinPattern>(); }
+
+    #[test]
+    fn boyer_moore_matcher_test() { matcher_test::<BoyerMoorePattern>(); }
+
+    #[test]
+    fn horspool_matcher_test() { matcher_test::<HorspoolPattern>(); }
+
+    #[test]
+    fn quick_matcher_test() { matcher_test::<QuickPattern>(); }
+
+    #[test]
+    fn kmp_matcher_test() { matcher_test::<KmpPattern>(); }
+
+    #[test]
+    fn z_matcher_test() { matcher_test::<ZPattern>(); }
+
+    #[test]
+    fn bitap_matcher_test() { matcher_test::<BitapPattern>(); }
+}
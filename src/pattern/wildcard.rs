@@ -0,0 +1,162 @@
+//! Wildcard matching implementation.
+
+/// **Wildcard Matching:** Returns `true` if the whole `text` matches `glob`.
+///
+/// In `glob`, `?` matches any byte, `*` matches any sequence of bytes, even empty, and a
+/// class like `[a-z_]` matches any byte in it, with ranges, or not in it if it starts with
+/// `!` or `^`. A `]` right after the start of a class is a byte of it, and a `[` without a
+/// `]` after it is a normal byte. Every other byte matches itself.
+///
+/// It goes through both slices with a pointer each, and when a byte does not match, it
+/// goes back to the last `*` found, making it match one more byte of `text`, so it never
+/// goes back further than that.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(n+m)          |                  |
+/// | Worst:    | O(n*m)          | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::pattern::wildcard;
+///
+/// assert!(wildcard(b"main.rs", b"*.rs"));
+/// assert!(wildcard(b"file01.txt", b"file[0-9][0-9].???"));
+/// assert!(!wildcard(b"file1a.txt", b"file[0-9][0-9].*"));
+/// assert!(wildcard(b"report.pdf", b"*[!~]"));
+/// ```
+pub fn wildcard(text: &[u8], glob: &[u8]) -> bool {
+    let (mut t, mut g) = (0, 0);
+    // Where the glob continues after the last `*`, and where the text was when it was found
+    // plus the bytes it matches.
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if let Some(&c) = glob.get(g) {
+            let next = match c {
+                b'*' => {
+                    star = Some((g + 1, t));
+                    g += 1;
+                    continue;
+                },
+                b'?' => Some(g + 1),
+                b'[' => match class(glob, g, text[t]) {
+                    Some((true, end)) => Some(end),
+                    Some((false, _)) => None,
+                    None if text[t] == b'[' => Some(g + 1),
+                    None => None,
+                },
+                c if c == text[t] => Some(g + 1),
+                _ => None,
+            };
+            if let Some(next) = next {
+                g = next;
+                t += 1;
+                continue;
+            }
+        }
+
+        match star {
+            Some((after, start)) => {
+                star = Some((after, start + 1));
+                g = after;
+                t = start + 1;
+            },
+            None => return false,
+        }
+    }
+
+    glob[g..].iter().all(|&c| c == b'*')
+}
+
+/// Returns if `c` matches the class that starts at `glob[start]`, and the index after the
+/// end of the class, or `None` if it has no end.
+fn class(glob: &[u8], start: usize, c: u8) -> Option<(bool, usize)> {
+    let mut i = start + 1;
+    let negate = matches!(glob.get(i), Some(b'!') | Some(b'^'));
+    if negate {
+        i += 1;
+    }
+
+    let (mut matched, mut first) = (false, true);
+    loop {
+        let low = *glob.get(i)?;
+        if low == b']' && !first {
+            return Some((matched != negate, i + 1));
+        }
+        first = false;
+        match (glob.get(i + 1), glob.get(i + 2)) {
+            (Some(b'-'), Some(&high)) if high != b']' => {
+                matched |= low <= c && c <= high;
+                i += 3;
+            },
+            _ => {
+                matched |= low == c;
+                i += 1;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Matches by trying every size for each `*`, without classes.
+    fn naive(text: &[u8], glob: &[u8]) -> bool {
+        match glob.split_first() {
+            None => text.is_empty(),
+            Some((b'*', rest)) => (0..=text.len()).any(|i| naive(&text[i..], rest)),
+            Some((&c, rest)) => match text.split_first() {
+                Some((&t, text)) => (c == b'?' || c == t) && naive(text, rest),
+                None => false,
+            },
+        }
+    }
+
+    #[test]
+    fn wildcard_test() {
+        assert!(wildcard(b"", b""));
+        assert!(wildcard(b"", b"***"));
+        assert!(!wildcard(b"", b"?"));
+        assert!(!wildcard(b"a", b""));
+        assert!(wildcard(b"abc", b"a*c"));
+        assert!(wildcard(b"abc", b"*b*"));
+        assert!(!wildcard(b"abc", b"*d*"));
+        assert!(wildcard(b"aaab", b"*a*b"));
+        assert!(!wildcard(b"aaa", b"*a*b"));
+        assert!(wildcard(b"mississippi", b"m*iss*ppi"));
+        assert!(!wildcard(b"mississippi", b"m*iss*ppi?"));
+    }
+
+    #[test]
+    fn class_test() {
+        assert!(wildcard(b"b", b"[abc]"));
+        assert!(!wildcard(b"d", b"[abc]"));
+        assert!(wildcard(b"d", b"[!abc]"));
+        assert!(wildcard(b"d", b"[^abc]"));
+        assert!(wildcard(b"5", b"[0-9]"));
+        assert!(wildcard(b"-", b"[a-]"));
+        assert!(wildcard(b"]", b"[]a]"));
+        assert!(wildcard(b"[a", b"[a"));
+        assert!(!wildcard(b"a", b"[a"));
+        assert!(wildcard(b"x.rs", b"*.[cr]s"));
+        assert!(wildcard(b"AbC", b"[A-Z][a-z][A-Z]"));
+        assert!(!wildcard(b"Abc", b"[A-Z][a-z][A-Z]"));
+    }
+
+    #[test]
+    fn wildcard_random_test() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..12)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let glob: Vec<u8> = (0..rng.gen_range(0..8))
+                .map(|_| [b'a', b'b', b'?', b'*'][rng.gen_range(0..4)])
+                .collect();
+            assert_eq!(wildcard(&text, &glob), naive(&text, &glob));
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Z-algorithm implementation.
+
+use super::Matcher;
+
+/// **Z-Array:** Computes the Z-array of `text`.
+///
+/// The value at `i` is the length of the longest common prefix of `text` and
+/// `text[i..]`, so the value at `0` is the length of `text` itself.
+///
+/// It works with slices of any type that can be compared for equality.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let z = pattern::z_array(b"AABXAAB");
+/// assert_eq!(z, [7, 1, 0, 0, 3, 1, 0]);
+/// ```
+pub fn z_array<T: Eq>(text: &[T]) -> Vec<usize> {
+    let n = text.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+
+    // `text[left..right]` is the match with a prefix of `text` that ends the furthest.
+    let (mut left, mut right) = (0, 0);
+    for i in 1..n {
+        if i < right {
+            z[i] = z[i - left].min(right - i);
+        }
+        while i + z[i] < n && text[z[i]] == text[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+    }
+    z
+}
+
+/// **Z-Algorithm:** Search for the pattern in the `find` parameter in a slice.
+///
+/// It returns `Some` holding the index of the first character of `find` that was found
+/// or `None` if not find.
+///
+/// It computes the [`z_array`] of `find`, and uses it the same way the Z-array is
+/// computed to get the longest common prefix of `find` and each position of the slice,
+/// where every position with a prefix of the size of `find` is a match. To search for
+/// the same `find` many times, use [`ZPattern`].
+///
+/// It works with slices of any type that can be compared for equality.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+m)          |                  |
+/// | Avrg:     | Θ(n+m)          |                  |
+/// | Worst:    | O(n+m)          | O(m)             |
+///
+/// [`z_array`]: ./fn.z_array.html
+/// [`ZPattern`]: ./struct.ZPattern.html
+///
+/// # Example
+/// ```rust
+/// use algos::pattern;
+///
+/// let p = b"ATCGGATTTCAGAAGCT";
+///
+/// let find = pattern::z_search(p, b"TTT");
+/// assert_eq!(find, Some(6));
+/// ```
+pub fn z_search<T: Eq + Clone>(pattern: &[T], find: &[T]) -> Option<usize> {
+    ZPattern::new(find).find(pattern)
+}
+
+/// A pattern for the Z-algorithm with its Z-array already computed, see [`z_search`].
+///
+/// [`z_search`]: ./fn.z_search.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZPattern<T = u8> {
+    find: Vec<T>,
+    z:    Vec<usize>,
+}
+
+impl<T: Eq + Clone> ZPattern<T> {
+    /// Creates a new `ZPattern` for `find`, computing its Z-array.
+    pub fn new(find: &[T]) -> Self { ZPattern { find: find.to_vec(), z: z_array(find) } }
+
+    /// Calls `report` with the index of each occurrence of the pattern in `text` while it
+    /// returns `true`.
+    fn run<F: FnMut(usize) -> bool>(&self, text: &[T], mut report: F) {
+        let (size_patt, size_find) = (text.len(), self.find.len());
+
+        // `text[left..right]` is the match with a prefix of `find` that ends the furthest.
+        let (mut left, mut right) = (0, 0);
+        for i in 0..=size_patt {
+            let mut lcp = 0;
+            if i < right {
+                lcp = self.z[i - left].min(right - i);
+            }
+            if i + lcp >= right {
+                while lcp < size_find && i + lcp < size_patt && self.find[lcp] == text[i + lcp] {
+                    lcp += 1;
+                }
+                left = i;
+                right = i + lcp;
+            }
+            if lcp == size_find && !report(i) {
+                return;
+            }
+        }
+    }
+}
+
+impl<T: Eq + Clone> Matcher<T> for ZPattern<T> {
+    fn compile(find: &[T]) -> Self { Self::new(find) }
+
+    fn pattern_len(&self) -> usize { self.find.len() }
+
+    fn find(&self, text: &[T]) -> Option<usize> {
+        let mut found = None;
+        self.run(text, |i| {
+            found = Some(i);
+            false
+        });
+        found
+    }
+
+    fn find_all(&self, text: &[T]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.run(text, |i| {
+            found.push(i);
+            true
+        });
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn z_array_test() {
+        assert_eq!(z_array(b""), []);
+        assert_eq!(z_array(b"A"), [1]);
+        assert_eq!(z_array(b"AAAA"), [4, 3, 2, 1]);
+        assert_eq!(z_array(b"ABACABA"), [7, 0, 1, 0, 3, 0, 1]);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..100)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let naive: Vec<usize> = (0..text.len())
+                .map(|i| text[i..].iter().zip(&text).take_while(|(a, b)| a == b).count())
+                .collect();
+            assert_eq!(z_array(&text), naive);
+        }
+    }
+
+    #[test]
+    fn z_search_cases() {
+        let p = b"ATCGGATTTCAGAAGCT";
+
+        assert_eq!(z_search(p, b"ATC"), Some(0));
+        assert_eq!(z_search(p, b"TTT"), Some(6));
+        assert_eq!(z_search(p, b"AAG"), Some(12));
+        assert_eq!(z_search(p, b"GCT"), Some(14));
+        assert_eq!(z_search(p, b"TTTT"), None);
+        assert_eq!(z_search(p, b""), Some(0));
+        assert_eq!(z_search(b"AT", b"ATC"), None);
+    }
+}
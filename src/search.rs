@@ -1,6 +1,32 @@
 //! A module for using searching algorithms.
 //!
-//! The array **must** be crescent ordered.
+//! The array **must** be crescent ordered, unless said otherwise.
+//!
+//! Besides the functions that search for a value, some searches have other flavors, with
+//! the same comparator conventions of the [`sort`] module:
+//!  - `search::<name>_find(&v, &x, &|a, b| a < b)`: takes a `bool` closure that returns
+//!    `true` if `a` must come before `b`, and returns the index of `x`, if found.
+//!  - `search::<name>_by(&v, |e| e.cmp(&x))`: takes a function that returns the
+//!    [`Ordering`] of each element relative to the target, like
+//!    [`slice::binary_search_by`].
+//!  - `search::<name>_by_key(&v, &key, |e| e.key)`: takes a function that extracts a key
+//!    that implements [`Ord`] from the elements, like [`slice::binary_search_by_key`].
+//!
+//! [`sort`]: ../sort/index.html
+//! [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+//! [`slice::binary_search_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by
+//! [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+//! [`slice::binary_search_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by_key
+
+use std::{
+    cmp::Ordering,
+    ops::{Range, RangeInclusive},
+};
+
+use crate::{
+    numerics::{Fib, NumericKey},
+    sort::{insection_sort, partition3_at},
+};
 
 /// **Linear Search:** Search for the value `x` in an array.
 ///
@@ -13,140 +39,1540 @@
 ///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
-/// | Best:     | Ω(1)            |                  |
-/// | Avrg:     | Θ(n)            |                  |
-/// | Worst:    | O(n)            | O(1)             |
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::linear(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::linear(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn linear<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
+    for (i, e) in v.iter().enumerate() {
+        if e == x {
+            return Ok(i);
+        } else if e > x {
+            return Err(i);
+        }
+    }
+    Err(0)
+}
+
+/// **Binary Search:** Search for the value `x` in an array.
+///
+/// If the value is found then `Ok` is returned, containing the index of the matching
+/// element; if the value is not found then `Err` is returned, containing the index where
+/// a matching element could be inserted while maintaining sorted order.
+///
+/// Returns `Err` holding the leftmost term if `x` not found.
+///
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::binary(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::binary(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn binary<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
+    binary_search(v, &mut |e| partial_cmp(e, x))
+}
+
+/// **Binary Search:** Search for the value `x` in an array sorted according to the way
+/// you define the `cmp` parameter.
+///
+/// Same as [`binary`], but with a `bool` comparator and returning only the index of a
+/// matching element, if any. Two elements match if neither one is less than the other
+/// according to `cmp`.
+///
+/// [`binary`]: ./fn.binary.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [23, 17, 11, 8, 4, 3, 1];
+///
+/// // Decrescent order
+/// let find = search::binary_find(&v, &11, &|a, b| a > b);
+/// assert_eq!(find, Some(2));
+///
+/// let find2 = search::binary_find(&v, &19, &|a, b| a > b);
+/// assert_eq!(find2, None);
+/// ```
+pub fn binary_find<T, C: Fn(&T, &T) -> bool>(v: &[T], x: &T, cmp: &C) -> Option<usize> {
+    binary_search(v, &mut |e| {
+        if cmp(e, x) {
+            Ordering::Less
+        } else if cmp(x, e) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+    .ok()
+}
+
+/// **Binary Search:** Search with the `f` function, that returns the [`Ordering`] of each
+/// element relative to the target.
+///
+/// Same as [`binary`], but with a comparator function, like
+/// [`slice::binary_search_by`].
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`binary`]: ./fn.binary.html
+/// [`slice::binary_search_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::binary_by(&v, |e| e.cmp(&11));
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::binary_by(&v, |e| e.cmp(&19));
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn binary_by<T, F: FnMut(&T) -> Ordering>(v: &[T], mut f: F) -> Result<usize, usize> {
+    binary_search(v, &mut f)
+}
+
+/// **Binary Search:** Search for the `key` in an array sorted by the key extracted by the
+/// `f` function.
+///
+/// Same as [`binary`], but comparing the keys returned by `f`, like
+/// [`slice::binary_search_by_key`].
+///
+/// [`binary`]: ./fn.binary.html
+/// [`slice::binary_search_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by_key
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (4, 'c'), (8, 'd'), (11, 'e')];
+///
+/// let find = search::binary_by_key(&v, &8, |&(n, _)| n);
+/// assert_eq!(find, Ok(3));
+///
+/// let find2 = search::binary_by_key(&v, &2, |&(n, _)| n);
+/// assert_eq!(find2, Err(1));
+/// ```
+pub fn binary_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+    v: &[T], key: &K, mut f: F,
+) -> Result<usize, usize> {
+    binary_search(v, &mut |e| f(e).cmp(key))
+}
+
+fn binary_search<T, F: FnMut(&T) -> Ordering>(v: &[T], f: &mut F) -> Result<usize, usize> {
+    // Searches in `v[l..r]`, so `r` is never an index of the array.
+    let (mut l, mut r) = (0, v.len());
+    while l < r {
+        // This has the same result as (l+r)/2, but without overflowing.
+        let mid = l + (r - l) / 2;
+
+        match f(&v[mid]) {
+            Ordering::Greater => r = mid,
+            Ordering::Less => l = mid + 1,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(l)
+}
+
+/// Compares `e` to `x`, where incomparable values are considered equal.
+fn partial_cmp<T: PartialOrd>(e: &T, x: &T) -> Ordering {
+    if e < x {
+        Ordering::Less
+    } else if e > x {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// **Lower Bound:** Returns the index of the first element of the array that is not less
+/// than `x`.
+///
+/// If there are elements equal to `x`, it is the index of the first one, otherwise it is
+/// the index where `x` could be inserted while maintaining sorted order. If every element
+/// is less than `x`, it is the length of the array.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 3, 3, 8, 11];
+///
+/// assert_eq!(search::lower_bound(&v, &3), 1);
+/// assert_eq!(search::lower_bound(&v, &4), 4);
+/// assert_eq!(search::lower_bound(&v, &12), 6);
+/// ```
+pub fn lower_bound<T: PartialOrd>(v: &[T], x: &T) -> usize {
+    bound(v, &mut |e| partial_cmp(e, x) == Ordering::Less)
+}
+
+/// **Lower Bound:** Returns the index of the first element of the array for which the `f`
+/// function, that returns the [`Ordering`] of each element relative to the target,
+/// doesn't return `Less`.
+///
+/// Same as [`lower_bound`], but with a comparator function.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`lower_bound`]: ./fn.lower_bound.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [11, 8, 3, 3, 3, 1];
+///
+/// // Decrescent order
+/// assert_eq!(search::lower_bound_by(&v, |e| 3.cmp(e)), 2);
+/// ```
+pub fn lower_bound_by<T, F: FnMut(&T) -> Ordering>(v: &[T], mut f: F) -> usize {
+    bound(v, &mut |e| f(e) == Ordering::Less)
+}
+
+/// **Lower Bound:** Returns the index of the first element of the array whose key,
+/// extracted by the `f` function, is not less than `key`.
+///
+/// Same as [`lower_bound`], but comparing the keys returned by `f`.
+///
+/// [`lower_bound`]: ./fn.lower_bound.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+///
+/// assert_eq!(search::lower_bound_by_key(&v, &3, |&(n, _)| n), 1);
+/// ```
+pub fn lower_bound_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], key: &K, mut f: F) -> usize {
+    bound(v, &mut |e| f(e) < *key)
+}
+
+/// **Upper Bound:** Returns the index of the first element of the array that is greater
+/// than `x`.
+///
+/// If there are elements equal to `x`, it is the index after the last one, otherwise it
+/// is the index where `x` could be inserted while maintaining sorted order. If no element
+/// is greater than `x`, it is the length of the array.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 3, 3, 8, 11];
+///
+/// assert_eq!(search::upper_bound(&v, &3), 4);
+/// assert_eq!(search::upper_bound(&v, &4), 4);
+/// assert_eq!(search::upper_bound(&v, &0), 0);
+/// ```
+pub fn upper_bound<T: PartialOrd>(v: &[T], x: &T) -> usize {
+    bound(v, &mut |e| partial_cmp(e, x) != Ordering::Greater)
+}
+
+/// **Upper Bound:** Returns the index of the first element of the array for which the `f`
+/// function, that returns the [`Ordering`] of each element relative to the target,
+/// returns `Greater`.
+///
+/// Same as [`upper_bound`], but with a comparator function.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`upper_bound`]: ./fn.upper_bound.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [11, 8, 3, 3, 3, 1];
+///
+/// // Decrescent order
+/// assert_eq!(search::upper_bound_by(&v, |e| 3.cmp(e)), 5);
+/// ```
+pub fn upper_bound_by<T, F: FnMut(&T) -> Ordering>(v: &[T], mut f: F) -> usize {
+    bound(v, &mut |e| f(e) != Ordering::Greater)
+}
+
+/// **Upper Bound:** Returns the index of the first element of the array whose key,
+/// extracted by the `f` function, is greater than `key`.
+///
+/// Same as [`upper_bound`], but comparing the keys returned by `f`.
+///
+/// [`upper_bound`]: ./fn.upper_bound.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+///
+/// assert_eq!(search::upper_bound_by_key(&v, &3, |&(n, _)| n), 3);
+/// ```
+pub fn upper_bound_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], key: &K, mut f: F) -> usize {
+    bound(v, &mut |e| f(e) <= *key)
+}
+
+/// **Equal Range:** Returns the [`lower_bound`] and the [`upper_bound`] of `x` in the
+/// array.
+///
+/// The elements equal to `x` are the ones between both indexes, and if there is none both
+/// are the index where `x` could be inserted while maintaining sorted order.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// [`lower_bound`]: ./fn.lower_bound.html
+/// [`upper_bound`]: ./fn.upper_bound.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 3, 3, 8, 11];
+///
+/// let (lo, hi) = search::equal_range(&v, &3);
+/// assert_eq!((lo, hi), (1, 4));
+/// assert_eq!(&v[lo..hi], &[3, 3, 3]);
+///
+/// assert_eq!(search::equal_range(&v, &5), (4, 4));
+/// ```
+pub fn equal_range<T: PartialOrd>(v: &[T], x: &T) -> (usize, usize) {
+    equal_range_search(v, &mut |e| partial_cmp(e, x))
+}
+
+/// **Equal Range:** Returns the [`lower_bound_by`] and the [`upper_bound_by`] of the
+/// target in the array, with the `f` function that returns the [`Ordering`] of each
+/// element relative to the target.
+///
+/// Same as [`equal_range`], but with a comparator function.
+///
+/// [`lower_bound_by`]: ./fn.lower_bound_by.html
+/// [`upper_bound_by`]: ./fn.upper_bound_by.html
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`equal_range`]: ./fn.equal_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [11, 8, 3, 3, 3, 1];
+///
+/// // Decrescent order
+/// assert_eq!(search::equal_range_by(&v, |e| 3.cmp(e)), (2, 5));
+/// ```
+pub fn equal_range_by<T, F: FnMut(&T) -> Ordering>(v: &[T], mut f: F) -> (usize, usize) {
+    equal_range_search(v, &mut f)
+}
+
+/// **Equal Range:** Returns the [`lower_bound_by_key`] and the [`upper_bound_by_key`] of
+/// `key` in the array, comparing it to the keys extracted by the `f` function.
+///
+/// Same as [`equal_range`], but comparing the keys returned by `f`.
+///
+/// [`lower_bound_by_key`]: ./fn.lower_bound_by_key.html
+/// [`upper_bound_by_key`]: ./fn.upper_bound_by_key.html
+/// [`equal_range`]: ./fn.equal_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+///
+/// assert_eq!(search::equal_range_by_key(&v, &3, |&(n, _)| n), (1, 3));
+/// ```
+pub fn equal_range_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+    v: &[T], key: &K, mut f: F,
+) -> (usize, usize) {
+    equal_range_search(v, &mut |e| f(e).cmp(key))
+}
+
+/// **Find Range:** Returns the range of the elements of the array equal to `x`.
+///
+/// It is the [`equal_range`] as a `Range`, so it can index the array directly. If there
+/// is no element equal to `x`, the range is empty and starts where `x` could be inserted
+/// while maintaining sorted order.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// [`equal_range`]: ./fn.equal_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 3, 3, 8, 11];
+///
+/// let range = search::find_range(&v, &3);
+/// assert_eq!(range, 1..4);
+/// assert_eq!(&v[range], &[3, 3, 3]);
+///
+/// assert!(search::find_range(&v, &5).is_empty());
+/// ```
+pub fn find_range<T: PartialOrd>(v: &[T], x: &T) -> Range<usize> {
+    let (lo, hi) = equal_range(v, x);
+    lo..hi
+}
+
+/// **Find Range:** Returns the range of the elements of the array for which the `f`
+/// function, that returns the [`Ordering`] of each element relative to the target,
+/// returns `Equal`.
+///
+/// Same as [`find_range`], but with a comparator function.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`find_range`]: ./fn.find_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = ["apple", "banana", "blueberry", "cherry"];
+///
+/// // Every word starting with 'b'
+/// let range = search::find_range_by(&v, |w| w.as_bytes()[0].cmp(&b'b'));
+/// assert_eq!(&v[range], &["banana", "blueberry"]);
+/// ```
+pub fn find_range_by<T, F: FnMut(&T) -> Ordering>(v: &[T], f: F) -> Range<usize> {
+    let (lo, hi) = equal_range_by(v, f);
+    lo..hi
+}
+
+/// **Find Range:** Returns the range of the elements of the array whose key, extracted by
+/// the `f` function, is equal to `key`.
+///
+/// Same as [`find_range`], but comparing the keys returned by `f`.
+///
+/// [`find_range`]: ./fn.find_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+///
+/// let range = search::find_range_by_key(&v, &3, |&(n, _)| n);
+/// assert_eq!(&v[range], &[(3, 'b'), (3, 'c')]);
+/// ```
+pub fn find_range_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], key: &K, f: F) -> Range<usize> {
+    let (lo, hi) = equal_range_by_key(v, key, f);
+    lo..hi
+}
+
+/// **Count:** Returns the number of elements of the array equal to `x`.
+///
+/// It is the length of the [`find_range`] of `x`, so it doesn't look at each element.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// [`find_range`]: ./fn.find_range.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 3, 3, 8, 11];
+///
+/// assert_eq!(search::count(&v, &3), 3);
+/// assert_eq!(search::count(&v, &5), 0);
+/// ```
+pub fn count<T: PartialOrd>(v: &[T], x: &T) -> usize { find_range(v, x).len() }
+
+/// Returns the index of the first element for which `is_before` returns `false`, where it
+/// returns `true` for every element before it and `false` for every element after it.
+fn bound<T, F: FnMut(&T) -> bool>(v: &[T], is_before: &mut F) -> usize {
+    let (mut l, mut r) = (0, v.len());
+    while l < r {
+        let mid = l + (r - l) / 2;
+        if is_before(&v[mid]) {
+            l = mid + 1;
+        } else {
+            r = mid;
+        }
+    }
+    l
+}
+
+fn equal_range_search<T, F: FnMut(&T) -> Ordering>(v: &[T], f: &mut F) -> (usize, usize) {
+    // Once an equal element is found, the bounds are searched at each side of it.
+    match binary_search(v, f) {
+        Ok(mid) => {
+            let lo = bound(&v[..mid], &mut |e| f(e) == Ordering::Less);
+            let hi = mid + 1 + bound(&v[mid + 1..], &mut |e| f(e) != Ordering::Greater);
+            (lo, hi)
+        },
+        Err(i) => (i, i),
+    }
+}
+
+/// **Exponential Search:** Search for the value `x` in an array.
+///
+/// If the value is found then `Ok` is returned, containing the index of the matching
+/// element; if the value is not found then `Err` is returned, containing the index where
+/// a matching element could be inserted while maintaining sorted order.
+///
+/// It doubles the index of the probe until it overshoots `x`, and then does a binary
+/// search between the last two probes. So it only looks at the beginning of the array if
+/// `x` is there, which makes it the search of choice for huge or unbounded arrays, and
+/// for galloping in merges.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(log(i))       |                  |
+/// | Worst:    | O(log(i))       | O(1)             |
+///
+/// **Obs.:** Variation of binary search, where `i` is the index where `x` is or would be
+/// inserted.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::exponential(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::exponential(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn exponential<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
+    exponential_search(v, &mut |e| partial_cmp(e, x))
+}
+
+/// **Exponential Search:** Search with the `f` function, that returns the [`Ordering`] of
+/// each element relative to the target.
+///
+/// Same as [`exponential`], but with a comparator function.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`exponential`]: ./fn.exponential.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [23, 17, 11, 8, 4, 3, 1];
+///
+/// // Decrescent order
+/// let find = search::exponential_by(&v, |e| 8.cmp(e));
+/// assert_eq!(find, Ok(3));
+/// ```
+pub fn exponential_by<T, F: FnMut(&T) -> Ordering>(v: &[T], mut f: F) -> Result<usize, usize> {
+    exponential_search(v, &mut f)
+}
+
+/// **Exponential Search:** Search for the `key` in an array sorted by the key extracted
+/// by the `f` function.
+///
+/// Same as [`exponential`], but comparing the keys returned by `f`.
+///
+/// [`exponential`]: ./fn.exponential.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (4, 'c'), (8, 'd'), (11, 'e')];
+///
+/// let find = search::exponential_by_key(&v, &8, |&(n, _)| n);
+/// assert_eq!(find, Ok(3));
+/// ```
+pub fn exponential_by_key<T, K: Ord, F: FnMut(&T) -> K>(
+    v: &[T], key: &K, mut f: F,
+) -> Result<usize, usize> {
+    exponential_search(v, &mut |e| f(e).cmp(key))
+}
+
+fn exponential_search<T, F: FnMut(&T) -> Ordering>(v: &[T], f: &mut F) -> Result<usize, usize> {
+    let mut bound = 1;
+    while bound < v.len() && f(&v[bound]) == Ordering::Less {
+        bound *= 2;
+    }
+
+    // The target is after `v[bound / 2]` and not after `v[bound]`.
+    let start = bound / 2;
+    let end = (bound + 1).min(v.len());
+    match binary_search(&v[start..end], f) {
+        Ok(i) => Ok(start + i),
+        Err(i) => Err(start + i),
+    }
+}
+
+/// **Unbounded Search:** Search in a conceptually infinite crescent sequence, given only
+/// by the `f` function, that returns the [`Ordering`] of the element at each index
+/// relative to the target.
+///
+/// There's no slice, so it works for monotone functions that are expensive to compute,
+/// like "the first commit after a timestamp". It probes the indexes `0, 1, 3, 7, 15, ...`
+/// until one is not less than the target, like [`exponential`], and then does a binary
+/// search between the last two probes.
+///
+/// If an index where `f` returns [`Ordering::Equal`] is found then `Ok` is returned,
+/// containing the first such index; otherwise `Err` is returned, containing the first
+/// index where `f` returns [`Ordering::Greater`]. If `f` is less than the target in every
+/// index, returns `Err(u64::MAX)`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(log(i))       |                  |
+/// | Worst:    | O(log(i))       | O(1)             |
+///
+/// **Obs.:** `i` is the index that is returned, and the time complexity is the number of
+/// calls to `f`.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`Ordering::Equal`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Equal
+/// [`Ordering::Greater`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Greater
+/// [`exponential`]: ./fn.exponential.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// // The cube root of 1331
+/// let find = search::unbounded(|i| (i * i * i).cmp(&1331));
+/// assert_eq!(find, Ok(11));
+///
+/// // The first square greater than 50
+/// let find2 = search::unbounded(|i| (i * i).cmp(&50));
+/// assert_eq!(find2, Err(8));
+/// ```
+pub fn unbounded<F: FnMut(u64) -> Ordering>(mut f: F) -> Result<u64, u64> {
+    let (mut lo, mut hi) = (0, 0);
+    let mut order = f(hi);
+    while order == Ordering::Less {
+        if hi == u64::MAX {
+            return Err(u64::MAX);
+        }
+        lo = hi + 1;
+        hi = hi.saturating_mul(2).saturating_add(1);
+        order = f(hi);
+    }
+
+    // Everything before `lo` is less than the target and `order` is the one of `hi`.
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match f(mid) {
+            Ordering::Less => lo = mid + 1,
+            o => {
+                hi = mid;
+                order = o;
+            },
+        }
+    }
+
+    if order == Ordering::Equal {
+        Ok(hi)
+    } else {
+        Err(hi)
+    }
+}
+
+/// **Multi Search:** Search for each one of the `keys` in an array, with the `f`
+/// function, that returns the [`Ordering`] of an element relative to a key.
+///
+/// Returns, for each key in the order they are given, the index of a matching element, if
+/// any. The keys are sorted and the array is walked once from the start to the end, doing
+/// an [`exponential`] search for each key from where the previous one stopped, so it
+/// touches the array in order instead of doing a binary search from the root for each
+/// key. That's much friendlier to the cache for many keys.
+///
+/// `f` must be consistent with the order of the keys, i.e. if `a < b` and `f(e, &a)` is
+/// [`Ordering::Greater`] then so is `f(e, &b)`.
+///
+/// |   Case    | Time complexity        | Space complexity |
+/// |:----------|:----------------------:|:----------------:|
+/// | Best:     | Ω(mlog(m))             |                  |
+/// | Avrg:     | Θ(mlog(m) + mlog(n/m)) |                  |
+/// | Worst:    | O(mlog(m) + mlog(n/m)) | O(m)             |
+///
+/// **Obs.:** m is the number of keys, and mlog(m) is the time to sort them.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`Ordering::Greater`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html#variant.Greater
+/// [`exponential`]: ./fn.exponential.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(1, 'a'), (3, 'b'), (4, 'c'), (8, 'd'), (11, 'e')];
+///
+/// let find = search::multi(&v, &[8, 2, 11, 1], |&(n, _), k| n.cmp(k));
+/// assert_eq!(find, [Some(3), None, Some(4), Some(0)]);
+/// ```
+pub fn multi<T, K: Ord, F: FnMut(&T, &K) -> Ordering>(
+    v: &[T], keys: &[K], mut f: F,
+) -> Vec<Option<usize>> {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_unstable_by(|&a, &b| keys[a].cmp(&keys[b]));
+
+    let mut found = vec![None; keys.len()];
+    let mut start = 0;
+    for i in order {
+        // Every element before `start` is less than the previous key, so less than this one.
+        match exponential_search(&v[start..], &mut |e| f(e, &keys[i])) {
+            Ok(j) => {
+                start += j;
+                found[i] = Some(start);
+            },
+            Err(j) => start += j,
+        }
+    }
+    found
+}
+
+/// **Rotated Search:** Search for the value `x` in an array that was sorted according to
+/// the way you define the `cmp` parameter and then rotated.
+///
+/// Returns the index of a matching element, if any. The array is `w[k..]` followed by
+/// `w[..k]` for some sorted array `w`, like a circular buffer or the segments of a log.
+/// It finds the [`rotation_point`] `k` and then does a binary search in the part where
+/// `x` would be.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// **Obs.:** The worst case is only for arrays with many repeated elements, otherwise it
+/// is O(log(n)).
+///
+/// [`rotation_point`]: ./fn.rotation_point.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [11, 17, 23, 1, 3, 4, 8];
+///
+/// let find = search::rotated(&v, &4, &|a, b| a < b);
+/// assert_eq!(find, Some(5));
+///
+/// let find2 = search::rotated(&v, &19, &|a, b| a < b);
+/// assert_eq!(find2, None);
+/// ```
+pub fn rotated<T, C: Fn(&T, &T) -> bool>(v: &[T], x: &T, cmp: &C) -> Option<usize> {
+    let k = rotation_point(v, cmp);
+    // `v[..k]` has the greatest elements, all of them not less than `v[0]`.
+    if k > 0 && !cmp(x, &v[0]) {
+        binary_find(&v[..k], x, cmp)
+    } else {
+        binary_find(&v[k..], x, cmp).map(|i| k + i)
+    }
+}
+
+/// **Rotation Point:** Returns the index of the smallest element of an array that was
+/// sorted according to the way you define the `cmp` parameter and then rotated.
+///
+/// It is the `k` such that `v[k..]` followed by `v[..k]` is sorted, and it is zero if the
+/// array is sorted or empty. Each step compares the middle element to the last one to
+/// know at which side of the middle the rotation is.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// **Obs.:** The worst case is only for arrays with many repeated elements, where the
+/// middle and the last elements are often equal.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [11, 17, 23, 1, 3, 4, 8];
+/// assert_eq!(search::rotation_point(&v, &|a, b| a < b), 3);
+/// ```
+pub fn rotation_point<T, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> usize {
+    if v.is_empty() {
+        return 0;
+    }
+
+    let (mut l, mut r) = (0, v.len() - 1);
+    while l < r {
+        let mid = l + (r - l) / 2;
+        if cmp(&v[r], &v[mid]) {
+            // The rotation is after the middle.
+            l = mid + 1;
+        } else if cmp(&v[mid], &v[r]) {
+            r = mid;
+        } else if cmp(&v[r], &v[r - 1]) {
+            // Equal elements say nothing, unless the last one is where the rotation is.
+            return r;
+        } else {
+            r -= 1;
+        }
+    }
+    l
+}
+
+/// **Saddleback Search:** Search for the value `x` in a matrix where every row and every
+/// column is in crescent order.
+///
+/// Returns the row and the column of a matching element, if any. It starts at the top
+/// right corner and walks like a staircase: if the element is greater than `x`, the rest
+/// of its column is too, so it moves left, and if it is less than `x`, the rest of its
+/// row is too, so it moves down.
+///
+/// All rows must have the same length.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(r + c)        |                  |
+/// | Worst:    | O(r + c)        | O(1)             |
+///
+/// **Obs.:** `r` is the number of rows and `c` the number of columns.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let m = [[1, 4, 7, 11], [2, 5, 8, 12], [3, 6, 9, 16], [10, 13, 14, 17]];
+///
+/// assert_eq!(search::matrix(&m, &9), Some((2, 2)));
+/// assert_eq!(search::matrix(&m, &15), None);
+/// ```
+pub fn matrix<T: PartialOrd, R: AsRef<[T]>>(m: &[R], x: &T) -> Option<(usize, usize)> {
+    let cols = m.first().map_or(0, |row| row.as_ref().len());
+    saddleback(m.len(), cols, &|r, c| &m[r].as_ref()[c], x)
+}
+
+/// **Saddleback Search:** Search for the value `x` in a matrix stored by rows in the `v`
+/// array, with `cols` columns, where every row and every column is in crescent order.
+///
+/// Same as [`matrix`], but for a flat array, like the one of a
+/// [`numerics::matrix::Matrix`].
+///
+/// [`matrix`]: ./fn.matrix.html
+/// [`numerics::matrix::Matrix`]: ../numerics/matrix/struct.Matrix.html
+///
+/// # Panics
+/// This function panics if the length of `v` is not a multiple of `cols`.
+///
+/// # Example
+/// ```rust
+/// use algos::{numerics::matrix::Matrix, search};
+///
+/// let m = Matrix::from_vec(2, 3, vec![1, 3, 5, 2, 4, 6]);
+/// assert_eq!(search::matrix_flat(m.as_slice(), m.cols(), &4), Some((1, 1)));
+/// ```
+pub fn matrix_flat<T: PartialOrd>(v: &[T], cols: usize, x: &T) -> Option<(usize, usize)> {
+    if cols == 0 {
+        assert!(v.is_empty(), "the length must be a multiple of the columns");
+        return None;
+    }
+    assert_eq!(v.len() % cols, 0, "the length must be a multiple of the columns");
+    saddleback(v.len() / cols, cols, &|r, c| &v[r * cols + c], x)
+}
+
+fn saddleback<'a, T: PartialOrd + 'a, G: Fn(usize, usize) -> &'a T>(
+    rows: usize, cols: usize, get: &G, x: &T,
+) -> Option<(usize, usize)> {
+    if cols == 0 {
+        return None;
+    }
+
+    let (mut r, mut c) = (0, cols - 1);
+    while r < rows {
+        let e = get(r, c);
+        if e > x {
+            if c == 0 {
+                break;
+            }
+            c -= 1;
+        } else if e < x {
+            r += 1;
+        } else {
+            return Some((r, c));
+        }
+    }
+    None
+}
+
+/// **Interpolation Search:** Search for the value `x` in an array of numbers.
+///
+/// If the value is found then `Ok` is returned, containing the index of the matching
+/// element; if the value is not found then `Err` is returned, containing the index where
+/// a matching element could be inserted while maintaining sorted order.
+///
+/// Instead of probing the middle of the range, it probes where `x` would be if the
+/// numbers grew linearly between both ends of the range, like looking for a name in a
+/// phone book. For uniformly distributed numbers it only needs `log(log(n))` probes.
+/// Probes that don't shrink the range at least by half are bad, and after `log(n)` of
+/// them it continues with a binary search, so the worst case is still logarithmic.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(log(log(n)))  |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// **Obs.:** The average case is for uniformly distributed numbers.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::interpolation(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::interpolation(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn interpolation<T: NumericKey>(v: &[T], x: &T) -> Result<usize, usize> {
+    interpolation_search(v, *x, &mut |&e| e)
+}
+
+/// **Interpolation Search:** Search for the `key` in an array sorted by the numeric key
+/// extracted by the `f` function.
+///
+/// Same as [`interpolation`], but interpolating the keys returned by `f`, like the ids of
+/// a table of records.
+///
+/// [`interpolation`]: ./fn.interpolation.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [(100_u32, "ana"), (200, "bia"), (300, "caio"), (400, "davi")];
+///
+/// let find = search::interpolation_by_key(&v, &300, |&(id, _)| id);
+/// assert_eq!(find, Ok(2));
+/// ```
+pub fn interpolation_by_key<T, K, F>(v: &[T], key: &K, mut f: F) -> Result<usize, usize>
+where
+    K: NumericKey,
+    F: FnMut(&T) -> K,
+{
+    interpolation_search(v, *key, &mut f)
+}
+
+fn interpolation_search<T, K, F>(v: &[T], x: K, key: &mut F) -> Result<usize, usize>
+where
+    K: NumericKey,
+    F: FnMut(&T) -> K,
+{
+    let (mut l, mut r) = (0, v.len());
+    // Number of bad probes still allowed, about log(n).
+    let mut bad_probes = (usize::BITS - v.len().leading_zeros()) as usize;
+
+    while l < r {
+        if bad_probes == 0 {
+            return match binary_search(&v[l..r], &mut |e| partial_cmp(&key(e), &x)) {
+                Ok(i) => Ok(l + i),
+                Err(i) => Err(l + i),
+            };
+        }
+
+        let (lo, hi) = (key(&v[l]), key(&v[r - 1]));
+        if x < lo {
+            return Err(l);
+        } else if x > hi {
+            return Err(r);
+        }
+        let (lo, hi, xf) = (lo.to_f64(), hi.to_f64(), x.to_f64());
+        let pos =
+            if hi > lo { l + ((xf - lo) / (hi - lo) * (r - 1 - l) as f64) as usize } else { l };
+        let pos = pos.min(r - 1);
+
+        let len = r - l;
+        match partial_cmp(&key(&v[pos]), &x) {
+            Ordering::Greater => r = pos,
+            Ordering::Less => l = pos + 1,
+            Ordering::Equal => return Ok(pos),
+        }
+        if r - l > len / 2 {
+            bad_probes -= 1;
+        }
+    }
+    Err(l)
+}
+
+/// **Jump Search:** Search for the value `x` in an array.
+///
+/// If the value is found then `Ok` is returned, containing the index of the matching
+/// element; if the value is not found then `Err` is returned, containing the index where
+/// a matching element could be inserted while maintaining sorted order.
+///
+/// It jumps over blocks of `√n` elements until it finds the block where `x` would be,
+/// and then does a linear search in it. It only moves forward, and a single time
+/// backward, so it is useful when going back is expensive.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(√n)           |                  |
+/// | Worst:    | O(√n)           | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::jump(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::jump(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn jump<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
+    let step = ((v.len() as f64).sqrt() as usize).max(1);
+    jump_with(v, x, step)
+}
+
+/// **Jump Search:** Search for the value `x` in an array, jumping over blocks of `step`
+/// elements.
+///
+/// Same as [`jump`], but with a custom block size. It does about `n/step + step`
+/// comparisons.
+///
+/// [`jump`]: ./fn.jump.html
+///
+/// # Panics
+/// This function panics if `step` is zero.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::jump_with(&v, &11, 3);
+/// assert_eq!(find, Ok(4));
+/// ```
+pub fn jump_with<T: PartialOrd>(v: &[T], x: &T, step: usize) -> Result<usize, usize> {
+    assert!(step > 0, "the step must be greater than zero");
+
+    // Jumps while the last element of the block is less than `x`.
+    let (mut start, mut end) = (0, step.min(v.len()));
+    while end < v.len() && v[end - 1] < *x {
+        start = end;
+        end = (end + step).min(v.len());
+    }
+
+    for (i, e) in v[start..end].iter().enumerate() {
+        if e == x {
+            return Ok(start + i);
+        } else if e > x {
+            return Err(start + i);
+        }
+    }
+    Err(end)
+}
+
+/// **Fibonacci Search:** Search for the value `x` in an array.
+///
+/// If the value is found then `Ok` is returned, containing the index of the matching
+/// element; if the value is not found then `Err` is returned, containing the index where
+/// a matching element could be inserted while maintaining sorted order.
+///
+/// It brackets `x` in ranges whose lengths are [Fibonacci numbers] minus one, splitting
+/// a range of `F(k) - 1` elements into ranges of `F(k-1) - 1` and `F(k-2) - 1` elements
+/// around the probe. So the indexes are computed only with additions and subtractions,
+/// without divisions.
+///
+/// **Obs.:** Variation of binary search.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// [Fibonacci numbers]: ../numerics/struct.Fib.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let v = [1, 3, 4, 8, 11, 17, 23];
+///
+/// let find = search::fibonacci(&v, &11);
+/// assert_eq!(find, Ok(4));
+///
+/// let find2 = search::fibonacci(&v, &19);
+/// assert_eq!(find2, Err(6));
+/// ```
+pub fn fibonacci<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
+    // The smallest `F(k)` such that `F(k) - 1 >= v.len()`, as `(F(k-2), F(k-1))`.
+    let (mut fib2, mut fib1) = Fib::new()
+        .zip(Fib::at(1_u8))
+        .map(|(a, b)| (a as usize, b as usize))
+        .find(|&(a, b)| a + b > v.len())
+        .unwrap_or_default();
+
+    // Searches in `v[l..l + F(k) - 1]`, where the elements past the end of the array are
+    // greater than `x`.
+    let mut l = 0;
+    while fib1 + fib2 > 1 {
+        let i = l + fib1 - 1;
+
+        let ord = if i < v.len() { partial_cmp(&v[i], x) } else { Ordering::Greater };
+        match ord {
+            Ordering::Greater => {
+                // Goes to the `F(k-1) - 1` elements at the left.
+                fib1 -= fib2;
+                std::mem::swap(&mut fib1, &mut fib2);
+            },
+            Ordering::Less => {
+                // Goes to the `F(k-2) - 1` elements at the right.
+                l = i + 1;
+                fib1 -= fib2;
+                fib2 -= fib1;
+            },
+            Ordering::Equal => return Ok(i),
+        }
+    }
+    Err(l)
+}
+
+/// **Ternary Search:** Returns the `x` in `range` where the unimodal function `f` is
+/// minimum.
+///
+/// `f` must decrease and then increase over `range`, like a convex function, and may be
+/// constant only at its minimum. Each step evaluates `f` at two points splitting the
+/// range in thirds and discards the third where the minimum can't be. If there are
+/// several minimums, any of them is returned.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// **Obs.:** `n` is the length of the `range`, and the time is the number of calls to
+/// `f`.
+///
+/// # Panics
+/// This function panics if `range` is empty.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let x = search::ternary_min(-100..=100, |x| (x - 7) * (x - 7));
+/// assert_eq!(x, 7);
+/// ```
+pub fn ternary_min<T: PartialOrd, F: FnMut(i64) -> T>(range: RangeInclusive<i64>, mut f: F) -> i64 {
+    ternary(range, &mut f, &mut |a, b| a < b)
+}
+
+/// **Ternary Search:** Returns the `x` in `range` where the unimodal function `f` is
+/// maximum.
+///
+/// Same as [`ternary_min`], but `f` must increase and then decrease, like a concave
+/// function.
+///
+/// [`ternary_min`]: ./fn.ternary_min.html
+///
+/// # Panics
+/// This function panics if `range` is empty.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// // The price that maximizes the profit, when each unit more sells 2 items less.
+/// let x = search::ternary_max(0..=50, |price| price * (100 - 2 * price));
+/// assert_eq!(x, 25);
+/// ```
+pub fn ternary_max<T: PartialOrd, F: FnMut(i64) -> T>(range: RangeInclusive<i64>, mut f: F) -> i64 {
+    ternary(range, &mut f, &mut |a, b| a > b)
+}
+
+/// **Ternary Search:** Returns the `x` in the `[lo, hi]` interval where the unimodal
+/// function `f` is minimum, within `tolerance`.
+///
+/// Same as [`ternary_min`], but over real numbers. It stops when the interval is not
+/// larger than `tolerance`, or when it can't be split anymore because of the precision
+/// of `f64`, so a `tolerance` of zero gives the most precise answer.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(log(n))       |                  |
+/// | Avrg:     | Θ(log(n))       |                  |
+/// | Worst:    | O(log(n))       | O(1)             |
+///
+/// **Obs.:** `n` is `(hi - lo) / tolerance`, and the time is the number of calls to `f`.
+///
+/// [`ternary_min`]: ./fn.ternary_min.html
 ///
 /// # Example
 /// ```rust
 /// use algos::search;
 ///
-/// let v = [1, 3, 4, 8, 11, 17, 23];
+/// let x = search::ternary_min_float(0.0, 4.0, |x| (x - 1.5) * (x - 1.5), 1e-9);
+/// assert!((x - 1.5).abs() < 1e-6);
+/// ```
+pub fn ternary_min_float<T: PartialOrd, F: FnMut(f64) -> T>(
+    lo: f64, hi: f64, mut f: F, tolerance: f64,
+) -> f64 {
+    ternary_float(lo, hi, &mut f, tolerance, &mut |a, b| a < b)
+}
+
+/// **Ternary Search:** Returns the `x` in the `[lo, hi]` interval where the unimodal
+/// function `f` is maximum, within `tolerance`.
 ///
-/// let find = search::linear(&v, &11);
-/// assert_eq!(find, Ok(4));
+/// Same as [`ternary_min_float`], but `f` must increase and then decrease, like a concave
+/// function.
 ///
-/// let find2 = search::linear(&v, &19);
-/// assert_eq!(find2, Err(6));
+/// [`ternary_min_float`]: ./fn.ternary_min_float.html
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let x = search::ternary_max_float(0.0, 3.0, f64::sin, 0.0);
+/// assert!((x - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
 /// ```
-pub fn linear<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
-    for (i, e) in v.iter().enumerate() {
-        if e == x {
-            return Ok(i);
-        } else if e > x {
-            return Err(i);
+pub fn ternary_max_float<T: PartialOrd, F: FnMut(f64) -> T>(
+    lo: f64, hi: f64, mut f: F, tolerance: f64,
+) -> f64 {
+    ternary_float(lo, hi, &mut f, tolerance, &mut |a, b| a > b)
+}
+
+/// Ternary search where `is_better` returns `true` if its first argument is closer to
+/// the extremum.
+fn ternary<T, F, B>(range: RangeInclusive<i64>, f: &mut F, is_better: &mut B) -> i64
+where
+    F: FnMut(i64) -> T,
+    B: FnMut(&T, &T) -> bool,
+{
+    let (mut lo, mut hi) = range.into_inner();
+    assert!(lo <= hi, "the range must not be empty");
+
+    // `abs_diff` doesn't overflow even for the whole range of `i64`.
+    while hi.abs_diff(lo) > 2 {
+        let third = (hi.abs_diff(lo) / 3) as i64;
+        let (m1, m2) = (lo + third, hi - third);
+        if is_better(&f(m1), &f(m2)) {
+            // The extremum is not after `m2`.
+            hi = m2 - 1;
+        } else {
+            // The extremum is not before `m1`, or `m2` is as good as it.
+            lo = m1 + 1;
         }
     }
-    Err(0)
+
+    let mut best = (lo, f(lo));
+    for x in lo + 1..=hi {
+        let fx = f(x);
+        if is_better(&fx, &best.1) {
+            best = (x, fx);
+        }
+    }
+    best.0
 }
 
-/// **Binary Search:** Search for the value `x` in an array.
-///
-/// If the value is found then `Ok` is returned, containing the index of the matching
-/// element; if the value is not found then `Err` is returned, containing the index where
-/// a matching element could be inserted while maintaining sorted order.
+fn ternary_float<T, F, B>(
+    mut lo: f64, mut hi: f64, f: &mut F, tolerance: f64, is_better: &mut B,
+) -> f64
+where
+    F: FnMut(f64) -> T,
+    B: FnMut(&T, &T) -> bool,
+{
+    loop {
+        // Each end is divided before subtracting, so it doesn't overflow to infinity even
+        // for the whole range of `f64`.
+        let third = hi / 3.0 - lo / 3.0;
+        if third * 3.0 <= tolerance {
+            break;
+        }
+        let (m1, m2) = (lo + third, hi - third);
+        if !(lo < m1 && m1 < m2 && m2 < hi) {
+            // The interval can't be split anymore.
+            break;
+        }
+        if is_better(&f(m1), &f(m2)) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+    lo / 2.0 + hi / 2.0
+}
+
+/// **Partition Point:** Returns the first `x` in `range` for which the monotone predicate
+/// `pred` returns `true`, or the end of the `range` if there is none.
 ///
-/// Returns `Err` holding the leftmost term if `x` not found.
+/// `pred` must return `false` for every value before some point and `true` for every
+/// value from it on, and the binary search finds that point. It is the "binary search the
+/// answer" technique: the smallest value that satisfies a condition, like the smallest
+/// capacity that fits some load, without an array of the values.
 ///
+/// Note that it is the opposite of [`slice::partition_point`], where the predicate is
+/// `true` for the elements before the point.
 ///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
-/// | Best:     | Ω(1)            |                  |
+/// | Best:     | Ω(log(n))       |                  |
 /// | Avrg:     | Θ(log(n))       |                  |
 /// | Worst:    | O(log(n))       | O(1)             |
 ///
+/// **Obs.:** `n` is the length of the `range`, and the time is the number of calls to
+/// `pred`.
+///
+/// [`slice::partition_point`]: https://doc.rust-lang.org/std/primitive.slice.html#method.partition_point
+///
 /// # Example
 /// ```rust
 /// use algos::search;
 ///
-/// let v = [1, 3, 4, 8, 11, 17, 23];
-///
-/// let find = search::binary(&v, &11);
-/// assert_eq!(find, Ok(4));
+/// // The integer square root of 1000.
+/// let x = search::partition_point(0..1000, |x| x * x > 1000) - 1;
+/// assert_eq!(x, 31);
 ///
-/// let find2 = search::binary(&v, &19);
-/// assert_eq!(find2, Err(6));
+/// // The smallest speed to eat 30 bananas in piles of 3, 6, 7 and 11 in 8 hours.
+/// let piles = [3, 6, 7, 11];
+/// let hours = |speed: i64| piles.iter().map(|p| (p + speed - 1) / speed).sum::<i64>();
+/// assert_eq!(search::partition_point(1..12, |speed| hours(speed) <= 8), 4);
 /// ```
-pub fn binary<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
-    let (mut l, mut r) = (0, v.len());
-    // Looks like I'm unable to make v recursive implementation, so I made interative.
-    while l <= r {
-        // This has the same result as (l+r)/2, but it's probably the same or faster.
-        let mid = (l + r) >> 1;
-
-        if v[mid] > *x {
-            r = mid - 1;
-        } else if v[mid] < *x {
-            l = mid + 1;
+pub fn partition_point<P: FnMut(i64) -> bool>(range: Range<i64>, mut pred: P) -> i64 {
+    let (mut lo, mut hi) = (range.start, range.end);
+    while lo < hi {
+        let mid = lo + (hi.abs_diff(lo) / 2) as i64;
+        if pred(mid) {
+            hi = mid;
         } else {
-            return Ok(mid);
+            lo = mid + 1;
         }
     }
-    Err(l)
+    lo
 }
 
-/// **Exponential Search:** Search for the value `x` in an array.
+/// **Partition Point:** Returns the first `x` in `range` for which the monotone predicate
+/// `pred` returns `true`, or the end of the `range` if there is none.
 ///
-/// If the value is found then `Ok` is returned, containing the index of the matching
-/// element; if the value is not found then `Err` is returned, containing the index where
-/// a matching element could be inserted while maintaining sorted order.
+/// Same as [`partition_point`], but over the `f64`s of the `range`. It searches the
+/// `f64`s themselves, not the real numbers, so the result is exact: `pred` is `false` for
+/// the `f64` right before it. It needs at most 64 calls to `pred`.
 ///
-/// Returns `Err` holding the leftmost term if `x` not found.
+/// [`partition_point`]: ./fn.partition_point.html
 ///
-/// **Obs.:** Variation of binary search.
+/// # Panics
+/// This function panics if any end of the `range` is `NaN`.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let x = search::partition_point_float(0.0..2.0, |x| x * x >= 2.0);
+/// assert!(x * x >= 2.0);
+/// assert!((x - std::f64::consts::SQRT_2).abs() <= f64::EPSILON);
+/// ```
+pub fn partition_point_float<P: FnMut(f64) -> bool>(range: Range<f64>, mut pred: P) -> f64 {
+    assert!(!range.start.is_nan() && !range.end.is_nan(), "the range must not have NaN");
+    let range = float_key(range.start)..float_key(range.end);
+    float_from_key(partition_point(range, |key| pred(float_from_key(key))))
+}
+
+/// Returns a key of `x` with the same order of `f64::total_cmp`.
+fn float_key(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    bits ^ (((bits >> 63) as u64) >> 1) as i64
+}
+
+/// Returns the `f64` of the `key`, the inverse of `float_key`.
+fn float_from_key(key: i64) -> f64 {
+    f64::from_bits((key ^ (((key >> 63) as u64) >> 1) as i64) as u64)
+}
+
+/// **Min-Max:** Returns the indexes of the minimum and the maximum elements of the array,
+/// according to the way you define the `cmp` parameter.
+///
+/// The array doesn't need to be sorted. It takes the elements in pairs and compares the
+/// smaller one of each pair only to the minimum and the greater one only to the maximum,
+/// so it needs `3⌈n/2⌉` comparisons at most, instead of the `2n` of looking for both
+/// separately. If there are several minimums it returns the first one, and if there are
+/// several maximums the last one, like [`Iterator::min_by`] and [`Iterator::max_by`].
+///
+/// Returns `None` if the array is empty.
 ///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
-/// | Best:     | Ω(1)            |                  |
-/// | Avrg:     | Θ(log(n))       |                  |
-/// | Worst:    | O(log(n))       | O(1)             |
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// [`Iterator::min_by`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min_by
+/// [`Iterator::max_by`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max_by
 ///
 /// # Example
 /// ```rust
 /// use algos::search;
 ///
-/// let v = [1, 3, 4, 8, 11, 17, 23];
-///
-/// let find = search::exponential(&v, &11);
-/// assert_eq!(find, Ok(4));
+/// let v = [9, 3, 5, 7, 8, 3, 9];
 ///
-/// let find2 = search::exponential(&v, &19);
-/// assert_eq!(find2, Err(6));
+/// assert_eq!(search::min_max(&v, &|a, b| a < b), Some((1, 6)));
+/// assert_eq!(search::min_max::<i32, _>(&[], &|a, b| a < b), None);
 /// ```
-pub fn exponential<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
-    if v[0] == *x {
-        return Ok(0);
-    }
+pub fn min_max<T, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> Option<(usize, usize)> {
+    let (mut min, mut max, start) = match v.len() {
+        0 => return None,
+        len if len % 2 == 1 => (0, 0, 1),
+        _ if cmp(&v[1], &v[0]) => (1, 0, 2),
+        _ => (0, 1, 2),
+    };
 
-    let mut range = 1;
-    while range < v.len() && v[range] <= *x {
-        range *= 2;
+    for i in (start..v.len()).step_by(2) {
+        let (small, large) = if cmp(&v[i + 1], &v[i]) { (i + 1, i) } else { (i, i + 1) };
+        if cmp(&v[small], &v[min]) {
+            min = small;
+        }
+        if !cmp(&v[large], &v[max]) {
+            max = large;
+        }
     }
+    Some((min, max))
+}
+
+/// **Median of Medians:** Reorders `v` so that the element at the `k` position is the one
+/// that would be there if `v` was sorted according to the way you define the `cmp`
+/// parameter, with a guaranteed linear worst case (BFPRT algorithm).
+///
+/// After it, the elements before `k` are not greater than `v[k]` and the elements after
+/// it are not less than `v[k]`, in any order. Unlike the quickselect of
+/// [`sort::select_nth`], the pivot isn't random: it is the median of the medians of
+/// groups of 5 elements, found recursively, so every partition discards at least 3/10 of
+/// the elements. It is slower than quickselect on average, but never quadratic.
+///
+/// The array doesn't need to be sorted.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(log(n))        |
+///
+/// [`sort::select_nth`]: ../sort/fn.select_nth.html
+///
+/// # Panic
+/// This function panics if `k` is not smaller than the `v` size.
+///
+/// # Example
+/// ```rust
+/// use algos::search;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7, 1];
+/// // The median
+/// search::select_deterministic(&mut v, 3, &|a, b| a < b);
+/// assert_eq!(v[3], 7);
+/// assert!(v[..3].iter().all(|&x| x <= 7) && v[4..].iter().all(|&x| x >= 7));
+/// ```
+pub fn select_deterministic<T, C: Fn(&T, &T) -> bool>(v: &mut [T], k: usize, cmp: &C) {
+    assert!(k < v.len(), "k is {} but the slice size is {}", k, v.len());
+    median_of_medians(v, k, &mut |a, b| cmp(a, b));
+}
 
-    let (mut l, mut r) = (range / 2, range.min(v.len()));
-    while l <= r {
-        // This has the same result as (l+r)/2, but it's probably the same or faster.
-        let mid = (l + r) >> 1;
+fn median_of_medians<T, F: FnMut(&T, &T) -> bool>(mut v: &mut [T], mut k: usize, is_less: &mut F) {
+    while v.len() > 5 {
+        // Moves the median of each group of 5 to the start and selects the median of them.
+        let (len, groups) = (v.len(), v.len().div_ceil(5));
+        for g in 0..groups {
+            let group = &mut v[g * 5..(g * 5 + 5).min(len)];
+            insection_sort(group, is_less);
+            let median = g * 5 + (group.len() - 1) / 2;
+            v.swap(g, median);
+        }
+        median_of_medians(&mut v[..groups], groups / 2, is_less);
 
-        if v[mid] > *x {
-            r = mid - 1;
-        } else if v[mid] < *x {
-            l = mid + 1;
+        let (lt, gt) = partition3_at(v, groups / 2, is_less);
+        if k < lt {
+            v = &mut std::mem::take(&mut v)[..lt];
+        } else if k >= gt {
+            v = &mut std::mem::take(&mut v)[gt..];
+            k -= gt;
         } else {
-            return Ok(mid);
+            // `k` is among the elements equal to the pivot.
+            return;
         }
     }
-    Err(l)
+    insection_sort(v, is_less);
 }
 
-/// **Fibonacci Search:** Search for the value `x` in an array.
+/// When [`bisect_float`] stops.
 ///
-/// If the value is found then `Ok` is returned, containing the index of the matching
-/// element; if the value is not found then `Err` is returned, containing the index where
-/// a matching element could be inserted while maintaining sorted order.
+/// It also stops when the interval can't be split anymore because of the precision of
+/// `f64`, whatever the tolerance.
 ///
+/// [`bisect_float`]: ./fn.bisect_float.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// When the interval is not larger than the value.
+    Absolute(f64),
+    /// When the interval is not larger than the value times the magnitude of its ends.
+    Relative(f64),
+    /// After the number of iterations, each one halving the interval.
+    Iterations(u32),
+}
+
+/// **Bisection:** Returns the `x` in the `[lo, hi]` interval where the monotone function
+/// `f` crosses zero, within the `tolerance`.
 ///
-/// **Obs.:** Variation of binary search.
+/// `f(lo)` and `f(hi)` must not have the same sign, and each iteration keeps the half of
+/// the interval whose ends still have opposite signs, so it finds a root of a continuous
+/// function, or the threshold where a monotone function changes sign. It returns the
+/// middle of the last interval, or the point where `f` is exactly zero.
+///
+/// Returns `None` if `f(lo)` and `f(hi)` have the same sign or any of them is `NaN`.
 ///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
@@ -154,59 +1580,67 @@ pub fn exponential<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
 /// | Avrg:     | Θ(log(n))       |                  |
 /// | Worst:    | O(log(n))       | O(1)             |
 ///
+/// **Obs.:** `n` is the length of the interval divided by the tolerance, and the time is
+/// the number of calls to `f`.
+///
 /// # Example
 /// ```rust
-/// use algos::search;
+/// use algos::search::{self, Tolerance};
 ///
-/// let v = [1, 3, 4, 8, 11, 17, 23];
+/// let x = search::bisect_float(0.0, 2.0, |x| x * x - 2.0, Tolerance::Absolute(1e-12));
+/// assert!((x.unwrap() - std::f64::consts::SQRT_2).abs() < 1e-12);
 ///
-/// let find = search::fibonacci(&v, &11);
-/// assert_eq!(find, Ok(4));
+/// // The temperature where the water boils at 0.7 atm, with a hundredth of precision.
+/// let pressure = |t: f64| (13.7 - 5120.0 / (t + 273.15)).exp();
+/// let t = search::bisect_float(0.0, 100.0, |t| pressure(t) - 0.7, Tolerance::Relative(1e-4));
+/// assert!((t.unwrap() - 91.09).abs() < 0.01);
 ///
-/// let find2 = search::fibonacci(&v, &19);
-/// assert_eq!(find2, Err(3));
+/// assert_eq!(search::bisect_float(0.0, 1.0, |x| x + 1.0, Tolerance::Iterations(10)), None);
 /// ```
-pub fn fibonacci<T: PartialOrd>(v: &[T], x: &T) -> Result<usize, usize> {
-    let (mut fib1, mut fib2) = (0, 1);
-    let mut fibn = fib1 + fib2;
-
-    // We are recalculating numbers, we can do better using dynamic programming.
-    // Stores the smallest Fibonacci Number greater than or equal to v.len().
-    while fibn < v.len() {
-        fib1 = fib2;
-        fib2 = fibn;
-        fibn = fib1 + fib2;
-    }
-
-    let mut off = 0;
-    while fibn > 1 {
-        let i = (off + fib1 - 1).min(v.len());
-
-        if v[i] < *x {
-            fibn = fib2;
-            fib2 = fib1;
-            fib1 = fibn - fib2;
-            off = i;
-        } else if v[i] > *x {
-            fibn = fib1;
-            fib2 -= fib1;
-            fib1 = fibn - fib2;
-        } else {
-            return Ok(i);
-        }
+pub fn bisect_float<F: FnMut(f64) -> f64>(
+    mut lo: f64, mut hi: f64, mut f: F, tolerance: Tolerance,
+) -> Option<f64> {
+    let (f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo == 0.0 {
+        return Some(lo);
+    } else if f_hi == 0.0 {
+        return Some(hi);
+    } else if f_lo.is_nan() || f_hi.is_nan() || (f_lo < 0.0) == (f_hi < 0.0) {
+        return None;
     }
+    let lo_is_negative = f_lo < 0.0;
 
-    if fib2 == 1 && v[off + 1] == *x {
-        return Ok(off + 1);
-    }
+    let mut iterations = 0;
+    loop {
+        // Each end is halved before the subtraction and the sum, so they don't overflow to
+        // infinity even for the whole range of `f64`.
+        let width = (hi / 2.0 - lo / 2.0).abs() * 2.0;
+        let done = match tolerance {
+            Tolerance::Absolute(eps) => width <= eps,
+            Tolerance::Relative(eps) => width <= eps * lo.abs().max(hi.abs()),
+            Tolerance::Iterations(n) => iterations >= n,
+        };
+        let mid = lo / 2.0 + hi / 2.0;
+        if done || mid == lo || mid == hi {
+            return Some(mid);
+        }
 
-    Err(off)
+        let f_mid = f(mid);
+        if f_mid == 0.0 {
+            return Some(mid);
+        } else if (f_mid < 0.0) == lo_is_negative {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        iterations += 1;
+    }
 }
 
-
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use rand::prelude::{thread_rng, Rng};
 
     #[test]
     pub fn linear_test() {
@@ -235,6 +1669,70 @@ pub mod test {
         let err2 = binary(&v, &13);
         assert_eq!(err1, Err(6));
         assert_eq!(err2, Err(5));
+
+        let err3 = binary(&v, &0);
+        let err4 = binary(&v, &99);
+        assert_eq!(err3, Err(0));
+        assert_eq!(err4, Err(7));
+        assert_eq!(binary(&[], &1), Err(0));
+    }
+    #[test]
+    pub fn binary_comparators_test() {
+        let v = [1, 3, 4, 8, 11, 17, 23];
+
+        for (i, x) in v.iter().enumerate() {
+            assert_eq!(binary_find(&v, x, &|a, b| a < b), Some(i));
+            assert_eq!(binary_by(&v, |e| e.cmp(x)), Ok(i));
+            assert_eq!(binary_by_key(&v, &(x * 2), |e| e * 2), Ok(i));
+        }
+        for x in &[0, 2, 5, 99] {
+            assert_eq!(binary_find(&v, x, &|a, b| a < b), None);
+            assert_eq!(binary_by(&v, |e| e.cmp(x)), v.binary_search(x));
+        }
+
+        let v = [23.8, 17.7, 11.6, 8.5, 4.4, 3.3, 1.2];
+        assert_eq!(binary_find(&v, &8.5, &|a, b| a > b), Some(3));
+        assert_eq!(binary_find(&v, &8.4, &|a, b| a > b), None);
+    }
+    #[test]
+    pub fn bounds_test() {
+        let v = [1, 3, 3, 3, 8, 11, 11];
+        let rev = [11, 11, 8, 3, 3, 3, 1];
+
+        for x in 0..13 {
+            let lo = v.iter().take_while(|&&e| e < x).count();
+            let hi = v.iter().take_while(|&&e| e <= x).count();
+            assert_eq!(lower_bound(&v, &x), lo);
+            assert_eq!(upper_bound(&v, &x), hi);
+            assert_eq!(equal_range(&v, &x), (lo, hi));
+
+            assert_eq!(lower_bound_by(&v, |e| e.cmp(&x)), lo);
+            assert_eq!(upper_bound_by(&v, |e| e.cmp(&x)), hi);
+            assert_eq!(equal_range_by(&v, |e| e.cmp(&x)), (lo, hi));
+
+            assert_eq!(lower_bound_by_key(&rev, &-x, |e| -e), v.len() - hi);
+            assert_eq!(upper_bound_by_key(&rev, &-x, |e| -e), v.len() - lo);
+            assert_eq!(equal_range_by_key(&rev, &-x, |e| -e), (v.len() - hi, v.len() - lo));
+        }
+
+        assert_eq!(equal_range(&[], &1), (0, 0));
+        assert_eq!(equal_range(&[2, 2, 2, 2], &2), (0, 4));
+    }
+    #[test]
+    pub fn find_range_test() {
+        let v = [1, 3, 3, 3, 8, 11, 11];
+
+        for x in 0..13 {
+            let expected: Vec<usize> = (0..v.len()).filter(|&i| v[i] == x).collect();
+            let range = find_range(&v, &x);
+            assert_eq!(range.clone().collect::<Vec<_>>(), expected);
+            assert_eq!(range.start, lower_bound(&v, &x));
+            assert_eq!(find_range_by(&v, |e| e.cmp(&x)), range);
+            assert_eq!(find_range_by_key(&v, &(x * 2), |e| e * 2), range);
+            assert_eq!(count(&v, &x), expected.len());
+        }
+        assert_eq!(find_range(&[], &1), 0..0);
+        assert_eq!(count(&[2.5, 2.5, 2.5], &2.5), 3);
     }
     #[test]
     pub fn exponential_test() {
@@ -249,8 +1747,165 @@ pub mod test {
         let err2 = exponential(&v, &13);
         assert_eq!(err1, Err(6));
         assert_eq!(err2, Err(5));
+
+        let err3 = exponential(&v, &0);
+        let err4 = exponential(&v, &99);
+        assert_eq!(err3, Err(0));
+        assert_eq!(err4, Err(7));
+        assert_eq!(exponential(&[], &1), Err(0));
+    }
+    #[test]
+    pub fn exponential_random_test() {
+        let mut rng = thread_rng();
+        for len in 0..100 {
+            let mut v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..1000)).collect();
+            v.sort();
+            v.dedup();
+
+            for x in 0..1001 {
+                assert_eq!(exponential(&v, &x), v.binary_search(&x));
+                assert_eq!(exponential_by(&v, |e| e.cmp(&x)), v.binary_search(&x));
+                assert_eq!(exponential_by_key(&v, &(x + 1), |e| e + 1), v.binary_search(&x));
+            }
+        }
+    }
+    #[test]
+    pub fn rotated_test() {
+        let w = [1, 3, 4, 8, 11, 17, 23];
+        let is_less = &|a: &i32, b: &i32| a < b;
+
+        for k in 0..w.len() {
+            let v = [&w[k..], &w[..k]].concat();
+            assert_eq!(rotation_point(&v, is_less), (w.len() - k) % w.len());
+            for (i, x) in v.iter().enumerate() {
+                assert_eq!(rotated(&v, x, is_less), Some(i));
+            }
+            for x in &[0, 2, 5, 19, 99] {
+                assert_eq!(rotated(&v, x, is_less), None);
+            }
+        }
+        assert_eq!(rotation_point(&[], is_less), 0);
+        assert_eq!(rotated(&[], &1, is_less), None);
+    }
+    #[test]
+    pub fn rotated_repeated_test() {
+        let mut rng = thread_rng();
+        let is_less = &|a: &u32, b: &u32| a < b;
+        for len in 1..60 {
+            let mut w: Vec<u32> = (0..len).map(|_| rng.gen_range(0..4)).collect();
+            w.sort();
+            for k in 0..len {
+                let v = [&w[k..], &w[..k]].concat();
+                let p = rotation_point(&v, is_less);
+                let back = [&v[p..], &v[..p]].concat();
+                assert_eq!(back, w);
+                for x in 0..5 {
+                    let find = rotated(&v, &x, is_less);
+                    assert_eq!(find.map(|i| v[i]), w.binary_search(&x).ok().map(|_| x));
+                }
+            }
+        }
+    }
+    #[test]
+    pub fn matrix_test() {
+        let m = vec![vec![1, 4, 7, 11], vec![2, 5, 8, 12], vec![3, 6, 9, 16], vec![10, 13, 14, 17]];
+        let flat = m.concat();
+
+        for (r, row) in m.iter().enumerate() {
+            for (c, x) in row.iter().enumerate() {
+                assert_eq!(matrix(&m, x), Some((r, c)));
+                assert_eq!(matrix_flat(&flat, 4, x), Some((r, c)));
+            }
+        }
+        for x in &[0, 15, 18] {
+            assert_eq!(matrix(&m, x), None);
+            assert_eq!(matrix_flat(&flat, 4, x), None);
+        }
+
+        assert_eq!(matrix::<i32, Vec<i32>>(&[], &1), None);
+        assert_eq!(matrix(&[[]; 3], &1), None);
+        assert_eq!(matrix_flat(&[], 0, &1), None);
+        assert_eq!(matrix(&[[1, 2, 3]], &3), Some((0, 2)));
+        assert_eq!(matrix(&[[1], [2], [3]], &2), Some((1, 0)));
+    }
+    #[test]
+    #[should_panic]
+    pub fn matrix_flat_cols_test() { matrix_flat(&[1, 2, 3], 2, &1); }
+    #[test]
+    pub fn interpolation_test() {
+        let v = [1, 3, 4, 8, 11, 17, 23];
+
+        let ok1 = interpolation(&v, &1);
+        let ok2 = interpolation(&v, &11);
+        assert_eq!(ok1, Ok(0));
+        assert_eq!(ok2, Ok(4));
+
+        let err1 = interpolation(&v, &19);
+        let err2 = interpolation(&v, &13);
+        let err3 = interpolation(&v, &0);
+        let err4 = interpolation(&v, &99);
+        assert_eq!(err1, Err(6));
+        assert_eq!(err2, Err(5));
+        assert_eq!(err3, Err(0));
+        assert_eq!(err4, Err(7));
+
+        assert_eq!(interpolation(&[] as &[u8], &1), Err(0));
+        assert_eq!(interpolation(&[5, 5, 5], &5), Ok(0));
+        assert_eq!(interpolation(&[0.5, 1.5, 2.5], &1.5), Ok(1));
+    }
+    #[test]
+    pub fn interpolation_random_test() {
+        let mut rng = thread_rng();
+        for len in (0..50).chain(vec![1000]) {
+            // Uniform and skewed numbers, the last ones with many bad probes.
+            let mut uniform: Vec<u64> = (0..len).map(|_| rng.gen_range(0..100_000)).collect();
+            let mut skewed: Vec<u64> =
+                uniform.iter().map(|x| x * x * x / 100_000 / 100_000).collect();
+            for v in [&mut uniform, &mut skewed].iter_mut() {
+                v.sort();
+                v.dedup();
+            }
+
+            for v in [uniform, skewed].iter() {
+                for _ in 0..100 {
+                    let x = rng.gen_range(0..100_001);
+                    assert_eq!(interpolation(v, &x), v.binary_search(&x));
+                }
+                for (i, x) in v.iter().enumerate() {
+                    assert_eq!(interpolation_by_key(v, x, |&e| e), Ok(i));
+                }
+            }
+        }
+    }
+    #[test]
+    pub fn jump_test() {
+        let v = [1, 3, 4, 8, 11, 17, 23];
+
+        let ok1 = jump(&v, &1);
+        let ok2 = jump(&v, &11);
+        assert_eq!(ok1, Ok(0));
+        assert_eq!(ok2, Ok(4));
+
+        let err1 = jump(&v, &19);
+        let err2 = jump(&v, &13);
+        let err3 = jump(&v, &0);
+        let err4 = jump(&v, &99);
+        assert_eq!(err1, Err(6));
+        assert_eq!(err2, Err(5));
+        assert_eq!(err3, Err(0));
+        assert_eq!(err4, Err(7));
+
+        for step in 1..10 {
+            for x in 0..25 {
+                assert_eq!(jump_with(&v, &x, step), v.binary_search(&x));
+            }
+        }
+        assert_eq!(jump(&[], &1), Err(0));
     }
     #[test]
+    #[should_panic]
+    pub fn jump_zero_step_test() { jump_with(&[1, 2, 3], &2, 0).ok(); }
+    #[test]
     pub fn fibonacci_test() {
         let v = [1, 3, 4, 8, 11, 17, 23];
 
@@ -261,7 +1916,232 @@ pub mod test {
 
         let err1 = fibonacci(&v, &19);
         let err2 = fibonacci(&v, &13);
-        assert_eq!(err1, Err(3));
-        assert_eq!(err2, Err(3));
+        assert_eq!(err1, Err(6));
+        assert_eq!(err2, Err(5));
+
+        let err3 = fibonacci(&v, &0);
+        let err4 = fibonacci(&v, &99);
+        assert_eq!(err3, Err(0));
+        assert_eq!(err4, Err(7));
+        assert_eq!(fibonacci(&[], &1), Err(0));
+    }
+    #[test]
+    pub fn ternary_test() {
+        for target in -20..20 {
+            for lo in -25..target {
+                for hi in target..25 {
+                    let dist = |x: i64| (x - target).abs();
+                    assert_eq!(ternary_min(lo..=hi, dist), target);
+                    assert_eq!(ternary_max(lo..=hi, |x| -dist(x)), target);
+                }
+            }
+        }
+
+        assert_eq!(ternary_min(i64::MIN..=i64::MAX, |x| (x as i128 - 5).abs()), 5);
+
+        // Flat at the minimum.
+        let x = ternary_min(0..=100, |x: i64| (x - 40).max(0) + (30 - x).max(0));
+        assert!((30..=40).contains(&x));
+        assert_eq!(ternary_min(5..=5, |x| x), 5);
+    }
+    #[test]
+    pub fn ternary_float_test() {
+        let x = ternary_min_float(-10.0, 10.0, |x| (x - 3.25).powi(2), 1e-9);
+        assert!((x - 3.25).abs() < 1e-6);
+        let x = ternary_max_float(-10.0, 10.0, |x| -(x + 2.5).abs(), 0.0);
+        assert!((x + 2.5).abs() < 1e-12);
+
+        // The extremum at the ends.
+        let x = ternary_min_float(1.0, 2.0, |x| x, 1e-9);
+        assert!((x - 1.0).abs() < 1e-6);
+        let x = ternary_max_float(1.0, 2.0, |x| x, 1e-9);
+        assert!((x - 2.0).abs() < 1e-6);
+
+        // The width of the whole range of `f64` overflows to infinity.
+        let x = ternary_min_float(-f64::MAX, f64::MAX, |x| (x - 1.0).abs(), 1e-9);
+        assert!((x - 1.0).abs() < 1e-6);
+        let x = ternary_max_float(-f64::MAX, f64::MAX, |x| -(x + 1e300).abs(), 0.0);
+        assert!((x + 1e300).abs() <= 1e285);
+        let x = ternary_min_float(-f64::MAX, f64::MAX, |x| -x, 0.0);
+        assert!(x.is_finite() && f64::MAX - x <= f64::MAX * 1e-15);
+    }
+    #[test]
+    #[should_panic]
+    pub fn ternary_empty_test() {
+        let (lo, hi) = (1, 0);
+        ternary_min(lo..=hi, |x| x);
+    }
+    #[test]
+    pub fn partition_point_test() {
+        for start in -10..10 {
+            for end in start..10 {
+                for point in start..=end {
+                    assert_eq!(partition_point(start..end, |x| x >= point), point);
+                }
+            }
+        }
+        assert_eq!(partition_point(i64::MIN..i64::MAX, |x| x >= -3), -3);
+        assert_eq!(partition_point(0..1 << 31, |x| x * x >= 1 << 60), 1 << 30);
+    }
+    #[test]
+    pub fn partition_point_float_test() {
+        let x = partition_point_float(0.0..10.0, |x| x * x >= 10.0);
+        let before = f64::from_bits(x.to_bits() - 1);
+        assert!(x * x >= 10.0 && before * before < 10.0);
+
+        assert_eq!(partition_point_float(-5.0..5.0, |x| x >= -1.5), -1.5);
+        assert_eq!(partition_point_float(-5.0..5.0, |x| x > 0.0), f64::from_bits(1));
+        assert_eq!(partition_point_float(-5.0..5.0, |_| false), 5.0);
+        assert_eq!(partition_point_float(f64::NEG_INFINITY..f64::INFINITY, |x| x >= 1e300), 1e300);
+    }
+    #[test]
+    pub fn min_max_test() {
+        let mut rng = thread_rng();
+        for len in 0..50 {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let comparisons = std::cell::Cell::new(0);
+            let is_less = |a: &(u32, usize), b: &(u32, usize)| {
+                comparisons.set(comparisons.get() + 1);
+                a.0 < b.0
+            };
+
+            let expected = v
+                .iter()
+                .min_by_key(|x| x.0)
+                .map(|min| (min.1, v.iter().max_by_key(|x| x.0).unwrap().1));
+            assert_eq!(min_max(&v, &is_less), expected);
+            assert!(comparisons.get() <= 3 * len.div_ceil(2));
+        }
+    }
+    #[test]
+    pub fn select_deterministic_test() {
+        let mut rng = thread_rng();
+        for &len in &[1, 2, 5, 6, 10, 100, 1000] {
+            for &distinct in &[1, 3, 1000] {
+                let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..distinct)).collect();
+                let mut p = v.clone();
+                p.sort();
+
+                for &k in &[0, len / 3, len / 2, len - 1] {
+                    let mut t = v.clone();
+                    select_deterministic(&mut t, k, &|a, b| a < b);
+                    assert_eq!(t[k], p[k]);
+                    assert!(t[..k].iter().all(|&x| x <= p[k]));
+                    assert!(t[k + 1..].iter().all(|&x| x >= p[k]));
+                }
+            }
+        }
+
+        // Sorted and reversed inputs are the bad cases of a naive quickselect.
+        let mut v: Vec<u32> = (0..10_000).rev().collect();
+        select_deterministic(&mut v, 1234, &|a, b| a < b);
+        assert_eq!(v[1234], 1234);
+    }
+    #[test]
+    #[should_panic]
+    pub fn select_deterministic_out_of_bounds_test() {
+        select_deterministic(&mut [1, 2, 3], 3, &|a, b| a < b);
+    }
+    #[test]
+    pub fn unbounded_test() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            // Every number repeated 3 times, without some of them.
+            let skip = rng.gen_range(0..1_000_000_u64);
+            let seq = |i: u64| {
+                let x = i / 3;
+                if x >= skip {
+                    x + 1
+                } else {
+                    x
+                }
+            };
+            let target = rng.gen_range(0..1_000_000);
+
+            let calls = std::cell::Cell::new(0);
+            let found = unbounded(|i| {
+                calls.set(calls.get() + 1);
+                seq(i).cmp(&target)
+            });
+            let first = (0..).find(|&i| seq(i) >= target).unwrap();
+            if target == skip {
+                assert_eq!(found, Err(first));
+            } else {
+                assert_eq!(found, Ok(first));
+            }
+            assert!(calls.get() <= 2 * 64);
+        }
+
+        assert_eq!(unbounded(|i| i.cmp(&0)), Ok(0));
+        assert_eq!(unbounded(|_| Ordering::Greater), Err(0));
+        assert_eq!(unbounded(|_| Ordering::Less), Err(u64::MAX));
+        assert_eq!(unbounded(|i| i.cmp(&(u64::MAX - 1))), Ok(u64::MAX - 1));
+    }
+    #[test]
+    pub fn multi_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 10, 1000] {
+            let mut v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..2000)).collect();
+            v.sort();
+            let keys: Vec<u32> = (0..500).map(|_| rng.gen_range(0..2100)).collect();
+
+            let found = multi(&v, &keys, |e, k| e.cmp(k));
+            assert_eq!(found.len(), keys.len());
+            for (k, f) in keys.iter().zip(found) {
+                match f {
+                    Some(i) => assert_eq!(v[i], *k),
+                    None => assert!(!v.contains(k)),
+                }
+            }
+        }
+        assert!(multi(&[1, 2, 3], &[] as &[i32], |e, k| e.cmp(k)).is_empty());
+    }
+    #[test]
+    pub fn bisect_float_test() {
+        let cube = |x: f64| x * x * x - 10.0;
+        let root = 10f64.cbrt();
+
+        let x = bisect_float(0.0, 10.0, cube, Tolerance::Absolute(1e-9)).unwrap();
+        assert!((x - root).abs() <= 1e-9);
+        let x = bisect_float(10.0, 0.0, cube, Tolerance::Relative(1e-6)).unwrap();
+        assert!((x - root).abs() <= 1e-6 * 10.0);
+        let x = bisect_float(0.0, 10.0, |x| -cube(x), Tolerance::Absolute(0.0)).unwrap();
+        assert!((x - root).abs() <= 2.0 * f64::EPSILON);
+
+        // Each iteration halves the interval.
+        let x = bisect_float(0.0, 1024.0, |x| x - 100.3, Tolerance::Iterations(10)).unwrap();
+        assert!((x - 100.3).abs() <= 0.5);
+        let x = bisect_float(0.0, 1.0, |x| x - 0.3, Tolerance::Iterations(0)).unwrap();
+        assert_eq!(x, 0.5);
+
+        // Roots at the ends and at the middle.
+        assert_eq!(bisect_float(2.0, 3.0, |x| x - 2.0, Tolerance::Absolute(1e-9)), Some(2.0));
+        assert_eq!(bisect_float(2.0, 3.0, |x| x - 3.0, Tolerance::Absolute(1e-9)), Some(3.0));
+        assert_eq!(bisect_float(2.0, 4.0, |x| x - 3.0, Tolerance::Absolute(1e-9)), Some(3.0));
+
+        // No root in the interval.
+        assert_eq!(bisect_float(0.0, 1.0, |x| x + 1.0, Tolerance::Absolute(1e-9)), None);
+        assert_eq!(bisect_float(0.0, 1.0, |_| f64::NAN, Tolerance::Absolute(1e-9)), None);
+
+        // The width of the whole range of `f64` overflows to infinity.
+        let x = bisect_float(-f64::MAX, f64::MAX, |x| x - 1.0, Tolerance::Absolute(1e-9));
+        assert!((x.unwrap() - 1.0).abs() <= 1e-9);
+        let x = bisect_float(f64::MAX, -f64::MAX, |x| x + 1e300, Tolerance::Relative(1e-12));
+        assert!((x.unwrap() + 1e300).abs() <= 1e288);
+        let x = bisect_float(-f64::MAX, f64::MAX, |x| x, Tolerance::Iterations(1));
+        assert_eq!(x, Some(0.0));
+    }
+    #[test]
+    pub fn fibonacci_random_test() {
+        let mut rng = thread_rng();
+        for len in 0..100 {
+            let mut v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..1000)).collect();
+            v.sort();
+            v.dedup();
+
+            for x in 0..1001 {
+                assert_eq!(fibonacci(&v, &x), v.binary_search(&x));
+            }
+        }
     }
 }
@@ -1,13 +1,139 @@
 //! A module for using sorting algorithms.
 //!
 //! It contains all major sorting algorithms.
+//!
+//! Most sorting algorithms have three flavors:
+//!  - `sort::<name>(&mut v, &|a, b| a < b)`: takes a `bool` closure that returns `true`
+//!    if `a` must come before `b`.
+//!  - `sort::<name>_by(&mut v, |a, b| a.cmp(b))`: takes a three-way comparator that
+//!    returns an [`Ordering`], like [`slice::sort_by`].
+//!  - `sort::<name>_by_key(&mut v, |a| a.key)`: takes a function that extracts a key that
+//!    implements [`Ord`] from the elements, like [`slice::sort_by_key`].
+//!
+//! The exceptions are the sorts that don't compare the elements, [`counting`],
+//! [`bucket`], [`flash`], [`radix_lsd`], [`radix_msd`] and `simd`, that only have some of
+//! the flavors, and [`cached_key`], [`merge_with_buf`] and [`quick_with`], that take other
+//! parameters.
+//!
+//! [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+//! [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+//! [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+//! [`slice::sort_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
+//! [`counting`]: ./fn.counting.html
+//! [`bucket`]: ./fn.bucket.html
+//! [`flash`]: ./fn.flash.html
+//! [`radix_lsd`]: ./fn.radix_lsd.html
+//! [`radix_msd`]: ./fn.radix_msd.html
+//! [`cached_key`]: ./fn.cached_key.html
+//! [`merge_with_buf`]: ./fn.merge_with_buf.html
+//! [`quick_with`]: ./fn.quick_with.html
+
+use std::{cmp::Ordering, mem, ptr};
 
 use rand::prelude::{thread_rng, Rng};
 
+pub mod external;
+pub mod float;
+pub mod instrumented;
+pub mod list;
+pub mod network;
+
+mod argsort;
+mod auto;
+mod bitonic;
+mod block_merge;
+mod bucket;
+mod cached_key;
+mod comb;
+mod counting;
+mod cycle;
+mod ext;
+mod flash;
+mod gnome;
+mod intro;
+mod kway;
+mod merge_in_place;
+mod merge_iterative;
+mod merge_natural;
+mod odd_even;
+mod pancake;
+#[cfg(feature = "parallel")]
+mod par_merge;
+#[cfg(feature = "parallel")]
+mod par_quick;
+mod patience;
+mod pivot;
+mod quick3;
+mod quick_dual;
+mod quick_iterative;
+mod radix;
+mod select;
+mod shell;
+#[cfg(feature = "simd")]
+mod simd;
+mod smooth;
+mod sorted;
+mod sorter;
+mod strand;
+mod tim;
+mod top_k;
+mod tree;
+
+pub use argsort::{apply_permutation, argsort, argsort_by, argsort_by_key};
+pub use auto::{auto, auto_algorithm, auto_by, auto_by_key};
+pub use bitonic::{bitonic, bitonic_by, bitonic_by_key};
+pub use block_merge::{block_merge, block_merge_by, block_merge_by_key};
+pub use bucket::{bucket, bucket_by};
+pub use cached_key::{cached_key, cached_key_with};
+pub use comb::{comb, comb_by, comb_by_key};
+pub use counting::counting;
+pub use cycle::{cycle, cycle_by, cycle_by_key};
+pub use ext::SliceSortExt;
+pub use flash::{flash, flash_by};
+pub use gnome::{gnome, gnome_by, gnome_by_key};
+pub use intro::{intro, intro_by, intro_by_key};
+pub use kway::{kway_merge, kway_merge_by, kway_merge_by_key, kway_merge_iter, KWayMerge};
+pub use merge_in_place::{merge_in_place, merge_in_place_by, merge_in_place_by_key};
+pub use merge_iterative::{merge_iterative, merge_iterative_by, merge_iterative_by_key};
+pub use merge_natural::{merge_natural, merge_natural_by, merge_natural_by_key};
+pub use odd_even::{odd_even, odd_even_by, odd_even_by_key};
+pub use pancake::{pancake, pancake_by, pancake_by_key, pancake_flips};
+#[cfg(feature = "parallel")]
+pub use par_merge::{par_merge, par_merge_by, par_merge_by_key};
+#[cfg(feature = "parallel")]
+pub use par_quick::{
+    par_quick, par_quick_by, par_quick_by_key, par_quick_with, PAR_QUICK_THRESHOLD,
+};
+pub use patience::{patience, patience_by, patience_by_key, Piles};
+pub use pivot::{quick_with, PivotStrategy};
+pub use quick3::{quick3, quick3_by, quick3_by_key};
+pub use quick_dual::{quick_dual, quick_dual_by, quick_dual_by_key};
+pub use quick_iterative::{quick_iterative, quick_iterative_by, quick_iterative_by_key};
+pub use radix::{radix_lsd, radix_lsd_by_key, radix_msd, RadixKey};
+pub use select::{
+    partial, partial_by, partial_by_key, select_nth, select_nth_by, select_nth_by_key,
+};
+pub use shell::{shell, shell_by, shell_by_key, shell_with, GapSequence};
+#[cfg(feature = "simd")]
+pub use simd::{simd, SimdKey};
+pub use smooth::{smooth, smooth_by, smooth_by_key};
+pub use sorted::{is_sorted, is_sorted_by, is_sorted_by_key, runs, runs_by, runs_by_key};
+pub use sorter::{
+    by_algorithm, Algorithm, Bitonic, BlockMerge, Bubble, Cocktail, Comb, Cycle, Gnome, Heap,
+    Insertion, Intro, Merge, OddEven, Pancake, ParseAlgorithmError, Patience, Quick, Quick3,
+    QuickDual, Selection, Shell, Smooth, Sorter, Tim, Tree,
+};
+pub use strand::{strand, strand_by, strand_by_key};
+pub use tim::{tim, tim_by, tim_by_key};
+pub use top_k::{top_k, top_k_by, top_k_by_key};
+pub use tree::{tree, tree_by, tree_by_key};
+
+pub(crate) use quick3::partition3_at;
+
 /// **Selection Sort:** Sort `v` slice according to the way you define the `cmp`
 /// parameter.
 ///
-/// This sort is stable.
+/// This sort is unstable.
 ///
 /// |   Case    | Time complexity | Space complexity |
 /// |:----------|:---------------:|:----------------:|
@@ -24,15 +150,74 @@ use rand::prelude::{thread_rng, Rng};
 /// sort::selection(&mut v, &|v, b| v < b);
 /// ```
 pub fn selection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
-    for i in 0..=v.len() {
+    selection_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Selection Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`selection`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`selection`]: ./fn.selection.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::selection_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn selection_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    selection_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Selection Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`selection`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`selection`]: ./fn.selection.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::selection_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn selection_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    selection_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn selection_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    selection_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `selection_sort`, calling `on_swap` after each swap, which is how the
+/// [`instrumented`] sorts count them.
+///
+/// [`instrumented`]: ./instrumented/index.html
+fn selection_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
+    for i in 0..v.len() {
         let mut i_min = i;
         for j in i + 1..v.len() {
-            if cmp(&v[j], &v[i_min]) {
+            if is_less(&v[j], &v[i_min]) {
                 i_min = j;
             }
         }
         if i_min != i {
             v.swap(i_min, i);
+            on_swap();
         }
     }
 }
@@ -56,12 +241,72 @@ pub fn selection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
 /// sort::bubble(&mut v, &|v, b| v < b);
 /// ```
 pub fn bubble<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    bubble_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Bubble Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`bubble`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`bubble`]: ./fn.bubble.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::bubble_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn bubble_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    bubble_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Bubble Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`bubble`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`bubble`]: ./fn.bubble.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::bubble_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn bubble_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    bubble_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn bubble_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    bubble_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `bubble_sort`, calling `on_swap` after each swap.
+fn bubble_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
     for i in (0..v.len()).rev() {
+        let mut changed = false;
         for j in 0..i {
-            if cmp(&v[j + 1], &v[j]) {
+            if is_less(&v[j + 1], &v[j]) {
                 v.swap(j, j + 1);
+                on_swap();
+                changed = true;
             }
         }
+        if !changed {
+            break;
+        }
     }
 }
 
@@ -85,14 +330,74 @@ pub fn bubble<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
 /// sort::cocktail(&mut v, &|v, b| v < b);
 /// ```
 pub fn cocktail<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    cocktail_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Cocktail Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`cocktail`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`cocktail`]: ./fn.cocktail.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::cocktail_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn cocktail_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    cocktail_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Cocktail Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`cocktail`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`cocktail`]: ./fn.cocktail.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::cocktail_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn cocktail_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    cocktail_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn cocktail_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    cocktail_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `cocktail_sort`, calling `on_swap` after each swap.
+fn cocktail_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
+    if v.len() <= 1 {
+        return;
+    }
+
     let mut changed: bool = true;
     let mut start = 0;
     let mut end = v.len() - 1;
     while changed {
         changed = false;
         for i in start..end {
-            if cmp(&v[i + 1], &v[i]) {
+            if is_less(&v[i + 1], &v[i]) {
                 v.swap(i, i + 1);
+                on_swap();
                 changed = true;
             }
         }
@@ -102,10 +407,11 @@ pub fn cocktail<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
             break;
         }
 
-        changed = true;
-        for i in (start..end - 1).rev() {
-            if cmp(&v[i + 1], &v[i]) {
+        changed = false;
+        for i in (start..end).rev() {
+            if is_less(&v[i + 1], &v[i]) {
                 v.swap(i, i + 1);
+                on_swap();
                 changed = true;
             }
         }
@@ -133,17 +439,77 @@ pub fn cocktail<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
 /// sort::insection(&mut v, &|v, b| v < b);
 /// ```
 pub fn insection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    insection_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Insection Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`insection`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`insection`]: ./fn.insection.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::insection_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn insection_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    insection_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Insection Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`insection`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`insection`]: ./fn.insection.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::insection_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn insection_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    insection_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+pub(crate) fn insection_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    insection_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `insection_sort`, calling `on_swap` after each swap.
+fn insection_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
     for i in 1..v.len() {
         for j in (0..i).rev() {
-            if cmp(&v[j + 1], &v[j]) {
-                v.swap(j, j + 1);
+            if !is_less(&v[j + 1], &v[j]) {
+                break;
             }
+            v.swap(j, j + 1);
+            on_swap();
         }
     }
 }
 
 /// **Merge Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
 ///
+/// It allocates a single auxiliar buffer, with half of the `v` size, that is used by
+/// every merge. Use [`merge_with_buf`] to reuse the buffer between sorts.
+///
 /// This sort is stable.
 ///
 /// |   Case    | Time complexity | Space complexity |
@@ -152,6 +518,8 @@ pub fn insection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
 /// | Avrg:     | Θ(nlog(n))      |                  |
 /// | Worst:    | O(nlog(n))      | O(n)             |
 ///
+/// [`merge_with_buf`]: ./fn.merge_with_buf.html
+///
 /// # Example
 /// ```rust
 /// use algos::sort;
@@ -160,45 +528,161 @@ pub fn insection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
 /// // Crescent sorting
 /// sort::merge(&mut v, &|v, b| v < b);
 /// ```
-pub fn merge<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+pub fn merge<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    merge_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Merge Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`merge`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::merge_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn merge_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    merge_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Merge Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`merge`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::merge_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn merge_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    merge_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// **Merge Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// using `buf` as auxiliar memory.
+///
+/// Same as [`merge`], but it only allocates if `buf` capacity is smaller than half of the
+/// `v` size, so sorting many slices with the same buffer does no allocations after the
+/// first one. `buf` is cleared before sorting and left empty.
+///
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut buf = Vec::new();
+/// for v in &mut [[9, 3, 5, 7, 8, 7], [1, 3, 2, 6, 5, 4]] {
+///     // Crescent sorting
+///     sort::merge_with_buf(v, &mut buf, &|v, b| v < b);
+/// }
+/// assert!(buf.is_empty());
+/// ```
+pub fn merge_with_buf<T: PartialOrd, C: Fn(&T, &T) -> bool>(
+    v: &mut [T], buf: &mut Vec<T>, cmp: &C,
+) {
+    buf.clear();
+    buf.reserve(v.len() / 2);
+    merge_sort_buf(v, buf, &mut |a, b| cmp(a, b));
+}
+
+fn merge_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    // Auxiliar buffer big enough to hold the left half of "v", shared by every merge
+    let mut buf: Vec<T> = Vec::with_capacity(v.len() / 2);
+    merge_sort_buf(v, &mut buf, is_less);
+}
+
+/// Merge sort using the spare capacity of `buf`, that must be at least half of the `v`
+/// size, as auxiliar memory.
+fn merge_sort_buf<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], buf: &mut Vec<T>, is_less: &mut F) {
     let (start, mid, end) = (0, v.len() / 2, v.len());
-    if end <= 1 {
+    // Zero-sized types have nothing to be sorted.
+    if end <= 1 || mem::size_of::<T>() == 0 {
         return;
     }
-    merge(&mut v[start..mid], cmp);
-    merge(&mut v[mid..end], cmp);
-    // Copy array "v" to auxiliar array "o"
-    let mut o: Vec<T> = v.to_vec();
-    combine(&v[start..mid], &v[mid..end], &mut o[..], cmp);
-    // Copy itens of "o" into "v"
-    v.copy_from_slice(&o);
+    merge_sort_buf(&mut v[start..mid], buf, is_less);
+    merge_sort_buf(&mut v[mid..end], buf, is_less);
+    combine(v, mid, buf, is_less);
 }
 
-/// Combines `l` and `r` arrays into `o`
+/// Combines the sorted halves `v[..mid]` and `v[mid..]` into `v`, using `buf` as auxiliar
+/// memory.
+///
+/// The elements are moved (not copied) into the `buf` spare capacity, so `buf` length is
+/// never changed and it never owns the elements.
 ///
 /// # Panic
-/// This function may panic if `left` size + `right` size is different of `o` size.
-fn combine<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(
-    left: &[T], right: &[T], o: &mut [T], cmp: &C,
+/// This function panics if `buf` capacity is smaller than `mid` or `mid` is greater than
+/// `v` size.
+fn combine<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], mid: usize, buf: &mut Vec<T>, is_less: &mut F,
 ) {
-    assert_eq!(right.len() + left.len(), o.len());
-    let (mut i, mut j, mut k) = (0, 0, 0);
-    while i < left.len() && j < right.len() {
-        if cmp(&left[i], &right[j]) {
-            o[k] = left[i];
-            k += 1;
-            i += 1;
-        } else {
-            o[k] = right[j];
-            k += 1;
-            j += 1;
+    assert!(mid <= v.len() && mid <= buf.capacity());
+    let len = v.len();
+
+    // SAFETY: The left half is moved into `buf`, leaving `mid` "holes" in `v`. The
+    // `MergeHole` guard always keeps the remaining elements of `buf` and the holes in `v`
+    // with the same size, and fill the holes when dropped, even if `is_less` panics. So
+    // every element ends in `v` exactly once.
+    unsafe {
+        let v_ptr = v.as_mut_ptr();
+        let buf_ptr = buf.as_mut_ptr();
+        ptr::copy_nonoverlapping(v_ptr, buf_ptr, mid);
+
+        let mut hole = MergeHole { start: buf_ptr, end: buf_ptr.add(mid), dest: v_ptr };
+        let mut right = v_ptr.add(mid);
+        let right_end = v_ptr.add(len);
+
+        while hole.start < hole.end && right < right_end {
+            // Only takes from the right if strictly smaller, so the sort stays stable.
+            let to_move = if is_less(&*right, &*hole.start) {
+                let r = right;
+                right = right.add(1);
+                r
+            } else {
+                let l = hole.start;
+                hole.start = hole.start.add(1);
+                l
+            };
+            ptr::copy_nonoverlapping(to_move, hole.dest, 1);
+            hole.dest = hole.dest.add(1);
         }
+        // If the right half is over, `hole` moves the rest of the left half when dropped,
+        // otherwise the rest of the right half is already in place.
     }
-    if i < left.len() {
-        o[k..].copy_from_slice(&left[i..]);
-    }
-    if j < right.len() {
-        o[k..].copy_from_slice(&right[j..]);
+}
+
+/// Remaining range of a run that was moved into auxiliar memory and where it must go back
+/// to.
+struct MergeHole<T> {
+    start: *mut T,
+    end:   *mut T,
+    dest:  *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        // SAFETY: `start..end` are the elements not yet moved back and `dest` points to
+        // a gap of the same size.
+        unsafe {
+            let len = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
     }
 }
 
@@ -221,34 +705,116 @@ fn combine<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(
 /// // Crescent sorting
 /// sort::quick(&mut v, &|v, b| v < b);
 /// ```
-pub fn quick<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
-    let (start, end) = (0, v.len());
-    if end <= 1 {
+pub fn quick<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    quick_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`quick`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`quick`]: ./fn.quick.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::quick_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn quick_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    quick_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Quick Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`quick`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`quick`]: ./fn.quick.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::quick_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn quick_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    quick_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn quick_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    quick_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `quick_sort`, calling `on_swap` after each swap.
+fn quick_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
+    if v.len() <= 1 {
         return;
     }
-    let mid = partition(v, cmp);
-    quick(&mut v[start..mid - 1], cmp);
-    quick(&mut v[mid..end], cmp);
+    let mid = partition_hooked(v, is_less, on_swap);
+    let (left, right) = v.split_at_mut(mid);
+    quick_sort_hooked(left, is_less, on_swap);
+    quick_sort_hooked(&mut right[1..], is_less, on_swap);
 }
 
-/// Establish where is the middle of `v` and returns it.
-fn partition<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> usize {
-    let (start, end) = (0, v.len() - 1);
+/// Establish where is the middle of `v`, placing the pivot there, and returns it.
+fn partition<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> usize {
+    partition_hooked(v, is_less, &mut || {})
+}
+
+/// Same as `partition`, calling `on_swap` after each swap.
+fn partition_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
     // We randomize the choice of the pivot so we have less probability to have Worst case.
-    // Then we swap the random element to the end of the array.
-    let rand = thread_rng().gen_range(start..=end);
-    let pivot = v[rand];
-    v.swap(rand, end);
+    let rand = thread_rng().gen_range(0..v.len());
+    partition_at_hooked(v, rand, is_less, on_swap)
+}
+
+/// Partitions `v` around the `pivot` element, placing it in the middle, and returns its
+/// new position.
+fn partition_at<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], pivot: usize, is_less: &mut F) -> usize {
+    partition_at_hooked(v, pivot, is_less, &mut || {})
+}
+
+/// Same as `partition_at`, calling `on_swap` after each swap.
+fn partition_at_hooked<T, F, S>(
+    v: &mut [T], pivot: usize, is_less: &mut F, on_swap: &mut S,
+) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
+    // We swap the pivot to the end of the array.
+    let (start, end) = (0, v.len() - 1);
+    v.swap(pivot, end);
+    on_swap();
 
     let mut i = start;
     for j in start..end {
-        if cmp(&v[j], &pivot) {
+        if is_less(&v[j], &v[end]) {
             v.swap(i, j);
+            on_swap();
             i += 1;
         }
     }
     v.swap(i, end);
-    i + 1
+    on_swap();
+    i
 }
 
 /// **Heap Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
@@ -269,31 +835,99 @@ fn partition<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C)
 /// // Crescent sorting
 /// sort::heap(&mut v, &|v, b| v < b);
 /// ```
-pub fn heap<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
-    let (start, end) = (0, v.len());
-    for i in (start..end / 2).rev() {
-        heapify(&mut v[..], cmp, i);
+pub fn heap<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    heap_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Heap Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`heap`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`heap`]: ./fn.heap.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::heap_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn heap_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    heap_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Heap Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`heap`], but comparing the keys returned by `key`, like `slice::sort_by_key`.
+///
+/// [`heap`]: ./fn.heap.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::heap_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn heap_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    heap_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn heap_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    heap_sort_hooked(v, is_less, &mut || {});
+}
+
+/// Same as `heap_sort`, calling `on_swap` after each swap.
+fn heap_sort_hooked<T, F, S>(v: &mut [T], is_less: &mut F, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
+    let end = v.len();
+    for i in (0..end / 2).rev() {
+        heapify_hooked(v, is_less, i, on_swap);
     }
-    for i in (0..end).rev() {
+    for i in (1..end).rev() {
         v.swap(0, i);
-        heapify(&mut v[..i], cmp, 0);
+        on_swap();
+        heapify_hooked(&mut v[..i], is_less, 0, on_swap);
     }
 }
 
 /// Creates a heap with `node` which is an index in `v`.
-fn heapify<T: Copy + PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C, node: usize) {
+fn heapify<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F, node: usize) {
+    heapify_hooked(v, is_less, node, &mut || {});
+}
+
+/// Same as `heapify`, calling `on_swap` after each swap.
+fn heapify_hooked<T, F, S>(v: &mut [T], is_less: &mut F, node: usize, on_swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(),
+{
     let end = v.len();
-    let mut root = node;
-    let (left_child, right_child) = (2 * node, 2 * node + 1);
-    if left_child < end && cmp(&v[root], &v[left_child]) {
-        root = left_child;
-    }
-    if right_child < end && cmp(&v[root], &v[right_child]) {
-        root = right_child;
-    }
-    if root != node {
+    let mut node = node;
+    loop {
+        let mut root = node;
+        let (left_child, right_child) = (2 * node + 1, 2 * node + 2);
+        if left_child < end && is_less(&v[root], &v[left_child]) {
+            root = left_child;
+        }
+        if right_child < end && is_less(&v[root], &v[right_child]) {
+            root = right_child;
+        }
+        if root == node {
+            break;
+        }
         v.swap(node, root);
-        heapify(v, cmp, root);
+        on_swap();
+        node = root;
     }
 }
 
@@ -427,4 +1061,198 @@ pub mod test {
         heap(&mut v, &|a, b| a < b);
         assert_eq!(v, p);
     }
+
+    #[test]
+    pub fn merge_strings_test() {
+        let p: Vec<String> =
+            ["a", "bc", "def", "ghij", "klmno", "pqrstu"].iter().map(|s| s.to_string()).collect();
+        let mut v: Vec<String> =
+            ["bc", "a", "def", "klmno", "ghij", "pqrstu"].iter().map(|s| s.to_string()).collect();
+
+        merge(&mut v, &|a, b| a.len() < b.len());
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn merge_stable_test() {
+        let p = [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (3, 'e')];
+        let mut v = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')];
+
+        merge(&mut v, &|a, b| a.0 < b.0);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn merge_with_buf_test() {
+        let mut buf = vec!["x".to_string(); 3];
+        let mut rng = thread_rng();
+        for len in 0..50 {
+            let mut v: Vec<String> = (0..len).map(|_| rng.gen_range(0..20).to_string()).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            merge_with_buf(&mut v, &mut buf, &|a, b| a < b);
+            assert_eq!(v, p);
+            assert!(buf.is_empty());
+        }
+        assert!(buf.capacity() >= 49 / 2);
+    }
+
+    #[test]
+    pub fn merge_panic_test() {
+        use std::{
+            cell::Cell,
+            panic::{self, AssertUnwindSafe},
+        };
+
+        let mut v: Vec<String> = (0..20).rev().map(|x| x.to_string()).collect();
+        let calls = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            merge(&mut v, &|a, b| {
+                calls.set(calls.get() + 1);
+                if calls.get() == 30 {
+                    panic!("comparator panic");
+                }
+                a < b
+            })
+        }));
+        assert!(result.is_err());
+
+        // Every element must still be in the slice exactly once.
+        let mut p: Vec<String> = (0..20).map(|x| x.to_string()).collect();
+        p.sort();
+        v.sort();
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn quick_strings_test() {
+        let p: Vec<String> =
+            ["a", "bc", "def", "ghij", "klmno", "pqrstu"].iter().map(|s| s.to_string()).collect();
+        let mut v: Vec<String> =
+            ["bc", "a", "def", "klmno", "ghij", "pqrstu"].iter().map(|s| s.to_string()).collect();
+
+        quick(&mut v, &|a, b| a.len() < b.len());
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn heap_strings_test() {
+        let p: Vec<String> =
+            ["a", "bc", "def", "ghij", "klmno", "pqrstu"].iter().map(|s| s.to_string()).collect();
+        let mut v: Vec<String> =
+            ["bc", "a", "def", "klmno", "ghij", "pqrstu"].iter().map(|s| s.to_string()).collect();
+
+        heap(&mut v, &|a, b| a.len() < b.len());
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn n_log_n_random_test() {
+        let mut rng = thread_rng();
+        for len in 0..100 {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..50)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let (mut m, mut q, mut h) = (v.clone(), v.clone(), v.clone());
+            merge(&mut m, &|a, b| a < b);
+            quick(&mut q, &|a, b| a < b);
+            heap(&mut h, &|a, b| a < b);
+            assert_eq!(m, p);
+            assert_eq!(q, p);
+            assert_eq!(h, p);
+        }
+    }
+
+    type SortBy = fn(&mut [i32], fn(&i32, &i32) -> Ordering);
+
+    #[test]
+    pub fn by_test() {
+        let sorts: [SortBy; 7] = [
+            |v, f| selection_by(v, f),
+            |v, f| bubble_by(v, f),
+            |v, f| cocktail_by(v, f),
+            |v, f| insection_by(v, f),
+            |v, f| merge_by(v, f),
+            |v, f| quick_by(v, f),
+            |v, f| heap_by(v, f),
+        ];
+
+        for sort in sorts.iter() {
+            let p = [99, 30, 23, 15, 12, 9, 8, 7, 7, 5, 3];
+            let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+            sort(&mut v, |a, b| b.cmp(a));
+            assert_eq!(v, p);
+
+            let mut empty: [i32; 0] = [];
+            sort(&mut empty, |a, b| a.cmp(b));
+
+            let mut two = [2, 1];
+            sort(&mut two, |a, b| a.cmp(b));
+            assert_eq!(two, [1, 2]);
+        }
+    }
+
+    #[test]
+    pub fn sorted_input_test() {
+        use std::cell::Cell;
+
+        // Sorted inputs are the best case, a single pass without any swap.
+        type SortBool = fn(&mut [i32], &mut dyn FnMut(&i32, &i32) -> bool);
+        let sorts: [SortBool; 3] = [
+            |v, f| bubble_sort(v, &mut |a, b| f(a, b)),
+            |v, f| cocktail_sort(v, &mut |a, b| f(a, b)),
+            |v, f| insection_sort(v, &mut |a, b| f(a, b)),
+        ];
+
+        for sort in sorts.iter() {
+            let mut v: Vec<i32> = (0..100).collect();
+            let calls = Cell::new(0);
+            sort(&mut v, &mut |a, b| {
+                calls.set(calls.get() + 1);
+                a < b
+            });
+            assert_eq!(calls.get(), 99);
+            assert!(v.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    pub fn by_three_way_test() {
+        // Sort by length, then alphabetically.
+        let p = ["a", "b", "ab", "cd", "abc"];
+        let mut v = ["cd", "abc", "b", "ab", "a"];
+
+        merge_by(&mut v, |a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    pub fn by_key_test() {
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            name: String,
+            age:  u8,
+        }
+
+        let person = |name: &str, age| Person { name: name.to_string(), age };
+        let sorts: [fn(&mut [Person]); 7] = [
+            |v| selection_by_key(v, |p| p.age),
+            |v| bubble_by_key(v, |p| p.age),
+            |v| cocktail_by_key(v, |p| p.age),
+            |v| insection_by_key(v, |p| p.age),
+            |v| merge_by_key(v, |p| p.age),
+            |v| quick_by_key(v, |p| p.age),
+            |v| heap_by_key(v, |p| p.age),
+        ];
+
+        for sort in sorts.iter() {
+            let p = [person("Carl", 9), person("Ana", 23), person("Bob", 42), person("Dan", 61)];
+            let mut v =
+                [person("Bob", 42), person("Dan", 61), person("Ana", 23), person("Carl", 9)];
+            sort(&mut v);
+            assert_eq!(v, p);
+        }
+    }
 }
@@ -0,0 +1,172 @@
+//! Argsort implementation.
+
+use std::cmp::Ordering;
+
+use super::{counting::permute, merge_sort};
+
+/// **Argsort:** Returns the permutation of indexes that sorts `v` according to the way
+/// you define the `cmp` parameter, without changing `v`.
+///
+/// The returned vector `idx` is such that `v[idx[0]], v[idx[1]], ...` is sorted. Use
+/// [`apply_permutation`] to put `v`, or other slices with the same size, in that order.
+///
+/// This sort is stable, so equal elements have their indexes in crescent order.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// [`apply_permutation`]: ./fn.apply_permutation.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// let idx = sort::argsort(&v, &|v, b| v < b);
+/// assert_eq!(idx, [1, 2, 3, 5, 4, 0]);
+/// ```
+pub fn argsort<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> Vec<usize> {
+    argsort_indexes(v, &mut |a, b| cmp(a, b))
+}
+
+/// **Argsort:** Returns the permutation of indexes that sorts `v` according to the
+/// [`Ordering`] returned by the `compare` function, without changing `v`.
+///
+/// Same as [`argsort`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`argsort`]: ./fn.argsort.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// let idx = sort::argsort_by(&v, |a, b| b.cmp(a));
+/// assert_eq!(idx, [0, 4, 3, 5, 2, 1]);
+/// ```
+pub fn argsort_by<T, F: FnMut(&T, &T) -> Ordering>(v: &[T], mut compare: F) -> Vec<usize> {
+    argsort_indexes(v, &mut |a, b| compare(a, b) == Ordering::Less)
+}
+
+/// **Argsort:** Returns the permutation of indexes that sorts `v` according to the key
+/// extracted by the `key` function, without changing `v`.
+///
+/// Same as [`argsort`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`argsort`]: ./fn.argsort.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// let idx = sort::argsort_by_key(&v, |a: &i32| a.abs());
+/// assert_eq!(idx, [1, 2, 3, 5, 4, 0]);
+/// ```
+pub fn argsort_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], mut key: F) -> Vec<usize> {
+    argsort_indexes(v, &mut |a, b| key(a).lt(&key(b)))
+}
+
+fn argsort_indexes<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..v.len()).collect();
+    merge_sort(&mut idx, &mut |&a, &b| is_less(&v[a], &v[b]));
+    idx
+}
+
+/// Reorders `v` so that `v[i]` becomes the element that was at `v[perm[i]]`.
+///
+/// With the permutation returned by [`argsort`] it sorts `v`, and it can be applied to
+/// several slices to sort all of them by one of them.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// [`argsort`]: ./fn.argsort.html
+///
+/// # Panic
+/// This function panics if `perm` is not a permutation of the indexes of `v`.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut ages = [30, 25, 41];
+/// let mut names = ["Ana", "Bia", "Caio"];
+///
+/// let idx = sort::argsort(&ages, &|a, b| a < b);
+/// sort::apply_permutation(&mut ages, &idx);
+/// sort::apply_permutation(&mut names, &idx);
+/// assert_eq!(ages, [25, 30, 41]);
+/// assert_eq!(names, ["Bia", "Ana", "Caio"]);
+/// ```
+pub fn apply_permutation<T>(v: &mut [T], perm: &[usize]) {
+    assert_eq!(v.len(), perm.len(), "the permutation must have the size of the slice");
+
+    // The inverse permutation: where each element must go.
+    let mut dest = vec![usize::MAX; v.len()];
+    for (i, &p) in perm.iter().enumerate() {
+        assert!(p < v.len() && dest[p] == usize::MAX, "`perm` is not a permutation");
+        dest[p] = i;
+    }
+    permute(v, &mut dest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn argsort_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        let idx = argsort(&v, &|a, b| a < b);
+        assert_eq!(idx, [1, 2, 3, 5, 4, 0, 10, 9, 8, 7, 6]);
+        assert_eq!(idx.iter().map(|&i| v[i]).collect::<Vec<_>>(), p);
+    }
+
+    #[test]
+    fn argsort_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        let idx = argsort(&v, &|a, b| a < b);
+        apply_permutation(&mut v, &idx);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn apply_permutation_test() {
+        let mut rng = thread_rng();
+        let keys: Vec<u32> = (0..1000).map(|_| rng.gen_range(0..10)).collect();
+        let mut values: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+
+        let mut p: Vec<(u32, String)> = keys.iter().copied().zip(values.clone()).collect();
+        p.sort_by_key(|x| x.0);
+
+        let idx = argsort_by_key(&keys, |&k| k);
+        let mut keys = keys;
+        apply_permutation(&mut keys, &idx);
+        apply_permutation(&mut values, &idx);
+        assert_eq!(keys.into_iter().zip(values).collect::<Vec<_>>(), p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_permutation_repeated_test() {
+        let mut v = [1, 2, 3];
+        apply_permutation(&mut v, &[0, 2, 2]);
+    }
+}
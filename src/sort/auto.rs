@@ -0,0 +1,233 @@
+//! Adaptive sorting, that chooses the algorithm from the input.
+
+use std::{cmp::Ordering, mem};
+
+use super::{insection_sort, intro::intro_sort, merge_sort, tim::tim_sort, Algorithm};
+
+/// Slices with this size or smaller are sorted with insertion sort.
+const INSERTION_THRESHOLD: usize = 20;
+
+/// Slices of elements larger than this, in bytes, are sorted with insertion sort only up
+/// to half of `INSERTION_THRESHOLD`, since it moves the elements `O(n²)` times.
+const LARGE_ELEMENT: usize = 4 * mem::size_of::<usize>();
+
+/// Slices whose natural runs have at least this mean length are sorted with Tim sort.
+const MIN_MEAN_RUN: usize = 32;
+
+/// **Auto Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// with the algorithm that best fits it.
+///
+/// It chooses the algorithm with [`auto_algorithm`]:
+///  - Insertion sort for small slices.
+///  - Tim sort for slices that are already mostly sorted, in crescent or decrescent
+///    order, which it sorts in linear time in the best case.
+///  - Merge sort for large elements, since it moves them less times than quick sort.
+///  - Intro sort for everything else.
+///
+/// Checking the runs of the slice costs up to `n` comparisons.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// [`auto_algorithm`]: ./fn.auto_algorithm.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::auto(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn auto<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    auto_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Auto Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function, with the algorithm that best fits it.
+///
+/// Same as [`auto`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`auto`]: ./fn.auto.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::auto_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn auto_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    auto_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Auto Sort:** Sort `v` slice according to the key extracted by the `key` function,
+/// with the algorithm that best fits it.
+///
+/// Same as [`auto`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`auto`]: ./fn.auto.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// sort::auto_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn auto_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    auto_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// Returns the algorithm that [`auto`] would use to sort `v` according to the way you
+/// define the `cmp` parameter.
+///
+/// It is one of [`Algorithm::Insertion`], [`Algorithm::Tim`], [`Algorithm::Merge`] and
+/// [`Algorithm::Intro`].
+///
+/// [`auto`]: ./fn.auto.html
+/// [`Algorithm::Insertion`]: ./enum.Algorithm.html#variant.Insertion
+/// [`Algorithm::Tim`]: ./enum.Algorithm.html#variant.Tim
+/// [`Algorithm::Merge`]: ./enum.Algorithm.html#variant.Merge
+/// [`Algorithm::Intro`]: ./enum.Algorithm.html#variant.Intro
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{self, Algorithm};
+///
+/// let small = [9, 3, 5, 7, 8, 7];
+/// assert_eq!(sort::auto_algorithm(&small, &|a, b| a < b), Algorithm::Insertion);
+///
+/// let reversed: Vec<u32> = (0..1000).rev().collect();
+/// assert_eq!(sort::auto_algorithm(&reversed, &|a, b| a < b), Algorithm::Tim);
+/// ```
+pub fn auto_algorithm<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> Algorithm {
+    choose(v, &mut |a, b| cmp(a, b))
+}
+
+fn auto_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    match choose(v, is_less) {
+        Algorithm::Insertion => insection_sort(v, is_less),
+        Algorithm::Tim => tim_sort(v, is_less),
+        Algorithm::Merge => merge_sort(v, is_less),
+        _ => intro_sort(v, is_less),
+    }
+}
+
+fn choose<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> Algorithm {
+    let threshold = if mem::size_of::<T>() > LARGE_ELEMENT {
+        INSERTION_THRESHOLD / 2
+    } else {
+        INSERTION_THRESHOLD
+    };
+    if v.len() <= threshold {
+        return Algorithm::Insertion;
+    }
+
+    if has_long_runs(v, is_less) {
+        Algorithm::Tim
+    } else if mem::size_of::<T>() > LARGE_ELEMENT {
+        Algorithm::Merge
+    } else {
+        Algorithm::Intro
+    }
+}
+
+/// Returns `true` if the natural runs of `v`, the ascending and the strictly descending
+/// ones, have at least `MIN_MEAN_RUN` elements on average.
+fn has_long_runs<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> bool {
+    let max_runs = v.len() / MIN_MEAN_RUN;
+    let (mut runs, mut start) = (0, 0);
+    while start < v.len() {
+        runs += 1;
+        if runs > max_runs {
+            return false;
+        }
+
+        let mut end = start + 1;
+        if end < v.len() && is_less(&v[end], &v[start]) {
+            while end < v.len() && is_less(&v[end], &v[end - 1]) {
+                end += 1;
+            }
+        } else {
+            while end < v.len() && !is_less(&v[end], &v[end - 1]) {
+                end += 1;
+            }
+        }
+        start = end;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn auto_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        auto(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn auto_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        auto(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn auto_algorithm_test() {
+        let mut rng = thread_rng();
+        let random: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+        let mut sorted = random.clone();
+        sorted.sort();
+        let mut two_runs = sorted.clone();
+        two_runs.rotate_left(300);
+        let large: Vec<[u64; 8]> = random.iter().map(|&x| [x as u64; 8]).collect();
+
+        let cmp = |a: &u32, b: &u32| a < b;
+        assert_eq!(auto_algorithm(&random[..10], &cmp), Algorithm::Insertion);
+        assert_eq!(auto_algorithm(&random, &cmp), Algorithm::Intro);
+        assert_eq!(auto_algorithm(&sorted, &cmp), Algorithm::Tim);
+        assert_eq!(auto_algorithm(&two_runs, &cmp), Algorithm::Tim);
+        assert_eq!(auto_algorithm(&large, &|a, b| a < b), Algorithm::Merge);
+        assert_eq!(auto_algorithm(&large[..15], &|a, b| a < b), Algorithm::Merge);
+    }
+
+    #[test]
+    fn auto_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 15, 100, 10_000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v.clone();
+            auto(&mut t, &|a, b| a < b);
+            assert_eq!(t, p);
+
+            let mut t = p.clone();
+            t.reverse();
+            auto_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Bitonic sort implementation.
+
+use std::cmp::Ordering;
+
+/// **Bitonic Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It is a sorting network: the sequence of compare-and-swap operations only depends on
+/// the length of the slice, never on the elements, so every stage could be done in
+/// parallel or with SIMD. It builds bitonic sequences of size 2, 4, 8, ... and merges
+/// each one of them into a sorted sequence.
+///
+/// Slices with length that is not a power of two are padded up to the next power of two
+/// with virtual elements greater than every element. Every comparator of the network puts
+/// the lesser element first, so the comparators with a padding element never swap and are
+/// skipped.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog²(n))     |                  |
+/// | Avrg:     | Θ(nlog²(n))     |                  |
+/// | Worst:    | O(nlog²(n))     | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::bitonic(&mut v, &|v, b| v < b);
+/// ```
+pub fn bitonic<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    bitonic_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Bitonic Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`bitonic`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`bitonic`]: ./fn.bitonic.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::bitonic_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn bitonic_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    bitonic_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Bitonic Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`bitonic`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`bitonic`]: ./fn.bitonic.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::bitonic_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn bitonic_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    bitonic_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn bitonic_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let padded = v.len().next_power_of_two();
+
+    let mut size = 2;
+    while size <= padded {
+        // Merging two sorted halves is the same as merging the first one with the second
+        // one reversed, that is a bitonic sequence.
+        bitonic_stage(v, size - 1, is_less);
+
+        let mut dist = size / 4;
+        while dist > 0 {
+            bitonic_stage(v, dist, is_less);
+            dist /= 2;
+        }
+        size *= 2;
+    }
+}
+
+/// Compare and swap every pair `(i, i ^ mask)` of `v` with `i < i ^ mask`, putting the
+/// lesser element first.
+///
+/// All the pairs of a stage are disjoint, so they can be done in any order. Pairs out of
+/// the bounds of `v` are padding and are skipped.
+pub(super) fn bitonic_stage<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], mask: usize, is_less: &mut F,
+) {
+    for i in 0..v.len() {
+        let j = i ^ mask;
+        if j > i && j < v.len() && is_less(&v[j], &v[i]) {
+            v.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn bitonic_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        bitonic(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn bitonic_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        bitonic(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn bitonic_random_test() {
+        let mut rng = thread_rng();
+        for len in (0..70).chain([127, 128, 129, 1000, 1024].iter().copied()) {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            bitonic_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn bitonic_data_independent_test() {
+        // The pairs compared must be the same for any input of the same length.
+        let pairs = |v: &mut [i32]| {
+            let mut pairs = Vec::new();
+            let base = v.as_ptr() as usize;
+            let size = std::mem::size_of::<i32>();
+            bitonic_sort(v, &mut |a, b| {
+                let index = |x: &i32| (x as *const i32 as usize - base) / size;
+                pairs.push((index(a), index(b)));
+                a < b
+            });
+            pairs
+        };
+
+        let mut sorted: Vec<i32> = (0..13).collect();
+        let mut reversed: Vec<i32> = (0..13).rev().collect();
+        assert_eq!(pairs(&mut sorted), pairs(&mut reversed));
+    }
+}
@@ -0,0 +1,442 @@
+//! In-place stable block merge sort implementation.
+
+use std::cmp::Ordering;
+
+use super::insection_sort;
+
+/// Runs with this size are sorted with insertion sort before merging.
+const INSERTION_THRESHOLD: usize = 16;
+
+/// **Block Merge Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Merge Sort.
+///
+/// It is a bottom-up merge sort that merges in place, without allocating (WikiSort
+/// style). First it moves about `2√n` distinct elements (keys) to the start of the slice,
+/// then each merge splits the left run in blocks of about `√n` elements, tagged with some
+/// of the keys to keep their order, and rolls them through the right run. Each block is
+/// then merged locally with the right run elements that go after it, using the rest of
+/// the keys as a swap buffer. In the end the keys are sorted and merged back.
+///
+/// If the slice doesn't have enough distinct elements, the local merges are done with
+/// rotations, that are cheap since there are few distinct elements.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::block_merge(&mut v, &|v, b| v < b);
+/// ```
+pub fn block_merge<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    block_merge_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Block Merge Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`block_merge`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`block_merge`]: ./fn.block_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::block_merge_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn block_merge_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    block_merge_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Block Merge Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`block_merge`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`block_merge`]: ./fn.block_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::block_merge_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn block_merge_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    block_merge_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// How the runs of a level of the merge sort are merged.
+struct Level {
+    /// Size of the blocks.
+    block:  usize,
+    /// Start of the swap buffer, of `block` elements, if there are enough keys for it.
+    buffer: Option<usize>,
+}
+
+impl Level {
+    /// Chooses the block size for merging runs of `width` elements with `keys` keys at
+    /// the start of the slice.
+    ///
+    /// Every block of the left run needs a key as a tag, and the swap buffer needs a key
+    /// per element.
+    fn new(width: usize, keys: usize) -> Self {
+        let block = sqrt_ceil(width);
+        let tags = width / block;
+        if keys >= tags + block {
+            Level { block, buffer: Some(tags) }
+        } else {
+            Level { block: block.max(width.div_ceil(keys)), buffer: None }
+        }
+    }
+}
+
+fn block_merge_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    if len <= INSERTION_THRESHOLD {
+        insection_sort(v, is_less);
+        return;
+    }
+
+    let keys = collect_keys(v, 2 * sqrt_ceil(len), is_less);
+    for run in v[keys..].chunks_mut(INSERTION_THRESHOLD) {
+        insection_sort(run, is_less);
+    }
+
+    let mut width = INSERTION_THRESHOLD;
+    while width < len - keys {
+        // The tags must be sorted, but the swap buffer was scrambled by the last level.
+        insection_sort(&mut v[..keys], is_less);
+        let level = Level::new(width, keys);
+
+        let mut start = keys;
+        while start + width < len {
+            let mid = start + width;
+            let end = (mid + width).min(len);
+
+            if is_less(&v[mid], &v[mid - 1]) {
+                if is_less(&v[end - 1], &v[start]) {
+                    // Every element of the right run is less than the left run.
+                    v[start..end].rotate_left(mid - start);
+                } else {
+                    merge_blocks(v, start, mid, end, &level, is_less);
+                }
+            }
+            start = end;
+        }
+        width *= 2;
+    }
+
+    // The keys are the first occurrence of each of them, so they go before the equal
+    // elements.
+    insection_sort(&mut v[..keys], is_less);
+    merge_rotations(v, 0, keys, len, is_less);
+}
+
+/// Moves up to `wanted` distinct elements to the start of `v`, in sorted order, returning
+/// how many were found.
+///
+/// The first occurrence of each element is taken, and the other elements keep their
+/// relative order.
+fn collect_keys<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], wanted: usize, is_less: &mut F) -> usize {
+    if v.is_empty() {
+        return 0;
+    }
+
+    // The keys are kept sorted in `v[first..first + found]`.
+    let (mut first, mut found) = (0, 1);
+    let mut i = 1;
+    while i < v.len() && found < wanted {
+        let pos = first + count_less(v, first, first + found, i, is_less);
+        if pos == first + found || is_less(&v[i], &v[pos]) {
+            // Move the keys to right before `v[i]`, and insert it in them.
+            let gap = i - (first + found);
+            v[first..i].rotate_left(found);
+            first += gap;
+            v[pos + gap..=i].rotate_right(1);
+            found += 1;
+        }
+        i += 1;
+    }
+
+    v[..first + found].rotate_right(found);
+    found
+}
+
+/// Merges the sorted runs `v[start..mid]` (A) and `v[mid..end]` (B), with `v[..]` having
+/// the keys used as tags and swap buffer.
+fn merge_blocks<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], start: usize, mid: usize, end: usize, level: &Level, is_less: &mut F,
+) {
+    let size = level.block;
+
+    // The first A block may be smaller and it is never moved.
+    let mut last_a = (start, start + (mid - start) % size);
+    let mut last_b = (last_a.1, last_a.1);
+    let mut block_a = (last_a.1, mid);
+    let mut block_b = (mid, mid + size.min(end - mid));
+
+    // Tag the A blocks by swapping their second element with a key, so their original
+    // order is known after they are rolled through B.
+    for (tag, block) in (block_a.0..block_a.1).step_by(size).enumerate() {
+        v.swap(tag, block + 1);
+    }
+    let mut tag = 0;
+    let mut min_a = block_a.0;
+
+    loop {
+        let b_ended = block_b.0 == block_b.1;
+        if b_ended || (last_b.0 < last_b.1 && !is_less(&v[last_b.1 - 1], &v[min_a])) {
+            // The minimum A block goes before some elements of the last B block: drop it
+            // there, and merge the previous A block with the B elements before it.
+            let b_split = last_b.0 + count_less(v, last_b.0, last_b.1, min_a, is_less);
+            let b_remaining = last_b.1 - b_split;
+
+            block_swap(v, block_a.0, min_a, size);
+            v.swap(block_a.0 + 1, tag);
+            tag += 1;
+
+            merge_local(v, last_a.0, last_a.1, b_split, level, is_less);
+            v[b_split..block_a.0 + size].rotate_left(block_a.0 - b_split);
+
+            last_a = (block_a.0 - b_remaining, block_a.0 - b_remaining + size);
+            last_b = (last_a.1, last_a.1 + b_remaining);
+            block_a.0 += size;
+            if block_a.0 == block_a.1 {
+                break;
+            }
+
+            min_a = block_a.0;
+            for block in (block_a.0 + size..block_a.1).step_by(size) {
+                if is_less(&v[block + 1], &v[min_a + 1]) {
+                    min_a = block;
+                }
+            }
+        } else if block_b.1 - block_b.0 < size {
+            // The last B block is smaller, move it before the A blocks.
+            let len = block_b.1 - block_b.0;
+            v[block_a.0..block_b.1].rotate_left(block_b.0 - block_a.0);
+
+            last_b = (block_a.0, block_a.0 + len);
+            block_a = (block_a.0 + len, block_a.1 + len);
+            min_a += len;
+            block_b.1 = block_b.0;
+        } else {
+            // Roll the A blocks, swapping the first one with the next B block.
+            block_swap(v, block_a.0, block_b.0, size);
+
+            last_b = (block_a.0, block_a.0 + size);
+            if min_a == block_a.0 {
+                min_a = block_a.1;
+            }
+            block_a = (block_a.0 + size, block_a.1 + size);
+            block_b = (block_b.0 + size, (block_b.1 + size).min(end));
+        }
+    }
+
+    merge_local(v, last_a.0, last_a.1, end, level, is_less);
+}
+
+/// Merges `v[start..mid]` with `v[mid..end]`, where the first one is at most one block.
+fn merge_local<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], start: usize, mid: usize, end: usize, level: &Level, is_less: &mut F,
+) {
+    match level.buffer {
+        Some(buffer) => merge_buffer(v, start, mid, end, buffer, is_less),
+        None => merge_rotations(v, start, mid, end, is_less),
+    }
+}
+
+/// Merges `v[start..mid]` with `v[mid..end]` swapping the first run with the buffer at
+/// `v[buffer..]`.
+///
+/// The elements of the buffer end up in the buffer, but in another order.
+fn merge_buffer<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], start: usize, mid: usize, end: usize, buffer: usize, is_less: &mut F,
+) {
+    let len_a = mid - start;
+    block_swap(v, start, buffer, len_a);
+
+    let (mut a, mut b, mut out) = (0, mid, start);
+    while a < len_a && b < end {
+        if is_less(&v[b], &v[buffer + a]) {
+            v.swap(out, b);
+            b += 1;
+        } else {
+            v.swap(out, buffer + a);
+            a += 1;
+        }
+        out += 1;
+    }
+
+    block_swap(v, buffer + a, out, len_a - a);
+}
+
+/// Merges `v[start..mid]` with `v[mid..end]` with rotations.
+///
+/// Each rotation puts at least one distinct element of the first run in place, so it is
+/// fast when there are few distinct elements.
+fn merge_rotations<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], mut start: usize, mut mid: usize, end: usize, is_less: &mut F,
+) {
+    while start < mid && mid < end {
+        // The elements of the first run not greater than the first of the second run are
+        // already in place.
+        start += count_not_greater(v, start, mid, mid, is_less);
+        if start == mid {
+            break;
+        }
+
+        let len = count_less(v, mid, end, start, is_less);
+        v[start..mid + len].rotate_left(mid - start);
+        start += len + 1;
+        mid += len;
+    }
+}
+
+/// Returns how many elements of the sorted `v[start..end]` are less than `v[key]`, with
+/// `key` out of that range.
+fn count_less<T, F: FnMut(&T, &T) -> bool>(
+    v: &[T], start: usize, end: usize, key: usize, is_less: &mut F,
+) -> usize {
+    let (mut lo, mut hi) = (start, end);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(&v[mid], &v[key]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo - start
+}
+
+/// Returns how many elements of the sorted `v[start..end]` are not greater than `v[key]`,
+/// with `key` out of that range.
+fn count_not_greater<T, F: FnMut(&T, &T) -> bool>(
+    v: &[T], start: usize, end: usize, key: usize, is_less: &mut F,
+) -> usize {
+    let (mut lo, mut hi) = (start, end);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(&v[key], &v[mid]) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo - start
+}
+
+/// Swaps `v[a..a + len]` with `v[b..b + len]`.
+fn block_swap<T>(v: &mut [T], a: usize, b: usize, len: usize) {
+    for i in 0..len {
+        v.swap(a + i, b + i);
+    }
+}
+
+/// Returns the least integer `s` such that `s * s >= n`.
+fn sqrt_ceil(n: usize) -> usize {
+    let mut s = (n as f64).sqrt() as usize;
+    while s * s < n {
+        s += 1;
+    }
+    while s > 0 && (s - 1) * (s - 1) >= n {
+        s -= 1;
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn block_merge_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        block_merge(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn block_merge_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        block_merge(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn collect_keys_test() {
+        let mut v = [(3, 0), (1, 1), (3, 2), (2, 3), (1, 4), (5, 5), (2, 6)];
+        let keys = collect_keys(&mut v, 3, &mut |a, b| a.0 < b.0);
+
+        assert_eq!(keys, 3);
+        assert_eq!(v, [(1, 1), (2, 3), (3, 0), (3, 2), (1, 4), (5, 5), (2, 6)]);
+    }
+
+    #[test]
+    fn merge_rotations_test() {
+        let mut v = [1, 3, 3, 5, 2, 3, 4, 6];
+        merge_rotations(&mut v, 0, 4, 8, &mut |a, b| a < b);
+        assert_eq!(v, [1, 2, 3, 3, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn block_merge_stable_test() {
+        let mut rng = thread_rng();
+        // Few distinct elements use the rotation merges, many use the swap buffer.
+        for &distinct in &[1, 2, 5, 30, 100, 1_000_000] {
+            for &len in &[0, 1, 17, 100, 1000, 4321] {
+                let v: Vec<(u32, usize)> =
+                    (0..len).map(|i| (rng.gen_range(0..distinct), i)).collect();
+                let mut p = v.clone();
+                p.sort_by_key(|x| x.0);
+
+                let mut t = v;
+                block_merge_by_key(&mut t, |x| x.0);
+                assert_eq!(t, p, "{} elements, {} distinct", len, distinct);
+            }
+        }
+    }
+
+    #[test]
+    fn block_merge_patterns_test() {
+        let sorted: Vec<i32> = (0..3000).collect();
+        let patterns: Vec<Vec<i32>> = vec![
+            sorted.clone(),
+            sorted.iter().rev().copied().collect(),
+            sorted.iter().map(|x| x % 17).collect(),
+            sorted.iter().map(|x| if x % 2 == 0 { *x } else { 3000 - x }).collect(),
+        ];
+
+        for v in patterns {
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            block_merge(&mut t, &|a, b| a < b);
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Bucket sort implementation.
+
+use std::cmp::Ordering;
+
+use super::{counting::counting_sort, insection_sort};
+
+/// **Bucket Sort:** Sort `v` slice of numbers in crescent order.
+///
+/// It distributes the elements into `v.len()` buckets of the same width between the
+/// minimum and the maximum element, sorts each bucket with insertion sort and
+/// concatenates them. Works best for uniformly distributed data, like `f32` or `f64` in
+/// the `[0, 1)` range.
+///
+/// The position of `NaN`s after sorting is unspecified.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n²)           | O(n)             |
+///
+/// **Obs.:** The average case is for uniformly distributed data.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+/// sort::bucket(&mut v);
+/// assert_eq!(v, [0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// ```
+pub fn bucket<T: Copy + PartialOrd + Into<f64>>(v: &mut [T]) {
+    let n = v.len();
+    if n <= 1 {
+        return;
+    }
+
+    let (min, max) = v.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+        let x = x.into();
+        (min.min(x), max.max(x))
+    });
+    let width = max - min;
+    if width <= 0.0 || !width.is_finite() {
+        // All elements are equal (or there is infinities), so buckets make no sense.
+        insection_sort(v, &mut |a, b| a < b);
+        return;
+    }
+
+    let last = (n - 1) as f64;
+    bucket_by(
+        v,
+        n,
+        |&x| ((x.into() - min) / width * last) as usize,
+        |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+    );
+}
+
+/// **Bucket Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function, distributing the elements into `buckets` buckets given by the
+/// `bucket_of` function.
+///
+/// `bucket_of` must be monotone with `compare`, i.e. if `a` is less than `b` then
+/// `bucket_of(a) <= bucket_of(b)`. Indexes greater than `buckets - 1` are put in the last
+/// bucket.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+k)          |                  |
+/// | Avrg:     | Θ(n+k)          |                  |
+/// | Worst:    | O(n²)           | O(n+k)           |
+///
+/// **Obs.:** k is the number of buckets and the average case is for uniformly distributed
+/// data.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+///
+/// # Panics
+/// This function panics if `buckets` is zero and `v` is not empty.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = ["pear", "apple", "banana", "peach", "avocado", "blueberry"];
+/// // One bucket per initial letter
+/// sort::bucket_by(&mut v, 26, |s| (s.as_bytes()[0] - b'a') as usize, |a, b| a.cmp(b));
+/// assert_eq!(v, ["apple", "avocado", "banana", "blueberry", "peach", "pear"]);
+/// ```
+pub fn bucket_by<T, B, F>(v: &mut [T], buckets: usize, mut bucket_of: B, mut compare: F)
+where
+    B: FnMut(&T) -> usize,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if v.is_empty() {
+        return;
+    }
+    assert!(buckets > 0, "there must be at least one bucket");
+
+    let keys: Vec<usize> = v.iter().map(|x| bucket_of(x).min(buckets - 1)).collect();
+    let starts = counting_sort(v, &keys, buckets - 1);
+
+    let is_less = &mut |a: &T, b: &T| compare(a, b) == Ordering::Less;
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(v.len());
+        insection_sort(&mut v[start..end], is_less);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn bucket_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        bucket(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn bucket_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        bucket(&mut v);
+        assert_eq!(v, p);
+
+        let mut v = [1.0_f32, -2.5, 0.0, -0.5, 2.0];
+        bucket(&mut v);
+        assert_eq!(v, [-2.5, -0.5, 0.0, 1.0, 2.0]);
+
+        let mut v = [2.0, 2.0, 2.0];
+        bucket(&mut v);
+        assert_eq!(v, [2.0, 2.0, 2.0]);
+
+        let mut v = [1.0, f64::INFINITY, -3.0];
+        bucket(&mut v);
+        assert_eq!(v, [-3.0, 1.0, f64::INFINITY]);
+    }
+
+    #[test]
+    fn bucket_random_test() {
+        let mut rng = thread_rng();
+        let v: Vec<f64> = (0..1000).map(|_| rng.gen()).collect();
+        let mut p = v.clone();
+        p.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut t = v;
+        bucket(&mut t);
+        assert_eq!(t, p);
+    }
+
+    #[test]
+    fn bucket_by_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..1000).map(|i| (rng.gen_range(0..1000), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v;
+        bucket_by(&mut t, 10, |x| x.0 as usize / 100, |a, b| a.0.cmp(&b.0));
+        assert_eq!(t, p);
+    }
+}
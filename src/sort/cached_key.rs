@@ -0,0 +1,116 @@
+//! Cached key sorting implementation.
+
+use super::{counting::permute, intro::intro_sort, Sorter};
+
+/// **Cached Key Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function, calling it only once per element.
+///
+/// It decorates each element with its key and its index in a side buffer, sorts the
+/// buffer and then moves the elements to their positions, following the cycles of the
+/// permutation. So it is faster than the `_by_key` sorts when the `key` function is
+/// expensive, like parsing dates or lowercasing strings, or when it allocates.
+///
+/// Since the index breaks the ties of equal keys, this sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** The time complexity is in comparisons of the keys, `key` is called exactly
+/// `n` times.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = ["Banana", "apple", "cherry", "Apple"];
+/// sort::cached_key(&mut v, |s| s.to_lowercase());
+/// assert_eq!(v, ["apple", "Apple", "Banana", "cherry"]);
+/// ```
+pub fn cached_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    let mut keys = decorate(v, key);
+    intro_sort(&mut keys, &mut |a, b| a < b);
+    undecorate(v, &keys);
+}
+
+/// **Cached Key Sort:** Sort `v` slice with the `sorter` algorithm according to the key
+/// extracted by the `key` function, calling it only once per element.
+///
+/// Same as [`cached_key`], but sorting the keys with the chosen algorithm. It is stable
+/// even if the algorithm is not.
+///
+/// [`cached_key`]: ./fn.cached_key.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{self, Heap};
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// sort::cached_key_with(Heap, &mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn cached_key_with<S: Sorter, T, K: Ord, F: FnMut(&T) -> K>(sorter: S, v: &mut [T], key: F) {
+    let mut keys = decorate(v, key);
+    sorter.sort(&mut keys);
+    undecorate(v, &keys);
+}
+
+/// Returns the key of each element with its index.
+fn decorate<T, K, F: FnMut(&T) -> K>(v: &[T], mut key: F) -> Vec<(K, usize)> {
+    v.iter().enumerate().map(|(i, x)| (key(x), i)).collect()
+}
+
+/// Puts `v` in the order of the sorted `keys`.
+fn undecorate<T, K>(v: &mut [T], keys: &[(K, usize)]) {
+    let mut dest = vec![0; v.len()];
+    for (pos, &(_, i)) in keys.iter().enumerate() {
+        dest[i] = pos;
+    }
+    permute(v, &mut dest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::Quick;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn cached_key_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        cached_key(&mut v, |&x| x);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn cached_key_calls_test() {
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+        let mut calls = 0;
+
+        cached_key(&mut v, |&x| {
+            calls += 1;
+            x
+        });
+        assert_eq!(calls, v.len());
+    }
+
+    #[test]
+    fn cached_key_stable_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..1000).map(|i| (rng.gen_range(0..10), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v.clone();
+        cached_key(&mut t, |x| x.0.to_string());
+        assert_eq!(t, p);
+
+        let mut t = v.clone();
+        cached_key_with(Quick, &mut t, |x| x.0);
+        assert_eq!(t, p);
+    }
+}
@@ -0,0 +1,139 @@
+//! Comb sort implementation.
+
+use std::cmp::Ordering;
+
+/// **Comb Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+/// It's a variation of Bubble Sort.
+///
+/// It compares and swaps elements `gap` apart, shrinking the gap by a factor of about 1.3
+/// on each pass until it is 1, when it becomes a bubble sort that stops once no swaps are
+/// made. This moves the small elements at the end of the slice (the "turtles") to the
+/// front quickly, so it is much faster than bubble sort on reversed inputs.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(n²/2^p)       |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// **Obs.:** p is the number of passes with gap greater than 1.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::comb(&mut v, &|v, b| v < b);
+/// ```
+pub fn comb<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    comb_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Comb Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`comb`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`comb`]: ./fn.comb.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::comb_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn comb_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    comb_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Comb Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`comb`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`comb`]: ./fn.comb.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::comb_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn comb_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    comb_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn comb_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let mut gap = v.len();
+    let mut sorted = false;
+
+    while !sorted {
+        // Shrink factor of 1.3, done with integers.
+        gap = (gap * 10 / 13).max(1);
+        // Rule of 11: gaps of 9 and 10 are slower than 11.
+        if gap == 9 || gap == 10 {
+            gap = 11;
+        }
+
+        // Only a pass with gap 1 and no swaps guarantees the slice is sorted.
+        sorted = gap == 1;
+        for i in 0..v.len().saturating_sub(gap) {
+            if is_less(&v[i + gap], &v[i]) {
+                v.swap(i, i + gap);
+                sorted = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn comb_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        comb(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn comb_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        comb(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn comb_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 9, 10, 11, 100, 1000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            comb_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+
+        let mut v: Vec<i32> = (0..1000).rev().collect();
+        comb(&mut v, &|a, b| a < b);
+        assert_eq!(v, (0..1000).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,133 @@
+//! Counting sort implementation.
+
+/// **Counting Sort:** Sort `v` slice according to the `key` of each element, where every
+/// key is in the `0..=max_key` range.
+///
+/// It counts how many elements have each key to find the final position of each element,
+/// then moves the elements to that position. Since it only uses the keys, it can sort
+/// records by small integers like enum discriminants or bucket ids.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+k)          |                  |
+/// | Avrg:     | Θ(n+k)          |                  |
+/// | Worst:    | O(n+k)          | O(n+k)           |
+///
+/// **Obs.:** k is `max_key`.
+///
+/// # Panics
+/// This function panics if `key` returns a value greater than `max_key`.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// #[derive(Debug, PartialEq, Clone, Copy)]
+/// enum Priority {
+///     High,
+///     Medium,
+///     Low,
+/// }
+///
+/// let mut v =
+///     [(Priority::Low, 1), (Priority::High, 2), (Priority::Medium, 3), (Priority::High, 4)];
+/// sort::counting(&mut v, |x| x.0 as usize, 2);
+/// assert_eq!(v, [
+///     (Priority::High, 2),
+///     (Priority::High, 4),
+///     (Priority::Medium, 3),
+///     (Priority::Low, 1)
+/// ]);
+/// ```
+pub fn counting<T, F: Fn(&T) -> usize>(v: &mut [T], key: F, max_key: usize) {
+    let keys: Vec<usize> = v
+        .iter()
+        .map(|x| {
+            let k = key(x);
+            assert!(k <= max_key, "key {} is greater than `max_key` ({})", k, max_key);
+            k
+        })
+        .collect();
+
+    counting_sort(v, &keys, max_key);
+}
+
+/// Sorts `v` by the already computed `keys`, returning the starting position of each key
+/// in the sorted slice.
+pub(super) fn counting_sort<T>(v: &mut [T], keys: &[usize], max_key: usize) -> Vec<usize> {
+    // Turn the counts into the starting position of each key.
+    let mut starts = vec![0_usize; max_key + 1];
+    for &k in keys {
+        starts[k] += 1;
+    }
+    let mut pos = 0;
+    for p in starts.iter_mut() {
+        let count = *p;
+        *p = pos;
+        pos += count;
+    }
+
+    let mut positions = starts.clone();
+    let mut dest: Vec<usize> = keys
+        .iter()
+        .map(|&k| {
+            let d = positions[k];
+            positions[k] += 1;
+            d
+        })
+        .collect();
+
+    permute(v, &mut dest);
+    starts
+}
+
+/// Moves each element `v[i]` to the `dest[i]` position, following the cycles of the
+/// permutation.
+///
+/// `dest` ends up being the identity permutation.
+pub(super) fn permute<T>(v: &mut [T], dest: &mut [usize]) {
+    for i in 0..v.len() {
+        while dest[i] != i {
+            let d = dest[i];
+            v.swap(i, d);
+            dest.swap(i, d);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn counting_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        counting(&mut v, |&x| x, 99);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn counting_stable_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(usize, String)> =
+            (0..1000).map(|i| (rng.gen_range(0..20), i.to_string())).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v;
+        counting(&mut t, |x| x.0, 19);
+        assert_eq!(t, p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn counting_max_key_test() {
+        let mut v = [1, 2, 3];
+        counting(&mut v, |&x| x, 2);
+    }
+}
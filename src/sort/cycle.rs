@@ -0,0 +1,270 @@
+//! Cycle sort implementation.
+
+use std::{cmp::Ordering, mem::ManuallyDrop, ptr};
+
+use super::MergeHole;
+
+/// **Cycle Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// returning the number of writes done to the slice.
+///
+/// It decomposes the permutation of the slice into cycles and rotates each one of them,
+/// writing every element directly into its final position. So each element is written
+/// at most once, and elements already in place are never written, that is the
+/// theoretical minimum number of writes. Useful when writes are much more expensive
+/// than comparisons, like in EEPROM or flash memory.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n²)           |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// **Obs.:** It does at most n writes.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// let writes = sort::cycle(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// assert_eq!(writes, 4);
+/// ```
+pub fn cycle<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> usize {
+    cycle_sort(v, &mut |a, b| cmp(a, b))
+}
+
+/// **Cycle Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function, returning the number of writes done to the slice.
+///
+/// Same as [`cycle`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`cycle`]: ./fn.cycle.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::cycle_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn cycle_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) -> usize {
+    cycle_sort(v, &mut |a, b| compare(a, b) == Ordering::Less)
+}
+
+/// **Cycle Sort:** Sort `v` slice according to the key extracted by the `key` function,
+/// returning the number of writes done to the slice.
+///
+/// Same as [`cycle`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`cycle`]: ./fn.cycle.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::cycle_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn cycle_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) -> usize {
+    cycle_sort(v, &mut |a, b| key(a).lt(&key(b)))
+}
+
+fn cycle_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> usize {
+    let len = v.len();
+    // Every access goes through `base`, since `v[start]` is a hole during the cycle.
+    let base = v.as_mut_ptr();
+    let mut writes = 0;
+
+    for start in 0..len.saturating_sub(1) {
+        // SAFETY: `start` is in bounds and it is not a hole yet.
+        let mut pos = unsafe { position(base, start, len, &*base.add(start), is_less) };
+        if pos == start {
+            continue;
+        }
+
+        // SAFETY: `v[start]` is moved out into `item` and the hole guard moves back the
+        // element in `item` into `v[start]` when the cycle ends or if `is_less` panics,
+        // so every element stays in the slice exactly once. Every `pos` is in bounds and
+        // different from `start`, so it is never the hole.
+        unsafe {
+            let mut item = ManuallyDrop::new(ptr::read(base.add(start)));
+            let item: *mut T = &mut *item;
+            let _hole = MergeHole { start: item, end: item.add(1), dest: base.add(start) };
+
+            // A cycle never has more elements than the range, but with an inconsistent
+            // `is_less` it may never come back to `start`.
+            let mut steps = 0;
+            while pos != start && steps < len - start {
+                ptr::swap_nonoverlapping(item, base.add(pos), 1);
+                writes += 1;
+                steps += 1;
+                pos = position(base, start, len, &*item, is_less);
+            }
+        }
+        // The last element of the cycle is written into the hole.
+        writes += 1;
+    }
+
+    writes
+}
+
+/// Returns the final position of `item` in the `start..len` range of `base`, skipping
+/// the elements equal to `item` that are already in their final position.
+///
+/// The position is always in bounds, even if `is_less` is not a consistent order.
+///
+/// # Safety
+/// `base` must be valid for `len` elements, where `base[start]` may be a hole.
+unsafe fn position<T, F: FnMut(&T, &T) -> bool>(
+    base: *const T, start: usize, len: usize, item: &T, is_less: &mut F,
+) -> usize {
+    let mut pos = start;
+    for i in start + 1..len {
+        if is_less(&*base.add(i), item) {
+            pos += 1;
+        }
+    }
+
+    if pos != start {
+        while pos < len && !is_less(item, &*base.add(pos)) && !is_less(&*base.add(pos), item) {
+            pos += 1;
+        }
+        // Only an inconsistent `is_less` says every element after it is equal to `item`.
+        pos = pos.min(len - 1);
+    }
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn cycle_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        cycle(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn cycle_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        cycle(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn cycle_strings_test() {
+        let mut v: Vec<String> =
+            ["pear", "apple", "fig", "apple", "kiwi"].iter().map(|s| s.to_string()).collect();
+
+        cycle(&mut v, &|a, b| a < b);
+        assert_eq!(v, ["apple", "apple", "fig", "kiwi", "pear"]);
+    }
+
+    #[test]
+    fn cycle_writes_test() {
+        let mut v: Vec<i32> = (0..10).collect();
+        assert_eq!(cycle(&mut v, &|a, b| a < b), 0);
+
+        // Five cycles of length 2.
+        let mut v: Vec<i32> = (0..10).rev().collect();
+        assert_eq!(cycle(&mut v, &|a, b| a < b), 10);
+
+        // A single cycle of length 4, the last element is in place.
+        let mut v = [1, 2, 3, 0, 4];
+        assert_eq!(cycle(&mut v, &|a, b| a < b), 4);
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cycle_panic_test() {
+        use std::{
+            cell::Cell,
+            panic::{self, AssertUnwindSafe},
+        };
+
+        let mut v: Vec<String> = (0..20).rev().map(|x| x.to_string()).collect();
+        let calls = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            cycle(&mut v, &|a, b| {
+                calls.set(calls.get() + 1);
+                if calls.get() == 30 {
+                    panic!("comparator panic");
+                }
+                a < b
+            })
+        }));
+        assert!(result.is_err());
+
+        // Every element must still be in the slice exactly once.
+        let mut p: Vec<String> = (0..20).map(|x| x.to_string()).collect();
+        p.sort();
+        v.sort();
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn cycle_inconsistent_test() {
+        use std::cell::Cell;
+
+        // Every element has the bit 40 set, so an element read out of bounds is noticed.
+        let tag = 1_u64 << 40;
+        let v: Vec<u64> = (0..5).map(|x| tag | x).collect();
+
+        // It says that every pair is equal but the first one compared, so the equal
+        // elements skipped in `position` go past the end.
+        let calls = Cell::new(0);
+        let mut t = v.clone();
+        cycle(&mut t, &|a: &u64, b: &u64| {
+            assert!(a & tag != 0 && b & tag != 0, "element out of bounds");
+            calls.set(calls.get() + 1);
+            calls.get() == 1
+        });
+        let mut sorted = t.clone();
+        sorted.sort();
+        assert_eq!(sorted, v);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let mut t = v.clone();
+            cycle_by(&mut t, |a: &u64, b: &u64| {
+                assert!(a & tag != 0 && b & tag != 0, "element out of bounds");
+                [Ordering::Less, Ordering::Equal, Ordering::Greater][rng.gen_range(0..3)]
+            });
+            t.sort();
+            assert_eq!(t, v);
+        }
+    }
+
+    #[test]
+    fn cycle_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 10, 100, 500] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..50)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            // Only the elements out of place must be written.
+            let misplaced = v.iter().zip(p.iter()).filter(|(a, b)| a != b).count();
+            let mut t = v;
+            assert_eq!(cycle_by(&mut t, |a, b| a.cmp(b)), misplaced);
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Extension trait to call the sorting algorithms as slice methods.
+
+use std::cmp::Ordering;
+
+use super::{BlockMerge, Heap, Insertion, Intro, Merge, Quick, Shell, Smooth, Sorter, Tim};
+
+macro_rules! slice_sort_ext {
+    ($($sorter:ident => $sort:ident, $sort_by:ident, $sort_by_key:ident, $title:literal;)+) => {
+        /// Extension trait to call the sorting algorithms of this module as methods of
+        /// slices.
+        ///
+        /// Every algorithm has three methods: `<name>_sort` sorts in crescent order,
+        /// `<name>_sort_by` takes a three-way comparator and `<name>_sort_by_key` takes a
+        /// function that extracts a key. The other algorithms can be used with
+        /// [`sort_with`] and a [`Sorter`].
+        ///
+        /// [`sort_with`]: #tymethod.sort_with
+        /// [`Sorter`]: ./trait.Sorter.html
+        ///
+        /// # Example
+        /// ```rust
+        /// use algos::sort::{Cycle, SliceSortExt};
+        ///
+        /// let mut v = [9, 3, 5, 7, 8, 7];
+        /// v.quick_sort();
+        /// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+        ///
+        /// v.merge_sort_by(|a, b| b.cmp(a));
+        /// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+        ///
+        /// v.sort_with(Cycle);
+        /// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+        /// ```
+        pub trait SliceSortExt<T> {
+            $(
+            #[doc = concat!("Sorts the slice in crescent order with ", $title, ".")]
+            fn $sort(&mut self)
+            where
+                T: Ord;
+
+            #[doc = concat!("Sorts the slice with ", $title, " according to the ",
+                            "[`Ordering`](https://doc.rust-lang.org/std/cmp/enum.Ordering.html) ",
+                            "returned by the `compare` function.")]
+            fn $sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F);
+
+            #[doc = concat!("Sorts the slice with ", $title, " according to the key ",
+                            "extracted by the `key` function.")]
+            fn $sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F);
+            )+
+
+            /// Sorts the slice in crescent order with the `sorter` algorithm.
+            fn sort_with<S: Sorter>(&mut self, sorter: S)
+            where
+                T: Ord;
+
+            /// Sorts the slice with the `sorter` algorithm according to the [`Ordering`]
+            /// returned by the `compare` function.
+            ///
+            /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+            fn sort_with_by<S: Sorter, F: FnMut(&T, &T) -> Ordering>(
+                &mut self, sorter: S, compare: F,
+            );
+        }
+
+        impl<T> SliceSortExt<T> for [T] {
+            $(
+            fn $sort(&mut self)
+            where
+                T: Ord,
+            {
+                $sorter.sort(self)
+            }
+
+            fn $sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+                $sorter.sort_by(self, compare)
+            }
+
+            fn $sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, key: F) {
+                $sorter.sort_by_key(self, key)
+            }
+            )+
+
+            fn sort_with<S: Sorter>(&mut self, sorter: S)
+            where
+                T: Ord,
+            {
+                sorter.sort(self)
+            }
+
+            fn sort_with_by<S: Sorter, F: FnMut(&T, &T) -> Ordering>(
+                &mut self, sorter: S, compare: F,
+            ) {
+                sorter.sort_by(self, compare)
+            }
+        }
+    };
+}
+
+slice_sort_ext! {
+    Insertion => insertion_sort, insertion_sort_by, insertion_sort_by_key, "Insertion Sort";
+    Merge => merge_sort, merge_sort_by, merge_sort_by_key, "Merge Sort";
+    Quick => quick_sort, quick_sort_by, quick_sort_by_key, "Quick Sort";
+    Heap => heap_sort, heap_sort_by, heap_sort_by_key, "Heap Sort";
+    Tim => tim_sort, tim_sort_by, tim_sort_by_key, "Tim Sort";
+    Intro => intro_sort, intro_sort_by, intro_sort_by_key, "Intro Sort";
+    Shell => shell_sort, shell_sort_by, shell_sort_by_key, "Shell Sort";
+    Smooth => smooth_sort, smooth_sort_by, smooth_sort_by_key, "Smooth Sort";
+    BlockMerge => block_merge_sort, block_merge_sort_by, block_merge_sort_by_key,
+        "Block Merge Sort";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::Quick3;
+
+    #[test]
+    fn slice_sort_ext_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+        let sorts: [fn(&mut [i32]); 10] = [
+            <[i32]>::insertion_sort,
+            <[i32]>::merge_sort,
+            <[i32]>::quick_sort,
+            <[i32]>::heap_sort,
+            <[i32]>::tim_sort,
+            <[i32]>::intro_sort,
+            <[i32]>::shell_sort,
+            <[i32]>::smooth_sort,
+            <[i32]>::block_merge_sort,
+            |v| v.sort_with(Quick3),
+        ];
+
+        for sort in sorts.iter() {
+            let mut t = v;
+            sort(&mut t);
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn slice_sort_ext_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = vec![9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        v.tim_sort_by(|a: &f64, b| a.total_cmp(b));
+        assert_eq!(v, p);
+        v.heap_sort_by_key(|&a| std::cmp::Reverse((a * 10.0) as i64));
+        assert!(v.iter().rev().eq(p.iter()));
+        v.sort_with_by(Quick3, |a, b| a.total_cmp(b));
+        assert_eq!(v, p);
+    }
+}
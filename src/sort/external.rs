@@ -0,0 +1,552 @@
+//! External merge sort, for data that doesn't fit in memory.
+//!
+//! The elements are read in runs of [`run_len`] elements, each run is sorted in memory
+//! and written to a temporary file, and then the runs are read back with a k-way merge,
+//! yielding the elements in order. At most [`fan_in`] runs are read at the same time, if
+//! there are more they are first merged in groups into longer runs, in as many passes as
+//! needed. Only one run and one element per open run are in memory at the same time. The
+//! temporary files are removed when the [`Merge`] iterator is dropped.
+//!
+//! The elements are written to the files with the [`Record`] trait, that is implemented
+//! for the integers, floats, [`String`] and `Vec<u8>`.
+//!
+//! This sort is stable.
+//!
+//! |   Case    | Time complexity | Space complexity |
+//! |:----------|:---------------:|:----------------:|
+//! | Best:     | Ω(nlog(n))      |                  |
+//! | Avrg:     | Θ(nlog(n))      |                  |
+//! | Worst:    | O(nlog(n))      | O(r + n/r)       |
+//!
+//! **Obs.:** r is the run length, and the disk space used is O(n). Each merge pass reads
+//! and writes all the elements again, so there are O(log<sub>k</sub>(n/r)) passes, with k
+//! the fan-in.
+//!
+//! [`run_len`]: ./struct.ExternalSort.html#method.run_len
+//! [`fan_in`]: ./struct.ExternalSort.html#method.fan_in
+//! [`Merge`]: ./struct.Merge.html
+//! [`Record`]: ./trait.Record.html
+//! [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+//!
+//! # Example
+//! ```rust
+//! use algos::sort::external::ExternalSort;
+//!
+//! let log = "b 2\nc 3\na 1\n";
+//! let sorted = ExternalSort::new().run_len(2).sort_lines(log.as_bytes()).unwrap();
+//!
+//! let lines: Vec<String> = sorted.collect::<Result<_, _>>().unwrap();
+//! assert_eq!(lines, ["a 1", "b 2", "c 3"]);
+//! ```
+
+use std::{
+    cmp::Ordering,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{self, AtomicUsize},
+    vec,
+};
+
+use super::kway::RunHeap;
+
+/// Default number of elements of each run.
+pub const DEFAULT_RUN_LEN: usize = 1 << 20;
+
+/// Default maximum number of runs merged at the same time.
+pub const DEFAULT_FAN_IN: usize = 64;
+
+/// Comparator of the [`Merge`] returned by the methods that sort in the natural order of
+/// the elements.
+///
+/// [`Merge`]: ./struct.Merge.html
+pub type NaturalOrder<T> = fn(&T, &T) -> Ordering;
+
+/// Used to give unique names to the temporary files.
+static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Types that can be written to and read from the temporary files of the
+/// [`ExternalSort`].
+///
+/// [`ExternalSort`]: ./struct.ExternalSort.html
+pub trait Record: Sized {
+    /// Writes `self` to `writer`.
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads a value written by [`write_to`] from `reader`, returning `None` if `reader`
+    /// is at its end.
+    ///
+    /// [`write_to`]: #tymethod.write_to
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>>;
+}
+
+/// Fills `buf` from `reader`, returning `false` if `reader` was already at its end.
+fn read_exact_or_eof<R: BufRead>(reader: &mut R, buf: &mut [u8]) -> io::Result<bool> {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(false);
+    }
+    reader.read_exact(buf)?;
+    Ok(true)
+}
+
+macro_rules! impl_record_num {
+    ($($t:ty)+) => {
+        $(
+        impl Record for $t {
+            fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+                let mut buf = [0; std::mem::size_of::<$t>()];
+                Ok(if read_exact_or_eof(reader, &mut buf)? {
+                    Some(<$t>::from_le_bytes(buf))
+                } else {
+                    None
+                })
+            }
+        }
+        )+
+    };
+}
+
+impl_record_num!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl Record for Vec<u8> {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self)
+    }
+
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        match u64::read_from(reader)? {
+            None => Ok(None),
+            Some(len) => {
+                let mut buf = vec![0; len as usize];
+                reader.read_exact(&mut buf)?;
+                Ok(Some(buf))
+            },
+        }
+    }
+}
+
+impl Record for String {
+    fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_to(writer)?;
+        writer.write_all(self.as_bytes())
+    }
+
+    fn read_from<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        match Vec::read_from(reader)? {
+            None => Ok(None),
+            Some(bytes) => String::from_utf8(bytes)
+                .map(Some)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+        }
+    }
+}
+
+/// **External Merge Sort:** Sorts elements from an iterator or a reader using temporary
+/// files, see the [module documentation].
+///
+/// [module documentation]: ./index.html
+#[derive(Debug, Clone)]
+pub struct ExternalSort {
+    run_len:  usize,
+    fan_in:   usize,
+    temp_dir: PathBuf,
+}
+
+impl ExternalSort {
+    /// Creates a sorter with runs of [`DEFAULT_RUN_LEN`] elements, that merges up to
+    /// [`DEFAULT_FAN_IN`] runs at a time and writes the runs in the system temporary
+    /// directory.
+    ///
+    /// [`DEFAULT_RUN_LEN`]: ./constant.DEFAULT_RUN_LEN.html
+    /// [`DEFAULT_FAN_IN`]: ./constant.DEFAULT_FAN_IN.html
+    pub fn new() -> Self {
+        ExternalSort { run_len: DEFAULT_RUN_LEN, fan_in: DEFAULT_FAN_IN, temp_dir: env::temp_dir() }
+    }
+
+    /// Sets the number of elements sorted in memory at a time.
+    pub fn run_len(mut self, run_len: usize) -> Self {
+        self.run_len = run_len.max(1);
+        self
+    }
+
+    /// Sets the maximum number of runs merged at the same time, that is also the maximum
+    /// number of temporary files open at the same time.
+    pub fn fan_in(mut self, fan_in: usize) -> Self {
+        self.fan_in = fan_in.max(2);
+        self
+    }
+
+    /// Sets the directory where the runs are written.
+    pub fn temp_dir<P: Into<PathBuf>>(mut self, temp_dir: P) -> Self {
+        self.temp_dir = temp_dir.into();
+        self
+    }
+
+    /// Sorts the elements of `iter` in crescent order.
+    pub fn sort<T, I>(&self, iter: I) -> io::Result<Merge<T, NaturalOrder<T>>>
+    where
+        T: Record + Ord,
+        I: IntoIterator<Item = T>,
+    {
+        self.sort_by(iter, T::cmp)
+    }
+
+    /// Sorts the elements of `iter` according to the [`Ordering`] returned by the
+    /// `compare` function.
+    ///
+    /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::sort::external::ExternalSort;
+    ///
+    /// let sorted = ExternalSort::new().run_len(3).sort_by(vec![9, 3, 5, 7, 8, 7], |a, b| b.cmp(a));
+    /// let v: Vec<i32> = sorted.unwrap().collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+    /// ```
+    pub fn sort_by<T, I, F>(&self, iter: I, compare: F) -> io::Result<Merge<T, F>>
+    where
+        T: Record,
+        I: IntoIterator<Item = T>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.try_sort_by(iter.into_iter().map(Ok), compare)
+    }
+
+    /// Sorts the elements of `iter` according to the key extracted by the `key`
+    /// function.
+    pub fn sort_by_key<T, I, K, F>(
+        &self, iter: I, mut key: F,
+    ) -> io::Result<Merge<T, impl FnMut(&T, &T) -> Ordering>>
+    where
+        T: Record,
+        I: IntoIterator<Item = T>,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(iter, move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Sorts the lines of `reader`, without the line terminators.
+    pub fn sort_lines<R: Read>(
+        &self, reader: R,
+    ) -> io::Result<Merge<String, NaturalOrder<String>>> {
+        self.try_sort_by(BufReader::new(reader).lines(), String::cmp)
+    }
+
+    /// Sorts the elements of `iter`, stopping at the first error.
+    pub fn try_sort_by<T, I, F>(&self, iter: I, mut compare: F) -> io::Result<Merge<T, F>>
+    where
+        T: Record,
+        I: IntoIterator<Item = io::Result<T>>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut iter = iter.into_iter();
+        let mut files = Vec::new();
+
+        let last = loop {
+            let mut run = Vec::with_capacity(self.run_len.min(DEFAULT_RUN_LEN));
+            for value in iter.by_ref().take(self.run_len) {
+                run.push(value?);
+            }
+            super::merge_by(&mut run, &mut compare);
+
+            // The last run is kept in memory.
+            if run.len() < self.run_len {
+                break run;
+            }
+            files.push(self.write_run(run.into_iter().map(Ok))?);
+        };
+
+        // The run in memory takes a place in the last merge too.
+        while files.len() >= self.fan_in {
+            let mut groups = files.into_iter().peekable();
+            files = Vec::new();
+            while groups.peek().is_some() {
+                let group: Vec<_> = groups.by_ref().take(self.fan_in).collect();
+                files.push(self.merge_files(group, &mut compare)?);
+            }
+        }
+
+        let runs = files.into_iter().map(|file| file.open().and_then(Run::new));
+        let mut runs = runs.collect::<io::Result<Vec<_>>>()?;
+        runs.push(Run::new(Source::Memory(last.into_iter()))?);
+        Ok(Merge::new(runs, compare))
+    }
+
+    /// Merges the runs of `files` into a new temporary file.
+    fn merge_files<T, F>(&self, mut files: Vec<RunFile>, compare: F) -> io::Result<RunFile>
+    where
+        T: Record,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if files.len() == 1 {
+            return Ok(files.remove(0));
+        }
+        let runs = files.into_iter().map(|file| file.open().and_then(Run::new));
+        self.write_run(Merge::new(runs.collect::<io::Result<_>>()?, compare))
+    }
+
+    /// Writes the elements of `run` to a new temporary file.
+    fn write_run<T, I>(&self, run: I) -> io::Result<RunFile>
+    where
+        T: Record,
+        I: IntoIterator<Item = io::Result<T>>,
+    {
+        let (file, path) = loop {
+            let path = self.temp_dir.join(format!(
+                "algos-external-{}-{}.run",
+                process::id(),
+                RUN_COUNTER.fetch_add(1, atomic::Ordering::Relaxed)
+            ));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (file, path),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            }
+        };
+        // From now on the file is removed when `run_file` is dropped, even on errors.
+        let run_file = RunFile { path };
+
+        // The file is closed when done, so only the runs being merged are open.
+        let mut writer = BufWriter::new(file);
+        for value in run {
+            value?.write_to(&mut writer)?;
+        }
+        writer.flush()?;
+        Ok(run_file)
+    }
+}
+
+impl Default for ExternalSort {
+    fn default() -> Self { Self::new() }
+}
+
+/// A temporary file with a sorted run, that is removed when dropped.
+#[derive(Debug)]
+struct RunFile {
+    path: PathBuf,
+}
+
+impl RunFile {
+    /// Opens the file to read the run.
+    fn open<T>(self) -> io::Result<Source<T>> {
+        let reader = BufReader::new(File::open(&self.path)?);
+        Ok(Source::File { reader, _file: self })
+    }
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) { let _ = fs::remove_file(&self.path); }
+}
+
+#[derive(Debug)]
+enum Source<T> {
+    Memory(vec::IntoIter<T>),
+    /// The file is kept only to be removed when the run is dropped.
+    File { reader: BufReader<File>, _file: RunFile },
+}
+
+/// A sorted run and its least element not yet merged.
+#[derive(Debug)]
+struct Run<T> {
+    source: Source<T>,
+    head:   Option<T>,
+}
+
+impl<T: Record> Run<T> {
+    fn new(source: Source<T>) -> io::Result<Self> {
+        let mut run = Run { source, head: None };
+        run.advance()?;
+        Ok(run)
+    }
+
+    /// Reads the next element of the run into `head`.
+    fn advance(&mut self) -> io::Result<()> {
+        self.head = match &mut self.source {
+            Source::Memory(iter) => iter.next(),
+            Source::File { reader, .. } => T::read_from(reader)?,
+        };
+        Ok(())
+    }
+}
+
+/// Iterator over the sorted elements of an [`ExternalSort`], doing a k-way merge of the
+/// sorted runs.
+///
+/// If reading a run fails, it yields the error and then ends.
+///
+/// [`ExternalSort`]: ./struct.ExternalSort.html
+#[derive(Debug)]
+pub struct Merge<T, F> {
+    runs:    Vec<Run<T>>,
+    /// The runs with a head, by their head and then by their index.
+    heap:    RunHeap,
+    compare: F,
+    error:   Option<io::Error>,
+}
+
+impl<T, F: FnMut(&T, &T) -> Ordering> Merge<T, F> {
+    fn new(runs: Vec<Run<T>>, mut compare: F) -> Self {
+        let heads = (0..runs.len()).filter(|&run| runs[run].head.is_some()).collect();
+        let heap = RunHeap::new(heads, &mut Self::is_less(&runs, &mut compare));
+        Merge { runs, heap, compare, error: None }
+    }
+
+    /// Returns a function that returns `true` if the run `a` must come before the run
+    /// `b`.
+    fn is_less<'s>(
+        runs: &'s [Run<T>], compare: &'s mut F,
+    ) -> impl FnMut(usize, usize) -> bool + 's {
+        move |a, b| match (&runs[a].head, &runs[b].head) {
+            // On ties the run that came first in the input goes first, so it is stable.
+            (Some(x), Some(y)) => compare(x, y).then(a.cmp(&b)) == Ordering::Less,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Record, F: FnMut(&T, &T) -> Ordering> Iterator for Merge<T, F> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            self.heap.clear();
+            return Some(Err(err));
+        }
+
+        let run = self.heap.first()?;
+        let value = self.runs[run].head.take();
+        if let Err(err) = self.runs[run].advance() {
+            // Yield the value that was already read, and the error in the next call.
+            self.error = Some(err);
+        }
+        let exhausted = self.runs[run].head.is_none();
+        self.heap.update_first(exhausted, &mut Self::is_less(&self.runs, &mut self.compare));
+
+        value.map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("algos-external-test-{}-{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn external_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let v = vec![9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        let sorted = ExternalSort::new().run_len(3).sort(v).unwrap();
+        assert_eq!(sorted.collect::<io::Result<Vec<_>>>().unwrap(), p);
+    }
+
+    #[test]
+    fn external_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let v = vec![9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        let sorted = ExternalSort::new().run_len(4).sort_by(v, |a: &f64, b| a.total_cmp(b));
+        assert_eq!(sorted.unwrap().collect::<io::Result<Vec<_>>>().unwrap(), p);
+    }
+
+    #[test]
+    fn external_stable_test() {
+        let dir = temp_dir("stable");
+        let mut rng = thread_rng();
+        for &(len, run_len) in &[(0, 10), (1, 10), (100, 100), (1000, 7), (10_000, 1000)] {
+            // The key is in the high bits and the position in the low bits.
+            let v: Vec<u64> = (0..len).map(|i| (rng.gen_range(0..10) << 32) | i).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x >> 32);
+
+            let sorter = ExternalSort::new().run_len(run_len).temp_dir(&dir);
+            let sorted = sorter.sort_by_key(v, |x| x >> 32).unwrap();
+            assert_eq!(sorted.collect::<io::Result<Vec<_>>>().unwrap(), p);
+        }
+        // Every temporary file was removed.
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn external_fan_in_test() {
+        let dir = temp_dir("fan-in");
+        let mut rng = thread_rng();
+        // Enough runs for several merge passes, with and without a run in memory.
+        for &(len, run_len, fan_in) in &[(1000, 7, 3), (1000, 10, 2), (640, 10, 64), (65, 1, 8)] {
+            let v: Vec<u64> = (0..len).map(|i| (rng.gen_range(0..10) << 32) | i).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x >> 32);
+
+            let sorter = ExternalSort::new().run_len(run_len).fan_in(fan_in).temp_dir(&dir);
+            let sorted = sorter.sort_by_key(v, |x| x >> 32).unwrap();
+            // Only the runs of the last merge are left.
+            assert!(fs::read_dir(&dir).unwrap().count() < fan_in);
+            assert_eq!(sorted.collect::<io::Result<Vec<_>>>().unwrap(), p);
+        }
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn external_lines_test() {
+        let dir = temp_dir("lines");
+        let mut rng = thread_rng();
+        let lines: Vec<String> =
+            (0..1000).map(|_| format!("line {}, ção", rng.gen_range(0..500))).collect();
+        let mut p = lines.clone();
+        p.sort();
+
+        let text = lines.join("\n");
+        let sorter = ExternalSort::new().run_len(64).temp_dir(&dir);
+        let mut sorted = sorter.sort_lines(text.as_bytes()).unwrap();
+        assert!(fs::read_dir(&dir).unwrap().count() > 1);
+
+        assert_eq!(sorted.by_ref().take(10).collect::<io::Result<Vec<_>>>().unwrap(), p[..10]);
+        // Dropping the iterator before the end removes the files too.
+        drop(sorted);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn external_error_test() {
+        let dir = temp_dir("error");
+        let iter =
+            (0..100u32).map(|i| if i == 50 { Err(io::Error::other("broken")) } else { Ok(i) });
+
+        let sorter = ExternalSort::new().run_len(10).temp_dir(&dir);
+        assert!(sorter.try_sort_by(iter, u32::cmp).is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn record_test() {
+        let mut buf = Vec::new();
+        (-5i64).write_to(&mut buf).unwrap();
+        String::from("algos").write_to(&mut buf).unwrap();
+        vec![1u8, 2, 3].write_to(&mut buf).unwrap();
+
+        let mut reader = &buf[..];
+        assert_eq!(i64::read_from(&mut reader).unwrap(), Some(-5));
+        assert_eq!(String::read_from(&mut reader).unwrap(), Some(String::from("algos")));
+        assert_eq!(Vec::read_from(&mut reader).unwrap(), Some(vec![1u8, 2, 3]));
+        assert_eq!(u8::read_from(&mut reader).unwrap(), None);
+    }
+}
@@ -0,0 +1,238 @@
+//! Flash sort implementation.
+
+use std::cmp::Ordering;
+
+use super::intro::intro_sort;
+use crate::numerics::NumericKey;
+
+/// **Flash Sort:** Sort `v` slice of numbers in crescent order.
+///
+/// It classifies the elements into about `0.43·n` classes of the same width between the
+/// minimum and the maximum element, using the classic ratio of Karl-Dietrich Neubert,
+/// moves each element to its class in place and sorts each class. Works best for large
+/// and roughly uniformly distributed data, where the classes are small.
+///
+/// The position of `NaN`s after sorting is unspecified.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** The average case is for uniformly distributed data. The space complexity is
+/// the count of each class, not the elements.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+/// sort::flash(&mut v);
+/// assert_eq!(v, [0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// ```
+pub fn flash<T: NumericKey>(v: &mut [T]) {
+    let n = v.len();
+    if n <= 1 {
+        return;
+    }
+
+    let (min, max) = v.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+        let x = x.to_f64();
+        (min.min(x), max.max(x))
+    });
+    let width = max - min;
+    let is_less = &mut |a: &T, b: &T| a < b;
+    if width <= 0.0 {
+        // All elements are equal.
+        return;
+    }
+    if !width.is_finite() {
+        // There is infinities, so the classes make no sense.
+        intro_sort(v, is_less);
+        return;
+    }
+
+    let classes = (n * 43 / 100).max(1);
+    let last = (classes - 1) as f64;
+    flash_sort(v, classes, &mut |&x| ((x.to_f64() - min) / width * last) as usize, is_less);
+}
+
+/// **Flash Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function, classifying the elements into `classes` classes given by the `class_of`
+/// function.
+///
+/// `class_of` must be monotone with `compare`, i.e. if `a` is less than `b` then
+/// `class_of(a) <= class_of(b)`. Indexes greater than `classes - 1` are put in the last
+/// class. It is called a few times for each element, so it should be cheap.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n+m)          |                  |
+/// | Avrg:     | Θ(n+m)          |                  |
+/// | Worst:    | O(nlog(n)+m)    | O(m)             |
+///
+/// **Obs.:** m is the number of classes and the average case is for uniformly distributed
+/// data.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+///
+/// # Panics
+/// This function panics if `classes` is zero and `v` is not empty.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [(9, 'a'), (3, 'b'), (5, 'c'), (71, 'd'), (38, 'e'), (14, 'f')];
+/// // One class per ten
+/// sort::flash_by(&mut v, 8, |x| x.0 / 10, |a, b| a.0.cmp(&b.0));
+/// assert_eq!(v, [(3, 'b'), (5, 'c'), (9, 'a'), (14, 'f'), (38, 'e'), (71, 'd')]);
+/// ```
+pub fn flash_by<T, C, F>(v: &mut [T], classes: usize, mut class_of: C, mut compare: F)
+where
+    C: FnMut(&T) -> usize,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if v.is_empty() {
+        return;
+    }
+    assert!(classes > 0, "there must be at least one class");
+
+    flash_sort(v, classes, &mut class_of, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+fn flash_sort<T, C, F>(v: &mut [T], classes: usize, class_of: &mut C, is_less: &mut F)
+where
+    C: FnMut(&T) -> usize,
+    F: FnMut(&T, &T) -> bool,
+{
+    let last = classes - 1;
+    let mut class_of = |x: &T| class_of(x).min(last);
+
+    // `heads[k]` is the next position of the class `k` still to be filled, and
+    // `starts[k]` is where it starts.
+    let mut starts = vec![0; classes + 1];
+    for x in v.iter() {
+        starts[class_of(x) + 1] += 1;
+    }
+    for k in 1..=classes {
+        starts[k] += starts[k - 1];
+    }
+    let mut heads = starts[..classes].to_vec();
+
+    // The flash: each element out of its class is swapped to the head of its class, so
+    // every element is moved at most once.
+    for k in 0..classes {
+        while heads[k] < starts[k + 1] {
+            let c = class_of(&v[heads[k]]);
+            if c != k {
+                v.swap(heads[k], heads[c]);
+            }
+            heads[c] += 1;
+        }
+    }
+
+    for k in 0..classes {
+        intro_sort(&mut v[starts[k]..starts[k + 1]], is_less);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn flash_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        flash(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn flash_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        flash(&mut v);
+        assert_eq!(v, p);
+
+        let mut v = [1.0_f32, -2.5, 0.0, -0.5, 2.0];
+        flash(&mut v);
+        assert_eq!(v, [-2.5, -0.5, 0.0, 1.0, 2.0]);
+
+        let mut v = [2.0, 2.0, 2.0];
+        flash(&mut v);
+        assert_eq!(v, [2.0, 2.0, 2.0]);
+
+        let mut v = [1.0, f64::INFINITY, -3.0];
+        flash(&mut v);
+        assert_eq!(v, [-3.0, 1.0, f64::INFINITY]);
+    }
+
+    #[test]
+    fn flash_wide_integers_test() {
+        let mut v = [i64::MAX, -7, i64::MIN, 0, 42, -7];
+        flash(&mut v);
+        assert_eq!(v, [i64::MIN, -7, -7, 0, 42, i64::MAX]);
+
+        let mut v = [u64::MAX, 3, u64::MAX - 1, 0, 1 << 60];
+        flash(&mut v);
+        assert_eq!(v, [0, 3, 1 << 60, u64::MAX - 1, u64::MAX]);
+
+        let mut v = [i128::MAX, 5, i128::MIN, -5];
+        flash(&mut v);
+        assert_eq!(v, [i128::MIN, -5, 5, i128::MAX]);
+
+        let mut rng = thread_rng();
+        let v: Vec<usize> = (0..1000).map(|_| rng.gen()).collect();
+        let mut p = v.clone();
+        p.sort();
+        let mut t = v;
+        flash(&mut t);
+        assert_eq!(t, p);
+    }
+
+    #[test]
+    fn flash_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 10, 100, 10000] {
+            let v: Vec<f64> = (0..len).map(|_| rng.gen()).collect();
+            let mut p = v.clone();
+            p.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            let mut t = v;
+            flash(&mut t);
+            assert_eq!(t, p);
+
+            // Skewed data puts most elements in a few classes.
+            let v: Vec<i32> = (0..len).map(|_| rng.gen_range(0..1000_i32).pow(3) / 1000).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            flash(&mut t);
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn flash_by_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..1000).map(|i| (rng.gen_range(0..1000), i)).collect();
+        let mut p = v.clone();
+        p.sort();
+
+        let mut t = v;
+        // The last class also gets the indexes past it.
+        flash_by(&mut t, 7, |x| x.0 as usize / 100, |a, b| a.cmp(b));
+        assert_eq!(t, p);
+    }
+}
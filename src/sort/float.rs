@@ -0,0 +1,266 @@
+//! Sorting of floating point numbers.
+//!
+//! Floats don't implement [`Ord`], since `NaN` is not comparable to any number. The
+//! functions of this module sort them by the IEEE 754 `totalOrder` predicate, the same as
+//! `f64::total_cmp`, with a [`NanPolicy`] that tells where the `NaN`s go.
+//!
+//! In `totalOrder`, `-0.0` comes before `+0.0`, and the negative and positive `NaN`s come
+//! before and after every number.
+//!
+//! [`Ord`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html
+//! [`NanPolicy`]: ./enum.NanPolicy.html
+//!
+//! # Example
+//! ```rust
+//! use algos::sort::float::{self, NanPolicy};
+//!
+//! let mut v = [2.5, f64::NAN, -1.0, 0.0, -0.0];
+//! float::sort_with(&mut v, NanPolicy::First).unwrap();
+//! assert!(v[0].is_nan());
+//! assert_eq!(v[1..], [-1.0, -0.0, 0.0, 2.5]);
+//!
+//! let mut v = [2.5, f64::NAN, -1.0];
+//! assert!(float::sort_with(&mut v, NanPolicy::Error).is_err());
+//! ```
+
+use std::{cmp::Ordering, error::Error, fmt};
+
+use super::intro::intro_sort;
+
+/// A floating point type.
+pub trait Float: Copy {
+    /// Returns the ordering of `self` and `other` by the IEEE 754 `totalOrder` predicate.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns `true` if `self` is `NaN`.
+    fn is_nan(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+        impl Float for $t {
+            fn total_cmp(&self, other: &Self) -> Ordering { <$t>::total_cmp(self, other) }
+
+            fn is_nan(self) -> bool { <$t>::is_nan(self) }
+        }
+        )+
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Where to put the `NaN`s when sorting floats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NanPolicy {
+    /// Every `NaN` goes before the numbers.
+    First,
+    /// Every `NaN` goes after the numbers.
+    #[default]
+    Last,
+    /// Return an error if there is a `NaN`, without changing the slice.
+    Error,
+    /// Sort them by `totalOrder`: the negative `NaN`s go first and the positive ones go
+    /// last.
+    Total,
+}
+
+/// The error returned when sorting floats with a `NaN` and [`NanPolicy::Error`].
+///
+/// [`NanPolicy::Error`]: ./enum.NanPolicy.html#variant.Error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NanError {
+    index: usize,
+}
+
+impl NanError {
+    /// Returns the index of the first `NaN` in the slice.
+    pub fn index(&self) -> usize { self.index }
+}
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NaN found at index {}", self.index)
+    }
+}
+
+impl Error for NanError {}
+
+/// **Float Sort:** Sort `v` slice of floats in crescent order, with the `NaN`s last.
+///
+/// Same as [`sort_with`] with [`NanPolicy::Last`].
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(log(n))        |
+///
+/// [`sort_with`]: ./fn.sort_with.html
+/// [`NanPolicy::Last`]: ./enum.NanPolicy.html#variant.Last
+///
+/// # Example
+/// ```rust
+/// use algos::sort::float;
+///
+/// let mut v = [9.5, f32::NAN, 3.1, 5.2, 7.3];
+/// float::sort(&mut v);
+/// assert_eq!(v[..4], [3.1, 5.2, 7.3, 9.5]);
+/// assert!(v[4].is_nan());
+/// ```
+pub fn sort<F: Float>(v: &mut [F]) {
+    let non_nan = move_nans(v, &mut |&x| x, NanPolicy::Last);
+    intro_sort(non_nan, &mut |a, b| a.total_cmp(b) == Ordering::Less);
+}
+
+/// **Float Sort:** Sort `v` slice of floats in crescent order, putting the `NaN`s
+/// according to the `nan` policy.
+///
+/// The numbers are sorted by the IEEE 754 `totalOrder` predicate, with intro sort.
+///
+/// # Errors
+/// Returns a [`NanError`] if `nan` is [`NanPolicy::Error`] and there is a `NaN` in `v`,
+/// without changing it.
+///
+/// [`NanError`]: ./struct.NanError.html
+/// [`NanPolicy::Error`]: ./enum.NanPolicy.html#variant.Error
+///
+/// # Example
+/// ```rust
+/// use algos::sort::float::{self, NanPolicy};
+///
+/// let mut v = [9.5, 3.1, f64::NAN, 5.2];
+/// float::sort_with(&mut v, NanPolicy::Last).unwrap();
+/// assert_eq!(v[..3], [3.1, 5.2, 9.5]);
+///
+/// let err = float::sort_with(&mut [1.0, f64::NAN], NanPolicy::Error).unwrap_err();
+/// assert_eq!(err.index(), 1);
+/// ```
+pub fn sort_with<F: Float>(v: &mut [F], nan: NanPolicy) -> Result<(), NanError> {
+    sort_by_key_with(v, |&x| x, nan)
+}
+
+/// **Float Sort:** Sort `v` slice according to the float key extracted by the `key`
+/// function, putting the elements with `NaN` keys according to the `nan` policy.
+///
+/// Same as [`sort_with`], but comparing the keys returned by `key`.
+///
+/// # Errors
+/// Returns a [`NanError`] if `nan` is [`NanPolicy::Error`] and there is a `NaN` key,
+/// without changing `v`.
+///
+/// [`sort_with`]: ./fn.sort_with.html
+/// [`NanError`]: ./struct.NanError.html
+/// [`NanPolicy::Error`]: ./enum.NanPolicy.html#variant.Error
+///
+/// # Example
+/// ```rust
+/// use algos::sort::float::{self, NanPolicy};
+///
+/// let mut v = [("b", 2.5), ("c", f64::NAN), ("a", -1.0)];
+/// float::sort_by_key_with(&mut v, |x| x.1, NanPolicy::First).unwrap();
+/// assert_eq!(v[0].0, "c");
+/// assert_eq!(v[1..], [("a", -1.0), ("b", 2.5)]);
+/// ```
+pub fn sort_by_key_with<T, F: Float, K: FnMut(&T) -> F>(
+    v: &mut [T], mut key: K, nan: NanPolicy,
+) -> Result<(), NanError> {
+    if nan == NanPolicy::Error {
+        if let Some(index) = v.iter().position(|x| key(x).is_nan()) {
+            return Err(NanError { index });
+        }
+    }
+
+    let non_nan = move_nans(v, &mut key, nan);
+    intro_sort(non_nan, &mut |a, b| key(a).total_cmp(&key(b)) == Ordering::Less);
+    Ok(())
+}
+
+/// Moves the elements with `NaN` keys to the start or the end of `v`, as told by `nan`,
+/// returning the rest of it.
+fn move_nans<'a, T, F: Float, K: FnMut(&T) -> F>(
+    v: &'a mut [T], key: &mut K, nan: NanPolicy,
+) -> &'a mut [T] {
+    match nan {
+        NanPolicy::First => {
+            let mut first = 0;
+            for i in 0..v.len() {
+                if key(&v[i]).is_nan() {
+                    v.swap(first, i);
+                    first += 1;
+                }
+            }
+            &mut v[first..]
+        },
+        NanPolicy::Last => {
+            let mut last = 0;
+            for i in 0..v.len() {
+                if !key(&v[i]).is_nan() {
+                    v.swap(last, i);
+                    last += 1;
+                }
+            }
+            &mut v[..last]
+        },
+        NanPolicy::Error | NanPolicy::Total => v,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn float_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        sort(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn nan_policy_test() {
+        let v = [1.0, f64::NAN, -0.0, -f64::NAN, 0.0, f64::NEG_INFINITY, -2.0];
+
+        let mut t = v;
+        sort_with(&mut t, NanPolicy::First).unwrap();
+        assert!(t[..2].iter().all(|x| x.is_nan()));
+        assert_eq!(t[2..], [f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.0]);
+        assert!(t[4].is_sign_negative() && t[5].is_sign_positive());
+
+        let mut t = v;
+        sort_with(&mut t, NanPolicy::Last).unwrap();
+        assert_eq!(t[..5], [f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.0]);
+        assert!(t[5..].iter().all(|x| x.is_nan()));
+
+        let mut t = v;
+        sort_with(&mut t, NanPolicy::Total).unwrap();
+        assert!(t[0].is_nan() && t[0].is_sign_negative());
+        assert_eq!(t[1..6], [f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.0]);
+        assert!(t[6].is_nan() && t[6].is_sign_positive());
+
+        let mut t = v;
+        assert_eq!(sort_with(&mut t, NanPolicy::Error), Err(NanError { index: 1 }));
+        assert_eq!(t[..1], v[..1]);
+        assert!(sort_with(&mut [2.0f32, 1.0], NanPolicy::Error).is_ok());
+    }
+
+    #[test]
+    fn float_random_test() {
+        let mut rng = thread_rng();
+        let v: Vec<f32> = (0..1000)
+            .map(|_| if rng.gen_ratio(1, 10) { f32::NAN } else { rng.gen_range(-1e3..1e3) })
+            .collect();
+        let mut p: Vec<f32> = v.iter().copied().filter(|x| !x.is_nan()).collect();
+        p.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut t = v.clone();
+        sort(&mut t);
+        assert_eq!(t[..p.len()], p[..]);
+        assert!(t[p.len()..].iter().all(|x| x.is_nan()));
+    }
+}
@@ -0,0 +1,119 @@
+//! Gnome sort implementation.
+
+use std::cmp::Ordering;
+
+/// **Gnome Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+/// It's a variation of Insertion Sort.
+///
+/// Like a garden gnome sorting flower pots, it looks at the current and the previous
+/// element: if they are in order it steps forward, otherwise it swaps them and steps
+/// back.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::gnome(&mut v, &|v, b| v < b);
+/// ```
+pub fn gnome<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    gnome_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Gnome Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`gnome`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`gnome`]: ./fn.gnome.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::gnome_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn gnome_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    gnome_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Gnome Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`gnome`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`gnome`]: ./fn.gnome.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::gnome_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn gnome_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    gnome_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn gnome_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let mut i = 1;
+    while i < v.len() {
+        if i > 0 && is_less(&v[i], &v[i - 1]) {
+            v.swap(i, i - 1);
+            i -= 1;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn gnome_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        gnome(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn gnome_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        gnome(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn gnome_stable_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..300).map(|i| (rng.gen_range(0..10), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v;
+        gnome_by_key(&mut t, |x| x.0);
+        assert_eq!(t, p);
+    }
+}
@@ -0,0 +1,218 @@
+//! Instrumented versions of the classic sorting algorithms, that count the operations
+//! they do.
+//!
+//! Each function sorts with the same code of the function of the same name in the
+//! [`sort`] module, counting its comparisons and swaps through the comparator and a hook,
+//! and returns a [`SortStats`] with how many comparisons, swaps and moves it did. It is
+//! meant to compare the algorithms empirically.
+//!
+//! [`sort`]: ../index.html
+//! [`SortStats`]: ./struct.SortStats.html
+//!
+//! # Example
+//! ```rust
+//! use algos::sort::instrumented;
+//!
+//! let v: Vec<u32> = (0..100).rev().collect();
+//!
+//! let bubble = instrumented::bubble(&mut v.clone(), &|a, b| a < b);
+//! let merge = instrumented::merge(&mut v.clone(), &|a, b| a < b);
+//! assert_eq!(bubble.comparisons, 100 * 99 / 2);
+//! assert!(merge.comparisons < bubble.comparisons);
+//! ```
+
+use std::fmt;
+
+/// Number of operations done by a sorting algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SortStats {
+    /// Number of times two elements were compared.
+    pub comparisons: usize,
+    /// Number of times two elements were swapped.
+    pub swaps:       usize,
+    /// Number of times an element was moved to or from auxiliar memory, or inside the
+    /// slice without a swap.
+    pub moves:       usize,
+}
+
+impl fmt::Display for SortStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} comparisons, {} swaps, {} moves", self.comparisons, self.swaps, self.moves)
+    }
+}
+
+/// Runs `sort` on `v` with `cmp` and a hook called after each swap, counting the
+/// comparisons and the swaps it does.
+fn counted<T, C, G>(v: &mut [T], cmp: &C, sort: G) -> SortStats
+where
+    C: Fn(&T, &T) -> bool,
+    G: FnOnce(&mut [T], &mut dyn FnMut(&T, &T) -> bool, &mut dyn FnMut()),
+{
+    let (mut comparisons, mut swaps) = (0, 0);
+    sort(
+        v,
+        &mut |a, b| {
+            comparisons += 1;
+            cmp(a, b)
+        },
+        &mut || swaps += 1,
+    );
+    SortStats { comparisons, swaps, moves: 0 }
+}
+
+/// **Selection Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter, returning the operations done.
+///
+/// See [`sort::selection`](../fn.selection.html).
+pub fn selection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::selection_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+/// **Bubble Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// returning the operations done.
+///
+/// See [`sort::bubble`](../fn.bubble.html).
+pub fn bubble<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::bubble_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+/// **Cocktail Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter, returning the operations done.
+///
+/// See [`sort::cocktail`](../fn.cocktail.html).
+pub fn cocktail<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::cocktail_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+/// **Insertion Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter, returning the operations done.
+///
+/// See [`sort::insection`](../fn.insection.html).
+pub fn insection<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::insection_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+/// **Merge Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// returning the operations done.
+///
+/// See [`sort::merge`](../fn.merge.html). It doesn't do swaps, the elements are moved to
+/// auxiliar memory and back.
+pub fn merge<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    // Each merge moves the left half to the buffer and every element of it back, and
+    // moves an element of the right half each time it is less than the left one.
+    let (mut comparisons, mut from_right) = (0, 0);
+    super::merge_sort(v, &mut |a, b| {
+        comparisons += 1;
+        let less = cmp(a, b);
+        if less {
+            from_right += 1;
+        }
+        less
+    });
+    let moves = if std::mem::size_of::<T>() == 0 { 0 } else { left_halves(v.len()) };
+    SortStats { comparisons, swaps: 0, moves: 2 * moves + from_right }
+}
+
+/// Returns the sum of the sizes of the left halves merged by merge sort for `len`
+/// elements.
+fn left_halves(len: usize) -> usize {
+    if len <= 1 {
+        return 0;
+    }
+    let mid = len / 2;
+    mid + left_halves(mid) + left_halves(len - mid)
+}
+
+/// **Quick Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// returning the operations done.
+///
+/// See [`sort::quick`](../fn.quick.html). The pivots are random, so the number of
+/// operations varies for the same input.
+pub fn quick<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::quick_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+/// **Heap Sort:** Sort `v` slice according to the way you define the `cmp` parameter,
+/// returning the operations done.
+///
+/// See [`sort::heap`](../fn.heap.html).
+pub fn heap<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) -> SortStats {
+    counted(v, cmp, |v, mut is_less, mut on_swap| {
+        super::heap_sort_hooked(v, &mut is_less, &mut on_swap)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Instrumented = fn(&mut [i32], &fn(&i32, &i32) -> bool) -> SortStats;
+
+    const ALGORITHMS: [Instrumented; 7] =
+        [selection, bubble, cocktail, insection, merge, quick, heap];
+
+    #[test]
+    fn instrumented_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let cmp: fn(&i32, &i32) -> bool = |a, b| a < b;
+        for sort in ALGORITHMS.iter() {
+            let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+            let stats = sort(&mut v, &cmp);
+            assert_eq!(v, p);
+            assert!(stats.comparisons > 0);
+        }
+    }
+
+    #[test]
+    fn instrumented_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        heap(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn counts_test() {
+        let sorted = [1, 2, 3, 4, 5];
+        let reversed = [5, 4, 3, 2, 1];
+        let cmp = |a: &i32, b: &i32| a < b;
+
+        // Sorted input: n - 1 comparisons and nothing else.
+        assert_eq!(insection(&mut sorted.clone(), &cmp), SortStats {
+            comparisons: 4,
+            swaps:       0,
+            moves:       0,
+        });
+        assert_eq!(bubble(&mut sorted.clone(), &cmp).swaps, 0);
+
+        // Reversed input: one swap per inversion.
+        assert_eq!(insection(&mut reversed.clone(), &cmp), SortStats {
+            comparisons: 10,
+            swaps:       10,
+            moves:       0,
+        });
+        assert_eq!(bubble(&mut reversed.clone(), &cmp).swaps, 10);
+        assert_eq!(selection(&mut reversed.clone(), &cmp), SortStats {
+            comparisons: 10,
+            swaps:       2,
+            moves:       0,
+        });
+
+        // Merges of [5] and [4], [2] and [1], [3] and [1, 2] and then [4, 5] and [1, 2, 3]:
+        // every comparison takes from the right half, which moves it once.
+        let stats = merge(&mut reversed.clone(), &cmp);
+        assert_eq!(stats, SortStats { comparisons: 7, swaps: 0, moves: 17 });
+    }
+}
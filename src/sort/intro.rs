@@ -0,0 +1,143 @@
+//! Introsort implementation.
+
+use std::cmp::Ordering;
+
+use super::{heap_sort, insection_sort, partition};
+
+/// Partitions with this size or smaller are sorted with insertion sort.
+const INSERTION_THRESHOLD: usize = 16;
+
+/// **Intro Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It starts as a quick sort, switches to heap sort when the recursion depth exceeds
+/// `2·log(n)` and uses insertion sort for small partitions. So it have the average
+/// performance of quick sort with a guaranteed `O(nlog(n))` worst case.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(log(n))        |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::intro(&mut v, &|v, b| v < b);
+/// ```
+pub fn intro<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    intro_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Intro Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`intro`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`intro`]: ./fn.intro.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::intro_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn intro_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    intro_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Intro Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`intro`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`intro`]: ./fn.intro.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::intro_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn intro_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    intro_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+pub(super) fn intro_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    // 2·log(n), computed from the number of bits needed to represent the length.
+    let depth_limit = 2 * (usize::BITS - v.len().leading_zeros()) as usize;
+    intro_sort_rec(v, depth_limit, is_less);
+}
+
+fn intro_sort_rec<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], depth: usize, is_less: &mut F) {
+    if v.len() <= INSERTION_THRESHOLD {
+        insection_sort(v, is_less);
+        return;
+    }
+    if depth == 0 {
+        heap_sort(v, is_less);
+        return;
+    }
+
+    let mid = partition(v, is_less);
+    let (left, right) = v.split_at_mut(mid);
+    intro_sort_rec(left, depth - 1, is_less);
+    intro_sort_rec(&mut right[1..], depth - 1, is_less);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn intro_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        intro(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn intro_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        intro(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn intro_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 16, 17, 100, 1000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            intro_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn intro_heap_fallback_test() {
+        // With no depth left it must fall back to heap sort right away.
+        let mut v: Vec<i32> = (0..100).rev().collect();
+        intro_sort_rec(&mut v, 0, &mut |a, b| a < b);
+        assert_eq!(v, (0..100).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,274 @@
+//! K-way merge implementation.
+
+use std::cmp::Ordering;
+
+/// **K-way Merge:** Merges the `slices`, each one sorted according to the way you define
+/// the `cmp` parameter, into a sorted vector.
+///
+/// It keeps a min-heap with a cursor to each slice, ordered by their current elements,
+/// and repeatedly takes the element of the cursor at the root. So it uses the existing
+/// order of the slices, instead of sorting everything again. On ties the element of the
+/// first slice goes first, so it is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(k))      |                  |
+/// | Avrg:     | Θ(nlog(k))      |                  |
+/// | Worst:    | O(nlog(k))      | O(n + k)         |
+///
+/// **Obs.:** `n` is the total number of elements and `k` the number of slices.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let shards: [&[i32]; 3] = [&[1, 4, 7], &[2, 5, 8], &[0, 3, 6, 9]];
+/// // Crescent sorting
+/// let v = sort::kway_merge(&shards, &|a, b| a < b);
+/// assert_eq!(v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn kway_merge<T: PartialOrd + Clone, C: Fn(&T, &T) -> bool>(
+    slices: &[&[T]], cmp: &C,
+) -> Vec<T> {
+    kway_merge_iter(slices, |a, b| {
+        if cmp(a, b) {
+            Ordering::Less
+        } else if cmp(b, a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+    .cloned()
+    .collect()
+}
+
+/// **K-way Merge:** Merges the `slices`, each one sorted according to the [`Ordering`]
+/// returned by the `compare` function, into a sorted vector.
+///
+/// Same as [`kway_merge`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`kway_merge`]: ./fn.kway_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let shards: [&[i32]; 2] = [&[9, 7, 3], &[8, 7, 5]];
+/// // Decrescent sorting
+/// let v = sort::kway_merge_by(&shards, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn kway_merge_by<T: Clone, F: FnMut(&T, &T) -> Ordering>(
+    slices: &[&[T]], compare: F,
+) -> Vec<T> {
+    kway_merge_iter(slices, compare).cloned().collect()
+}
+
+/// **K-way Merge:** Merges the `slices`, each one sorted according to the key extracted
+/// by the `key` function, into a sorted vector.
+///
+/// Same as [`kway_merge`], but comparing the keys returned by `key`.
+///
+/// [`kway_merge`]: ./fn.kway_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let shards: [&[i32]; 2] = [&[3, -5, 8], &[-5, 7, -9]];
+/// // Crescent sorting by the absolute value
+/// let v = sort::kway_merge_by_key(&shards, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -5, 7, 8, -9]);
+/// ```
+pub fn kway_merge_by_key<T: Clone, K: Ord, F: FnMut(&T) -> K>(
+    slices: &[&[T]], mut key: F,
+) -> Vec<T> {
+    kway_merge_iter(slices, |a, b| key(a).cmp(&key(b))).cloned().collect()
+}
+
+/// **K-way Merge:** Returns an iterator over the elements of the `slices`, each one
+/// sorted according to the [`Ordering`] returned by the `compare` function, in sorted
+/// order.
+///
+/// Same as [`kway_merge_by`], but lazy and without cloning the elements.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`kway_merge_by`]: ./fn.kway_merge_by.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let shards: [&[i32]; 3] = [&[1, 4, 7], &[2, 5, 8], &[0, 3, 6, 9]];
+/// let first: Vec<&i32> = sort::kway_merge_iter(&shards, |a, b| a.cmp(b)).take(4).collect();
+/// assert_eq!(first, [&0, &1, &2, &3]);
+/// ```
+pub fn kway_merge_iter<'a, T, F: FnMut(&T, &T) -> Ordering>(
+    slices: &[&'a [T]], compare: F,
+) -> KWayMerge<'a, T, F> {
+    KWayMerge::new(slices, compare)
+}
+
+/// Iterator over the elements of several sorted slices, in sorted order.
+///
+/// This `struct` is created by [`kway_merge_iter`].
+///
+/// [`kway_merge_iter`]: ./fn.kway_merge_iter.html
+#[derive(Debug, Clone)]
+pub struct KWayMerge<'a, T, F> {
+    /// The rest of each slice.
+    slices:  Vec<&'a [T]>,
+    /// The non-empty slices, by their first element and then by their index.
+    heap:    RunHeap,
+    compare: F,
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> Ordering> KWayMerge<'a, T, F> {
+    fn new(slices: &[&'a [T]], mut compare: F) -> Self {
+        let runs = (0..slices.len()).filter(|&s| !slices[s].is_empty()).collect();
+        let heap = RunHeap::new(runs, &mut Self::is_less(slices, &mut compare));
+        KWayMerge { slices: slices.to_vec(), heap, compare }
+    }
+
+    /// Returns a function that returns `true` if the slice `a` must come before the slice
+    /// `b`.
+    fn is_less<'s>(
+        slices: &'s [&'a [T]], compare: &'s mut F,
+    ) -> impl FnMut(usize, usize) -> bool + 's {
+        // On ties the slice that came first goes first, so it is stable.
+        move |a, b| compare(&slices[a][0], &slices[b][0]).then(a.cmp(&b)) == Ordering::Less
+    }
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> Ordering> Iterator for KWayMerge<'a, T, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.heap.first()?;
+        let (first, rest) = self.slices[s].split_first()?;
+        self.slices[s] = rest;
+        let is_less = &mut Self::is_less(&self.slices, &mut self.compare);
+        self.heap.update_first(rest.is_empty(), is_less);
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.runs().iter().map(|&s| self.slices[s].len()).sum();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> Ordering> ExactSizeIterator for KWayMerge<'a, T, F> {}
+
+/// Min-heap of the indexes of several sorted runs, ordered by the current element of each
+/// run, used by the k-way merges.
+///
+/// The heap doesn't know the runs, each method that changes it takes an `is_less(a, b)`
+/// function that returns `true` if the current element of the run `a` must come before
+/// the current element of the run `b`.
+#[derive(Debug, Clone)]
+pub(super) struct RunHeap {
+    heap: Vec<usize>,
+}
+
+impl RunHeap {
+    /// Creates a heap with the `runs`, that must have a current element.
+    pub(super) fn new<F: FnMut(usize, usize) -> bool>(runs: Vec<usize>, is_less: &mut F) -> Self {
+        let mut heap = RunHeap { heap: runs };
+        for node in (0..heap.heap.len() / 2).rev() {
+            heap.sift_down(node, is_less);
+        }
+        heap
+    }
+
+    /// Returns the run with the least current element, or `None` if the heap is empty.
+    pub(super) fn first(&self) -> Option<usize> { self.heap.first().copied() }
+
+    /// Returns the runs in the heap, in no particular order.
+    pub(super) fn runs(&self) -> &[usize] { &self.heap }
+
+    /// Removes every run of the heap.
+    pub(super) fn clear(&mut self) { self.heap.clear(); }
+
+    /// Restores the heap after the current element of the [`first`] run changed, removing
+    /// the run if it is `exhausted`.
+    ///
+    /// [`first`]: #method.first
+    ///
+    /// # Panics
+    /// It panics if the heap is empty.
+    pub(super) fn update_first<F: FnMut(usize, usize) -> bool>(
+        &mut self, exhausted: bool, is_less: &mut F,
+    ) {
+        if exhausted {
+            self.heap.swap_remove(0);
+        }
+        self.sift_down(0, is_less);
+    }
+
+    fn sift_down<F: FnMut(usize, usize) -> bool>(&mut self, mut node: usize, is_less: &mut F) {
+        loop {
+            let mut min = node;
+            for child in [2 * node + 1, 2 * node + 2].iter().copied() {
+                if child < self.heap.len() && is_less(self.heap[child], self.heap[min]) {
+                    min = child;
+                }
+            }
+            if min == node {
+                break;
+            }
+            self.heap.swap(node, min);
+            node = min;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn kway_merge_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let slices: [&[i32]; 4] = [&[7, 9, 99], &[], &[3, 5, 12, 23], &[7, 8, 15, 30]];
+
+        assert_eq!(kway_merge(&slices, &|a, b| a < b), p);
+        assert_eq!(kway_merge::<i32, _>(&[], &|a, b| a < b), []);
+    }
+
+    #[test]
+    fn kway_merge_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let slices: [&[f64]; 3] =
+            [&[3.1, 7.3, 99.0], &[5.2, 7.3, 8.4, 9.5], &[12.6, 15.7, 23.8, 30.9]];
+
+        assert_eq!(kway_merge(&slices, &|a, b| a < b), p);
+    }
+
+    #[test]
+    fn kway_merge_random_test() {
+        let mut rng = thread_rng();
+        for &k in &[1, 2, 7, 50] {
+            let mut shards: Vec<Vec<(u32, usize)>> = (0..k)
+                .map(|s| {
+                    let len = rng.gen_range(0..100);
+                    (0..len).map(|_| (rng.gen_range(0..20), s)).collect()
+                })
+                .collect();
+            for shard in &mut shards {
+                shard.sort();
+            }
+            let mut p: Vec<(u32, usize)> = shards.concat();
+            // Stable by the key: ties keep the order of the shards.
+            p.sort_by_key(|x| x.0);
+
+            let slices: Vec<&[(u32, usize)]> = shards.iter().map(|s| &s[..]).collect();
+            let merge = kway_merge_iter(&slices, |a, b| a.0.cmp(&b.0));
+            assert_eq!(merge.len(), p.len());
+            assert_eq!(merge.cloned().collect::<Vec<_>>(), p);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Sorting algorithms for linked lists.
+//!
+//! # Example
+//! ```rust
+//! use std::collections::LinkedList;
+//!
+//! use algos::sort::list;
+//!
+//! let mut l: LinkedList<_> = vec![9, 3, 5, 7, 8, 7].into_iter().collect();
+//! list::merge(&mut l, &|a, b| a < b);
+//! assert!(l.into_iter().eq(vec![3, 5, 7, 7, 8, 9]));
+//! ```
+
+use std::{cmp::Ordering, collections::LinkedList};
+
+/// **Merge Sort:** Sort `list` according to the way you define the `cmp` parameter.
+///
+/// It splits the list in halves, sorts them and merges them, relinking the nodes instead
+/// of moving the elements. So it doesn't allocate and doesn't need an auxiliar buffer,
+/// unlike the merge sort of slices.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(log(n))        |
+///
+/// **Obs.:** The space complexity is only the recursion, no node is allocated.
+///
+/// # Example
+/// ```rust
+/// use std::collections::LinkedList;
+///
+/// use algos::sort::list;
+///
+/// let mut l: LinkedList<_> = vec![9, 3, 5, 7, 8, 7].into_iter().collect();
+/// // Crescent sorting
+/// list::merge(&mut l, &|v, b| v < b);
+/// assert!(l.iter().eq(&[3, 5, 7, 7, 8, 9]));
+/// ```
+pub fn merge<T: PartialOrd, C: Fn(&T, &T) -> bool>(list: &mut LinkedList<T>, cmp: &C) {
+    merge_sort(list, &mut |a, b| cmp(a, b));
+}
+
+/// **Merge Sort:** Sort `list` according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`merge`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use std::collections::LinkedList;
+///
+/// use algos::sort::list;
+///
+/// let mut l: LinkedList<_> = vec![9, 3, 5, 7, 8, 7].into_iter().collect();
+/// // Decrescent sorting
+/// list::merge_by(&mut l, |a, b| b.cmp(a));
+/// assert!(l.iter().eq(&[9, 8, 7, 7, 5, 3]));
+/// ```
+pub fn merge_by<T, F: FnMut(&T, &T) -> Ordering>(list: &mut LinkedList<T>, mut compare: F) {
+    merge_sort(list, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Merge Sort:** Sort `list` according to the key extracted by the `key` function.
+///
+/// Same as [`merge`], but comparing the keys returned by `key`.
+///
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use std::collections::LinkedList;
+///
+/// use algos::sort::list;
+///
+/// let mut l: LinkedList<i32> = vec![-9, 3, -5, 7, 8, -7].into_iter().collect();
+/// // Crescent sorting by the absolute value
+/// list::merge_by_key(&mut l, |a| a.abs());
+/// assert!(l.iter().eq(&[3, -5, 7, -7, 8, -9]));
+/// ```
+pub fn merge_by_key<T, K: Ord, F: FnMut(&T) -> K>(list: &mut LinkedList<T>, mut key: F) {
+    merge_sort(list, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn merge_sort<T, F: FnMut(&T, &T) -> bool>(list: &mut LinkedList<T>, is_less: &mut F) {
+    if list.len() <= 1 {
+        return;
+    }
+    let mut right = list.split_off(list.len() / 2);
+    merge_sort(list, is_less);
+    merge_sort(&mut right, is_less);
+
+    let left = std::mem::take(list);
+    *list = combine(left, right, is_less);
+}
+
+/// Combines the sorted lists `left` and `right` into one.
+///
+/// It alternates between moving the run at the start of `left` that goes before the
+/// first element of `right` and the opposite, so the nodes are relinked a run at a time.
+fn combine<T, F: FnMut(&T, &T) -> bool>(
+    mut left: LinkedList<T>, mut right: LinkedList<T>, is_less: &mut F,
+) -> LinkedList<T> {
+    let mut merged = LinkedList::new();
+    while let Some(first) = right.front() {
+        // Equal elements are taken from the left, so the sort stays stable.
+        let run = left.iter().take_while(|x| !is_less(first, x)).count();
+        let mut rest = left.split_off(run);
+        merged.append(&mut left);
+        left = rest;
+
+        let first = match left.front() {
+            Some(first) => first,
+            None => break,
+        };
+        let run = right.iter().take_while(|x| is_less(x, first)).count();
+        rest = right.split_off(run);
+        merged.append(&mut right);
+        right = rest;
+    }
+    merged.append(&mut left);
+    merged.append(&mut right);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn list_merge_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut l: LinkedList<_> = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12].iter().copied().collect();
+
+        merge(&mut l, &|a, b| a < b);
+        assert!(l.iter().eq(&p));
+    }
+
+    #[test]
+    fn list_merge_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+        let mut l: LinkedList<f64> = v.iter().copied().collect();
+
+        merge(&mut l, &|a, b| a < b);
+        assert!(l.iter().eq(&p));
+    }
+
+    #[test]
+    fn list_merge_stable_test() {
+        let mut rng = thread_rng();
+        for len in (0..40).chain(vec![1000]) {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut l: LinkedList<_> = v.into_iter().collect();
+            merge_by_key(&mut l, |x| x.0);
+            assert!(l.iter().eq(&p));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! In-place merge implementation.
+
+use std::cmp::Ordering;
+
+/// **In-place Merge:** Merges the sorted ranges `v[..mid]` and `v[mid..]`, sorted
+/// according to the way you define the `cmp` parameter, without auxiliar memory.
+///
+/// It uses the SymMerge algorithm, by Pok-Son Kim and Arne Kutzner: it finds with a
+/// binary search the symmetric split of both ranges around the middle of `v`, such that
+/// rotating the inner parts leaves every element of the first half not greater than the
+/// elements of the second half, and then merges each half recursively. It is useful to
+/// insert a sorted batch in a sorted vector, by pushing it at the end and merging.
+///
+/// This merge is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(log(n))        |
+///
+/// **Obs.:** It does `O(mlog(n/m + 1))` comparisons, where `m` is the size of the smaller
+/// range, and the space complexity is only the recursion.
+///
+/// # Panics
+/// Panics if `mid > v.len()`.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = vec![1, 4, 6, 9];
+/// // Inserts a sorted batch
+/// let mid = v.len();
+/// v.extend_from_slice(&[2, 6, 7]);
+/// sort::merge_in_place(&mut v, mid, &|a, b| a < b);
+/// assert_eq!(v, [1, 2, 4, 6, 6, 7, 9]);
+/// ```
+pub fn merge_in_place<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], mid: usize, cmp: &C) {
+    merge_in_place_impl(v, mid, &mut |a, b| cmp(a, b));
+}
+
+/// **In-place Merge:** Merges the sorted ranges `v[..mid]` and `v[mid..]`, sorted
+/// according to the [`Ordering`] returned by the `compare` function, without auxiliar
+/// memory.
+///
+/// Same as [`merge_in_place`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`merge_in_place`]: ./fn.merge_in_place.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 7, 3, 8, 7, 5];
+/// // Decrescent sorting
+/// sort::merge_in_place_by(&mut v, 3, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn merge_in_place_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mid: usize, mut compare: F) {
+    merge_in_place_impl(v, mid, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **In-place Merge:** Merges the sorted ranges `v[..mid]` and `v[mid..]`, sorted
+/// according to the key extracted by the `key` function, without auxiliar memory.
+///
+/// Same as [`merge_in_place`], but comparing the keys returned by `key`.
+///
+/// [`merge_in_place`]: ./fn.merge_in_place.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [3, -7, 8, -5, 7, -9];
+/// // Crescent sorting by the absolute value
+/// sort::merge_in_place_by_key(&mut v, 3, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -7, 7, 8, -9]);
+/// ```
+pub fn merge_in_place_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mid: usize, mut key: F) {
+    merge_in_place_impl(v, mid, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn merge_in_place_impl<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], mid: usize, is_less: &mut F) {
+    assert!(mid <= v.len(), "mid is out of bounds");
+    let len = v.len();
+    if 0 < mid && mid < len {
+        sym_merge(v, 0, mid, len, is_less);
+    }
+}
+
+/// Merges `v[start..mid]` and `v[mid..end]`, that must be both non-empty.
+fn sym_merge<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], start: usize, mid: usize, end: usize, is_less: &mut F,
+) {
+    // A single element is inserted with a binary search.
+    if mid - start == 1 {
+        let (mut lo, mut hi) = (mid, end);
+        while lo < hi {
+            let h = lo + (hi - lo) / 2;
+            if is_less(&v[h], &v[start]) {
+                lo = h + 1;
+            } else {
+                hi = h;
+            }
+        }
+        v[start..lo].rotate_left(1);
+        return;
+    }
+    if end - mid == 1 {
+        let (mut lo, mut hi) = (start, mid);
+        while lo < hi {
+            let h = lo + (hi - lo) / 2;
+            if is_less(&v[mid], &v[h]) {
+                hi = h;
+            } else {
+                lo = h + 1;
+            }
+        }
+        v[lo..=mid].rotate_right(1);
+        return;
+    }
+
+    // Finds the split `v[first..mid]` and `v[mid..last]`, symmetric around `half`, such
+    // that after swapping them everything before `half` goes before everything after it.
+    let half = start + (end - start) / 2;
+    let n = half + mid;
+    let (mut lo, mut hi) = if mid > half { (n - end, half) } else { (start, mid) };
+    let p = n - 1;
+    while lo < hi {
+        let c = lo + (hi - lo) / 2;
+        if !is_less(&v[p - c], &v[c]) {
+            lo = c + 1;
+        } else {
+            hi = c;
+        }
+    }
+    let (first, last) = (lo, n - lo);
+
+    if first < mid && mid < last {
+        v[first..last].rotate_left(mid - first);
+    }
+    if start < first && first < half {
+        sym_merge(v, start, first, half, is_less);
+    }
+    if half < last && last < end {
+        sym_merge(v, half, last, end, is_less);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn merge_in_place_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [5, 7, 9, 23, 99, 3, 7, 8, 12, 15, 30];
+
+        merge_in_place(&mut v, 5, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_in_place_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [3.1, 7.3, 9.5, 15.7, 23.8, 99.0, 5.2, 7.3, 8.4, 12.6, 30.9];
+
+        merge_in_place(&mut v, 6, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_in_place_stable_test() {
+        let mut rng = thread_rng();
+        for len in 0..100 {
+            let mid = rng.gen_range(0..=len);
+            let mut v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            v[..mid].sort_by_key(|x| x.0);
+            v[mid..].sort_by_key(|x| x.0);
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            merge_in_place_by_key(&mut v, mid, |x| x.0);
+            assert_eq!(v, p);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn merge_in_place_out_of_bounds_test() { merge_in_place(&mut [1, 2], 3, &|a, b| a < b); }
+}
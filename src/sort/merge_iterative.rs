@@ -0,0 +1,136 @@
+//! Bottom-up merge sort implementation.
+
+use std::{cmp::Ordering, mem};
+
+use super::combine;
+
+/// **Iterative Merge Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Merge Sort.
+///
+/// Instead of splitting the slice recursively, it merges the runs of width 1 into runs of
+/// width 2, those into runs of width 4 and so on, until a single run is left. So it
+/// doesn't use the call stack, which makes it safe for huge slices, and every pass is a
+/// sequence of independent merges.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::merge_iterative(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn merge_iterative<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    merge_iterative_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Iterative Merge Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`merge_iterative`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`merge_iterative`]: ./fn.merge_iterative.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::merge_iterative_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn merge_iterative_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    merge_iterative_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Iterative Merge Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`merge_iterative`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`merge_iterative`]: ./fn.merge_iterative.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::merge_iterative_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn merge_iterative_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    merge_iterative_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn merge_iterative_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    // Zero-sized types have nothing to be sorted.
+    if len <= 1 || mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let mut buf: Vec<T> = Vec::new();
+    let mut width = 1;
+    while width < len {
+        // The left runs of this pass have `width` elements.
+        buf.reserve(width);
+        let mut start = 0;
+        while start + width < len {
+            let end = (start + 2 * width).min(len);
+            combine(&mut v[start..end], width, &mut buf, is_less);
+            start = end;
+        }
+        width *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn merge_iterative_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        merge_iterative(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_iterative_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        merge_iterative(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_iterative_stable_test() {
+        let mut rng = thread_rng();
+        for len in (0..70).chain(vec![1000, 1025]) {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut t = v.clone();
+            merge_iterative_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Natural merge sort implementation.
+
+use std::{cmp::Ordering, mem};
+
+use super::{combine, tim::count_run};
+
+/// **Natural Merge Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Merge Sort.
+///
+/// It first splits the slice in its natural runs, the ascending ones and the strictly
+/// descending ones, that are reversed, and then merges neighbour runs until a single run
+/// is left. So it is much faster than merge sort for slices that are already mostly
+/// sorted, like sorted data with some new elements appended, and sorts a sorted or
+/// reversed slice in linear time.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** More precisely, it is `O(nlog(r))` where `r` is the number of runs.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::merge_natural(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn merge_natural<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    merge_natural_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Natural Merge Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`merge_natural`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`merge_natural`]: ./fn.merge_natural.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::merge_natural_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn merge_natural_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    merge_natural_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Natural Merge Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`merge_natural`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`merge_natural`]: ./fn.merge_natural.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::merge_natural_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn merge_natural_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    merge_natural_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn merge_natural_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    // Zero-sized types have nothing to be sorted.
+    if len <= 1 || mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    // The boundaries of the runs: run `i` is `v[bounds[i]..bounds[i + 1]]`.
+    let mut bounds = vec![0];
+    while bounds[bounds.len() - 1] < len {
+        let start = bounds[bounds.len() - 1];
+        bounds.push(start + count_run(&mut v[start..], is_less));
+    }
+
+    let mut buf: Vec<T> = Vec::new();
+    while bounds.len() > 2 {
+        // Merges the runs in pairs, keeping the last one if it has no pair.
+        let mut merged = Vec::with_capacity(bounds.len() / 2 + 1);
+        merged.push(0);
+        for pair in bounds.windows(3).step_by(2) {
+            let (start, mid, end) = (pair[0], pair[1], pair[2]);
+            buf.reserve(mid - start);
+            combine(&mut v[start..end], mid - start, &mut buf, is_less);
+            merged.push(end);
+        }
+        if bounds.len() % 2 == 0 {
+            merged.push(len);
+        }
+        bounds = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn merge_natural_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        merge_natural(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_natural_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        merge_natural(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn merge_natural_stable_test() {
+        let mut rng = thread_rng();
+        for len in (0..70).chain(vec![1000, 1025]) {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut t = v.clone();
+            merge_natural_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn merge_natural_runs_test() {
+        // A sorted slice with some elements appended, and a reversed one.
+        let mut v: Vec<u32> = (0..1000).chain(vec![500, 2, 999, 0]).collect();
+        let mut p = v.clone();
+        p.sort();
+        merge_natural(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+
+        let mut v: Vec<u32> = (0..1000).rev().collect();
+        merge_natural(&mut v, &|a, b| a < b);
+        assert_eq!(v, (0..1000).collect::<Vec<_>>());
+    }
+}
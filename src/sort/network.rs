@@ -0,0 +1,352 @@
+//! Sorting networks for small fixed-size arrays.
+//!
+//! A sorting network is a fixed sequence of compare-exchange operations, that sorts any
+//! input of a given size. The comparisons done don't depend on the data, so there are no
+//! data dependent loops or branches besides the exchanges themselves, that the compiler
+//! can turn into conditional moves for simple types. That makes them very fast for small
+//! arrays, like in a hot inner loop or as the base case of other sorts.
+//!
+//! Up to 16 elements, the networks with the fewest comparators known are used, which are
+//! proven optimal up to 10 elements. Beyond that, the network is built with Batcher's
+//! odd-even merge sort, that does `O(nlog²(n))` comparisons.
+//!
+//! # Example
+//! ```rust
+//! use algos::sort::network;
+//!
+//! let mut v = [9, 3, 5, 7, 8, 7];
+//! network::sort_array(&mut v);
+//! assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+//! ```
+
+use std::cmp::Ordering;
+
+/// Size of the largest array with a hand-written network.
+pub const MAX_OPTIMAL: usize = 16;
+
+/// **Sorting Network:** Sort `v` array in crescent order.
+///
+/// It uses the network for `N` elements described in the [module documentation], so
+/// the comparisons done depend only on `N`.
+///
+/// It is not stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog²(n))     |                  |
+/// | Avrg:     | Θ(nlog²(n))     |                  |
+/// | Worst:    | O(nlog²(n))     | O(1)             |
+///
+/// [module documentation]: ./index.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::network;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+/// network::sort_array(&mut v);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99]);
+/// ```
+pub fn sort_array<T: Ord, const N: usize>(v: &mut [T; N]) { sort(v, &mut |a, b| a.lt(b)) }
+
+/// **Sorting Network:** Sort `v` array according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`sort_array`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`sort_array`]: ./fn.sort_array.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::network;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// network::sort_array_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn sort_array_by<T, F: FnMut(&T, &T) -> Ordering, const N: usize>(
+    v: &mut [T; N], mut compare: F,
+) {
+    sort(v, &mut |a, b| compare(a, b) == Ordering::Less)
+}
+
+/// **Sorting Network:** Sort `v` array according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`sort_array`], but comparing the keys returned by `key`.
+///
+/// [`sort_array`]: ./fn.sort_array.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::network;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// network::sort_array_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn sort_array_by_key<T, K: Ord, F: FnMut(&T) -> K, const N: usize>(v: &mut [T; N], mut key: F) {
+    sort(v, &mut |a, b| key(a).lt(&key(b)))
+}
+
+/// Sorts `v` with the network for its length.
+pub(super) fn sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    if v.len() <= MAX_OPTIMAL {
+        for &(i, j) in NETWORKS[v.len()] {
+            compare_exchange(v, i as usize, j as usize, is_less);
+        }
+    } else {
+        batcher(v, is_less);
+    }
+}
+
+/// Puts `v[i]` and `v[j]` in order, where `i < j`.
+#[inline(always)]
+fn compare_exchange<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], i: usize, j: usize, is_less: &mut F) {
+    if is_less(&v[j], &v[i]) {
+        v.swap(i, j);
+    }
+}
+
+/// Batcher's odd-even merge sort network, for any length.
+///
+/// It is the network for the next power of two, without the comparators with the
+/// missing elements, as if they were greater than every element.
+fn batcher<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    let mut p = 1;
+    while p < len {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < len {
+                for i in 0..k.min(len - j - k) {
+                    // Only compare elements of the same block of size `2p` being merged.
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        compare_exchange(v, i + j, i + j + k, is_less);
+                    }
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+}
+
+/// The comparators of the networks for each size up to `MAX_OPTIMAL`, from Knuth's "The
+/// Art of Computer Programming, Vol. 3" and Bert Dobbelaere's list of smallest known
+/// networks.
+#[rustfmt::skip]
+pub(super) const NETWORKS: [&[(u8, u8)]; 17] = [
+    &[],
+    &[],
+    // 2 inputs, 1 comparators in 1 layers.
+    &[
+        (0, 1),
+    ],
+    // 3 inputs, 3 comparators in 3 layers.
+    &[
+        (0, 2),
+        (0, 1),
+        (1, 2),
+    ],
+    // 4 inputs, 5 comparators in 3 layers.
+    &[
+        (0, 2), (1, 3),
+        (0, 1), (2, 3),
+        (1, 2),
+    ],
+    // 5 inputs, 9 comparators in 5 layers.
+    &[
+        (0, 3), (1, 4),
+        (0, 2), (1, 3),
+        (0, 1), (2, 4),
+        (1, 2), (3, 4),
+        (2, 3),
+    ],
+    // 6 inputs, 12 comparators in 5 layers.
+    &[
+        (0, 5), (1, 3), (2, 4),
+        (1, 2), (3, 4),
+        (0, 3), (2, 5),
+        (0, 1), (2, 3), (4, 5),
+        (1, 2), (3, 4),
+    ],
+    // 7 inputs, 16 comparators in 6 layers.
+    &[
+        (0, 6), (2, 3), (4, 5),
+        (0, 2), (1, 4), (3, 6),
+        (0, 1), (2, 5), (3, 4),
+        (1, 2), (4, 6),
+        (2, 3), (4, 5),
+        (1, 2), (3, 4), (5, 6),
+    ],
+    // 8 inputs, 19 comparators in 6 layers.
+    &[
+        (0, 2), (1, 3), (4, 6), (5, 7),
+        (0, 4), (1, 5), (2, 6), (3, 7),
+        (0, 1), (2, 3), (4, 5), (6, 7),
+        (2, 4), (3, 5),
+        (1, 4), (3, 6),
+        (1, 2), (3, 4), (5, 6),
+    ],
+    // 9 inputs, 25 comparators in 7 layers.
+    &[
+        (0, 3), (1, 7), (2, 5), (4, 8),
+        (0, 7), (2, 4), (3, 8), (5, 6),
+        (0, 2), (1, 3), (4, 5), (7, 8),
+        (1, 4), (3, 6), (5, 7),
+        (0, 1), (2, 4), (3, 5), (6, 8),
+        (2, 3), (4, 5), (6, 7),
+        (1, 2), (3, 4), (5, 6),
+    ],
+    // 10 inputs, 29 comparators in 8 layers.
+    &[
+        (0, 8), (1, 9), (2, 7), (3, 5), (4, 6),
+        (0, 2), (1, 4), (5, 8), (7, 9),
+        (0, 3), (2, 4), (5, 7), (6, 9),
+        (0, 1), (3, 6), (8, 9),
+        (1, 5), (2, 3), (4, 8), (6, 7),
+        (1, 2), (3, 5), (4, 6), (7, 8),
+        (2, 3), (4, 5), (6, 7),
+        (3, 4), (5, 6),
+    ],
+    // 11 inputs, 35 comparators in 8 layers.
+    &[
+        (0, 9), (1, 6), (2, 4), (3, 7), (5, 8),
+        (0, 1), (3, 5), (4, 10), (6, 9), (7, 8),
+        (1, 3), (2, 5), (4, 7), (8, 10),
+        (0, 4), (1, 2), (3, 7), (5, 9), (6, 8),
+        (0, 1), (2, 6), (4, 5), (7, 8), (9, 10),
+        (2, 4), (3, 6), (5, 7), (8, 9),
+        (1, 2), (3, 4), (5, 6), (7, 8),
+        (2, 3), (4, 5), (6, 7),
+    ],
+    // 12 inputs, 39 comparators in 9 layers.
+    &[
+        (0, 8), (1, 7), (2, 6), (3, 11), (4, 10), (5, 9),
+        (0, 1), (2, 5), (3, 4), (6, 9), (7, 8), (10, 11),
+        (0, 2), (1, 6), (5, 10), (9, 11),
+        (0, 3), (1, 2), (4, 6), (5, 7), (8, 11), (9, 10),
+        (1, 4), (3, 5), (6, 8), (7, 10),
+        (1, 3), (2, 5), (6, 9), (8, 10),
+        (2, 3), (4, 5), (6, 7), (8, 9),
+        (4, 6), (5, 7),
+        (3, 4), (5, 6), (7, 8),
+    ],
+    // 13 inputs, 45 comparators in 10 layers.
+    &[
+        (0, 12), (1, 10), (2, 9), (3, 7), (5, 11), (6, 8),
+        (1, 6), (2, 3), (4, 11), (7, 9), (8, 10),
+        (0, 4), (1, 2), (3, 6), (7, 8), (9, 10), (11, 12),
+        (4, 6), (5, 9), (8, 11), (10, 12),
+        (0, 5), (3, 8), (4, 7), (6, 11), (9, 10),
+        (0, 1), (2, 5), (6, 9), (7, 8), (10, 11),
+        (1, 3), (2, 4), (5, 6), (9, 10),
+        (1, 2), (3, 4), (5, 7), (6, 8),
+        (2, 3), (4, 5), (6, 7), (8, 9),
+        (3, 4), (5, 6),
+    ],
+    // 14 inputs, 51 comparators in 10 layers.
+    &[
+        (0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13),
+        (0, 2), (1, 3), (4, 8), (5, 9), (10, 12), (11, 13),
+        (0, 4), (1, 2), (3, 7), (5, 8), (6, 10), (9, 13), (11, 12),
+        (0, 6), (1, 5), (3, 9), (4, 10), (7, 13), (8, 12),
+        (2, 10), (3, 11), (4, 6), (7, 9),
+        (1, 3), (2, 8), (5, 11), (6, 7), (10, 12),
+        (1, 4), (2, 6), (3, 5), (7, 11), (8, 10), (9, 12),
+        (2, 4), (3, 6), (5, 8), (7, 10), (9, 11),
+        (3, 4), (5, 6), (7, 8), (9, 10),
+        (6, 7),
+    ],
+    // 15 inputs, 56 comparators in 10 layers.
+    &[
+        (0, 13), (1, 12), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10),
+        (0, 5), (1, 7), (2, 9), (3, 4), (6, 13), (8, 14), (11, 12),
+        (0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13),
+        (0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14),
+        (1, 2), (3, 12), (4, 6), (5, 7), (8, 10), (9, 11), (13, 14),
+        (1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14),
+        (2, 4), (3, 6), (9, 12), (11, 13),
+        (3, 5), (6, 8), (7, 9), (10, 12),
+        (3, 4), (5, 6), (7, 8), (9, 10), (11, 12),
+        (6, 7), (8, 9),
+    ],
+    // 16 inputs, 60 comparators in 10 layers.
+    &[
+        (0, 13), (1, 12), (2, 15), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10),
+        (0, 5), (1, 7), (2, 9), (3, 4), (6, 13), (8, 14), (10, 15), (11, 12),
+        (0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13), (14, 15),
+        (0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14), (13, 15),
+        (1, 2), (3, 12), (4, 6), (5, 7), (8, 10), (9, 11), (13, 14),
+        (1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14),
+        (2, 4), (3, 6), (9, 12), (11, 13),
+        (3, 5), (6, 8), (7, 9), (10, 12),
+        (3, 4), (5, 6), (7, 8), (9, 10), (11, 12),
+        (6, 7), (8, 9),
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Checks that the network for `len` elements sorts every sequence of 0s and 1s,
+    /// which by the 0-1 principle means that it sorts every input.
+    fn sorts_zero_one(len: usize) -> bool {
+        (0..1u32 << len).all(|bits| {
+            let mut v: Vec<u32> = (0..len).map(|i| (bits >> i) & 1).collect();
+            sort(&mut v, &mut |a, b| a < b);
+            v.windows(2).all(|w| w[0] <= w[1])
+        })
+    }
+
+    #[test]
+    fn network_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        sort_array(&mut v);
+        assert_eq!(v, p);
+
+        let mut empty: [i32; 0] = [];
+        sort_array(&mut empty);
+        let mut one = [1];
+        sort_array(&mut one);
+        assert_eq!(one, [1]);
+    }
+
+    #[test]
+    fn network_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        sort_array_by(&mut v, |a: &f64, b| a.total_cmp(b));
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn zero_one_test() {
+        for len in 0..=18 {
+            assert!(sorts_zero_one(len), "{}", len);
+        }
+    }
+
+    #[test]
+    fn network_random_test() {
+        let mut rng = thread_rng();
+        let mut v = [0u32; 100];
+        for _ in 0..100 {
+            rng.fill(&mut v[..]);
+            let mut p = v;
+            p.sort();
+            sort_array(&mut v);
+            assert_eq!(v, p);
+        }
+    }
+}
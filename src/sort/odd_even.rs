@@ -0,0 +1,162 @@
+//! Odd-even transposition sort implementation.
+
+use std::cmp::Ordering;
+
+/// **Odd-Even Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+/// It's a variation of Bubble Sort.
+///
+/// It alternates between an odd phase, that compares and swaps the pairs `(1, 2)`,
+/// `(3, 4)`, ..., and an even phase, that does the same with the pairs `(0, 1)`,
+/// `(2, 3)`, ..., until neither phase swaps anything. Since the pairs of a phase are
+/// disjoint, each phase could be done in parallel.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::odd_even(&mut v, &|v, b| v < b);
+/// ```
+pub fn odd_even<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    odd_even_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Odd-Even Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`odd_even`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`odd_even`]: ./fn.odd_even.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::odd_even_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn odd_even_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    odd_even_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Odd-Even Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`odd_even`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`odd_even`]: ./fn.odd_even.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::odd_even_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn odd_even_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    odd_even_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn odd_even_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let mut sorted = false;
+    while !sorted {
+        // Both phases must run, one phase without swaps doesn't mean the slice is sorted.
+        let odd = odd_phase(v, is_less);
+        let even = even_phase(v, is_less);
+        sorted = !odd && !even;
+    }
+}
+
+/// Compares and swaps the pairs `(1, 2)`, `(3, 4)`, ... of `v`, returning if any pair was
+/// swapped.
+pub(super) fn odd_phase<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> bool {
+    match v.get_mut(1..) {
+        Some(v) => compare_pairs(v, is_less),
+        None => false,
+    }
+}
+
+/// Compares and swaps the pairs `(0, 1)`, `(2, 3)`, ... of `v`, returning if any pair was
+/// swapped.
+pub(super) fn even_phase<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> bool {
+    compare_pairs(v, is_less)
+}
+
+/// Compares and swaps each of the disjoint pairs `(0, 1)`, `(2, 3)`, ... of `v`,
+/// returning if any pair was swapped.
+fn compare_pairs<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> bool {
+    let mut swapped = false;
+    for pair in v.chunks_exact_mut(2) {
+        if is_less(&pair[1], &pair[0]) {
+            pair.swap(0, 1);
+            swapped = true;
+        }
+    }
+    swapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn odd_even_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        odd_even(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn odd_even_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        odd_even(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn phases_test() {
+        let mut v = [4, 3, 2, 1, 0];
+        assert!(even_phase(&mut v, &mut |a, b| a < b));
+        assert_eq!(v, [3, 4, 1, 2, 0]);
+        assert!(odd_phase(&mut v, &mut |a, b| a < b));
+        assert_eq!(v, [3, 1, 4, 0, 2]);
+
+        let mut v: [i32; 0] = [];
+        assert!(!odd_phase(&mut v, &mut |a, b| a < b));
+        assert!(!even_phase(&mut v, &mut |a, b| a < b));
+    }
+
+    #[test]
+    fn odd_even_stable_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 3, 100, 301] {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut t = v;
+            odd_even_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Pancake sort implementation.
+
+use std::cmp::Ordering;
+
+/// **Pancake Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// The only operation allowed is a flip, that reverses a prefix of the slice, like
+/// flipping the top of a stack of pancakes with a spatula. For each size, from `n` down
+/// to `2`, it brings the greatest element to the front with one flip and then to the end
+/// of the unsorted prefix with another flip. Use [`pancake_flips`] to get the flips done.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n²)           |                  |
+/// | Avrg:     | Θ(n²)           |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// **Obs.:** It does at most 2n - 3 flips.
+///
+/// [`pancake_flips`]: ./fn.pancake_flips.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::pancake(&mut v, &|v, b| v < b);
+/// ```
+pub fn pancake<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    pancake_sort(v, &mut |a, b| cmp(a, b), None);
+}
+
+/// **Pancake Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`pancake`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`pancake`]: ./fn.pancake.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::pancake_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn pancake_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    pancake_sort(v, &mut |a, b| compare(a, b) == Ordering::Less, None);
+}
+
+/// **Pancake Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`pancake`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`pancake`]: ./fn.pancake.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::pancake_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn pancake_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    pancake_sort(v, &mut |a, b| key(a).lt(&key(b)), None);
+}
+
+/// **Pancake Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function, returning the flips done.
+///
+/// Same as [`pancake_by`], but each flip is recorded as the length of the prefix
+/// reversed, so replaying them with `v[..k].reverse()` on the original slice sorts it.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`pancake_by`]: ./fn.pancake_by.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [3, 1, 2];
+/// let flips = sort::pancake_flips(&mut v, |a, b| a.cmp(b));
+/// assert_eq!(v, [1, 2, 3]);
+/// assert_eq!(flips, vec![3, 2]);
+///
+/// // Replaying the flips
+/// let mut w = [3, 1, 2];
+/// for &k in &flips {
+///     w[..k].reverse();
+/// }
+/// assert_eq!(w, v);
+/// ```
+pub fn pancake_flips<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) -> Vec<usize> {
+    let mut flips = Vec::new();
+    pancake_sort(v, &mut |a, b| compare(a, b) == Ordering::Less, Some(&mut flips));
+    flips
+}
+
+fn pancake_sort<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], is_less: &mut F, mut flips: Option<&mut Vec<usize>>,
+) {
+    let mut flip = |v: &mut [T], k: usize| {
+        v[..k].reverse();
+        if let Some(flips) = flips.as_mut() {
+            flips.push(k);
+        }
+    };
+
+    for size in (2..=v.len()).rev() {
+        let mut max = 0;
+        for i in 1..size {
+            if !is_less(&v[i], &v[max]) {
+                max = i;
+            }
+        }
+
+        // Already at the end of the prefix.
+        if max == size - 1 {
+            continue;
+        }
+        if max != 0 {
+            flip(v, max + 1);
+        }
+        flip(v, size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn pancake_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        pancake(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn pancake_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        pancake(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn pancake_flips_test() {
+        let mut v = [1, 2, 3, 4];
+        assert!(pancake_flips(&mut v, |a, b| a.cmp(b)).is_empty());
+
+        let mut v = [4, 3, 2, 1];
+        assert_eq!(pancake_flips(&mut v, |a, b| a.cmp(b)), vec![4]);
+        assert_eq!(v, [1, 2, 3, 4]);
+
+        let mut rng = thread_rng();
+        for &len in &[0_usize, 1, 2, 10, 100] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..20)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v.clone();
+            let flips = pancake_flips(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+            assert!(flips.len() <= (2 * len).saturating_sub(3));
+
+            let mut w = v;
+            for &k in &flips {
+                w[..k].reverse();
+            }
+            assert_eq!(w, p);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Parallel merge sort implementation.
+
+use std::{cmp::Ordering, mem, mem::MaybeUninit, ptr};
+
+use super::merge_sort;
+
+/// Slices with this size or smaller are sorted with the sequential merge sort.
+const SORT_THRESHOLD: usize = 4096;
+/// Merges with this size or smaller are done sequentially.
+const MERGE_THRESHOLD: usize = 4096;
+
+/// **Parallel Merge Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Merge Sort.
+///
+/// The two halves of the slice are sorted in parallel, in a [`rayon`] thread pool, and
+/// then merged into an auxiliar buffer. Big merges are also done in parallel, splitting
+/// the bigger run in the middle and the smaller run where that middle element would be,
+/// and merging both parts at the same time. Small slices are sorted with the sequential
+/// [`merge`].
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** This is only available with the `parallel` feature.
+///
+/// [`rayon`]: https://docs.rs/rayon
+/// [`merge`]: ./fn.merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::par_merge(&mut v, &|v, b| v < b);
+/// ```
+pub fn par_merge<T, C>(v: &mut [T], cmp: &C)
+where
+    T: PartialOrd + Send,
+    C: Fn(&T, &T) -> bool + Sync,
+{
+    par_merge_sort(v, cmp);
+}
+
+/// **Parallel Merge Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`par_merge`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`par_merge`]: ./fn.par_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::par_merge_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn par_merge_by<T, F>(v: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    par_merge_sort(v, &|a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Parallel Merge Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`par_merge`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`par_merge`]: ./fn.par_merge.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::par_merge_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn par_merge_by_key<T, K, F>(v: &mut [T], key: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    par_merge_sort(v, &|a, b| key(a).lt(&key(b)));
+}
+
+fn par_merge_sort<T: Send, F: Fn(&T, &T) -> bool + Sync>(v: &mut [T], is_less: &F) {
+    // Zero-sized types have nothing to be sorted.
+    if v.len() <= SORT_THRESHOLD || mem::size_of::<T>() == 0 {
+        merge_sort(v, &mut |a, b| is_less(a, b));
+        return;
+    }
+
+    let mut buf: Vec<MaybeUninit<T>> = Vec::with_capacity(v.len());
+    buf.resize_with(v.len(), MaybeUninit::uninit);
+    sort(v, &mut buf, is_less);
+}
+
+/// Sorts `v` using `buf`, with the same size, as auxiliar memory.
+fn sort<T: Send, F: Fn(&T, &T) -> bool + Sync>(
+    v: &mut [T], buf: &mut [MaybeUninit<T>], is_less: &F,
+) {
+    if v.len() <= SORT_THRESHOLD {
+        merge_sort(v, &mut |a, b| is_less(a, b));
+        return;
+    }
+
+    let mid = v.len() / 2;
+    {
+        let (left, right) = v.split_at_mut(mid);
+        let (buf_left, buf_right) = buf.split_at_mut(mid);
+        rayon::join(|| sort(left, buf_left, is_less), || sort(right, buf_right, is_less));
+    }
+
+    let (left, right) = v.split_at_mut(mid);
+    merge(left, right, buf, is_less);
+
+    // SAFETY: `buf` has a bitwise copy of every element of `v`, sorted. If `is_less`
+    // panicked in the merge, `v` is untouched, and `buf` never drops its elements, so
+    // every element is still owned exactly once.
+    unsafe { ptr::copy_nonoverlapping(buf.as_ptr() as *const T, v.as_mut_ptr(), v.len()) }
+}
+
+/// Merges the sorted runs `a` and `b` into `dest`, that have the size of both, copying
+/// the elements.
+///
+/// The runs are only read, they are mutable so they can be sent to other threads without
+/// requiring `T: Sync`.
+fn merge<T: Send, F: Fn(&T, &T) -> bool + Sync>(
+    a: &mut [T], b: &mut [T], dest: &mut [MaybeUninit<T>], is_less: &F,
+) {
+    if a.len() + b.len() <= MERGE_THRESHOLD || a.is_empty() || b.is_empty() {
+        merge_sequential(a, b, dest, is_less);
+        return;
+    }
+
+    // Split the bigger run in the middle and the other one where its middle element goes,
+    // keeping the elements of `a` before the equal elements of `b`.
+    let (a_mid, b_mid) = if a.len() >= b.len() {
+        let a_mid = a.len() / 2;
+        (a_mid, b.partition_point(|x| is_less(x, &a[a_mid])))
+    } else {
+        let b_mid = b.len() / 2;
+        (a.partition_point(|x| !is_less(&b[b_mid], x)), b_mid)
+    };
+
+    let (a_left, a_right) = a.split_at_mut(a_mid);
+    let (b_left, b_right) = b.split_at_mut(b_mid);
+    let (dest_left, dest_right) = dest.split_at_mut(a_mid + b_mid);
+    rayon::join(
+        || merge(a_left, b_left, dest_left, is_less),
+        || merge(a_right, b_right, dest_right, is_less),
+    );
+}
+
+fn merge_sequential<T, F: Fn(&T, &T) -> bool>(
+    a: &[T], b: &[T], dest: &mut [MaybeUninit<T>], is_less: &F,
+) {
+    let (mut i, mut j) = (0, 0);
+    for slot in dest.iter_mut() {
+        // Only takes from `b` if strictly smaller, so the sort stays stable.
+        let x = if j == b.len() || (i < a.len() && !is_less(&b[j], &a[i])) {
+            i += 1;
+            &a[i - 1]
+        } else {
+            j += 1;
+            &b[j - 1]
+        };
+        // SAFETY: Each element is copied exactly once and `dest` never drops them.
+        *slot = MaybeUninit::new(unsafe { ptr::read(x) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn par_merge_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        par_merge(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn par_merge_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        par_merge(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn par_merge_stable_test() {
+        let mut rng = thread_rng();
+        for &(len, max) in &[(0, 1), (5000, 3), (100_000, 100), (200_000, 1_000_000)] {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..max), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut t = v;
+            par_merge_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn par_merge_unbalanced_test() {
+        // Runs where every element of one goes after the other.
+        let mut v: Vec<u32> = (50_000..100_000).chain(0..50_000).collect();
+        par_merge(&mut v, &|a, b| a < b);
+        assert_eq!(v, (0..100_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn par_merge_drop_test() {
+        let mut rng = thread_rng();
+        let v: Vec<String> = (0..20_000).map(|_| rng.gen_range(0..1000).to_string()).collect();
+        let mut p = v.clone();
+        p.sort();
+
+        let mut t = v;
+        par_merge(&mut t, &|a, b| a < b);
+        assert_eq!(t, p);
+    }
+}
@@ -0,0 +1,180 @@
+//! Parallel quick sort implementation.
+
+use std::cmp::Ordering;
+
+use super::{partition, quick_sort};
+
+/// Default size of the partitions that are sorted sequentially by the parallel quick
+/// sort.
+pub const PAR_QUICK_THRESHOLD: usize = 8192;
+
+/// **Parallel Quick Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Quick Sort.
+///
+/// After each partition, the two parts are sorted as [`rayon`] tasks, so idle threads of
+/// the thread pool steal them. Partitions with [`PAR_QUICK_THRESHOLD`] elements or less
+/// are sorted with the sequential [`quick`], since creating tasks for them costs more
+/// than sorting them. Use [`par_quick_with`] to choose another threshold.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(n²)           | O(log(n))        |
+///
+/// **Obs.:** This is only available with the `parallel` feature.
+///
+/// [`rayon`]: https://docs.rs/rayon
+/// [`PAR_QUICK_THRESHOLD`]: ./constant.PAR_QUICK_THRESHOLD.html
+/// [`quick`]: ./fn.quick.html
+/// [`par_quick_with`]: ./fn.par_quick_with.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::par_quick(&mut v, &|v, b| v < b);
+/// ```
+pub fn par_quick<T, C>(v: &mut [T], cmp: &C)
+where
+    T: PartialOrd + Send,
+    C: Fn(&T, &T) -> bool + Sync,
+{
+    par_quick_sort(v, PAR_QUICK_THRESHOLD, cmp);
+}
+
+/// **Parallel Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`par_quick`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`par_quick`]: ./fn.par_quick.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::par_quick_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn par_quick_by<T, F>(v: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    par_quick_sort(v, PAR_QUICK_THRESHOLD, &|a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Parallel Quick Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`par_quick`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`par_quick`]: ./fn.par_quick.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::par_quick_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn par_quick_by_key<T, K, F>(v: &mut [T], key: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    par_quick_sort(v, PAR_QUICK_THRESHOLD, &|a, b| key(a).lt(&key(b)));
+}
+
+/// **Parallel Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function, sorting sequentially the partitions with `threshold` elements or
+/// less.
+///
+/// Same as [`par_quick_by`], but with a custom threshold instead of
+/// [`PAR_QUICK_THRESHOLD`]. A smaller threshold creates more tasks, that can be better
+/// when comparing the elements is expensive.
+///
+/// [`par_quick_by`]: ./fn.par_quick_by.html
+/// [`PAR_QUICK_THRESHOLD`]: ./constant.PAR_QUICK_THRESHOLD.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v: Vec<u32> = (0..1000).rev().collect();
+/// sort::par_quick_with(&mut v, 100, |a, b| a.cmp(b));
+/// assert_eq!(v, (0..1000).collect::<Vec<_>>());
+/// ```
+pub fn par_quick_with<T, F>(v: &mut [T], threshold: usize, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    par_quick_sort(v, threshold, &|a, b| compare(a, b) == Ordering::Less);
+}
+
+fn par_quick_sort<T: Send, F: Fn(&T, &T) -> bool + Sync>(
+    v: &mut [T], threshold: usize, is_less: &F,
+) {
+    if v.len() <= threshold.max(1) {
+        quick_sort(v, &mut |a, b| is_less(a, b));
+        return;
+    }
+
+    let mid = partition(v, &mut |a, b| is_less(a, b));
+    let (left, right) = v.split_at_mut(mid);
+    rayon::join(
+        || par_quick_sort(left, threshold, is_less),
+        || par_quick_sort(&mut right[1..], threshold, is_less),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn par_quick_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        par_quick(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn par_quick_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        par_quick(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn par_quick_threshold_test() {
+        let mut rng = thread_rng();
+        for &threshold in &[0, 1, 10, 1000, PAR_QUICK_THRESHOLD] {
+            let v: Vec<u32> = (0..50_000).map(|_| rng.gen_range(0..1_000_000)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            par_quick_with(&mut t, threshold, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+}
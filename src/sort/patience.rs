@@ -0,0 +1,277 @@
+//! Patience sort implementation.
+
+use std::cmp::Ordering;
+
+use super::{counting::permute, kway::RunHeap};
+
+/// Piles of the patience game played with a slice.
+///
+/// The elements are dealt in order, each one on the leftmost pile whose top is not less
+/// than it, or on a new pile to the right if there is none. So the tops of the piles are
+/// in crescent order and each pile is in crescent order from the top to the bottom.
+///
+/// The number of piles is the length of the longest strictly increasing subsequence (LIS)
+/// of the slice, and by remembering the top of the previous pile when an element is
+/// dealt, one of those subsequences can be recovered.
+///
+/// # Example
+/// ```rust
+/// use algos::sort::Piles;
+///
+/// let v = [3, 1, 4, 1, 5, 9, 2, 6];
+/// let piles = Piles::new(&v, |a, b| a.cmp(b));
+///
+/// // Indexes of the elements in each pile, from the bottom to the top.
+/// assert_eq!(piles.piles(), &[vec![0, 1, 3], vec![2, 6], vec![4], vec![5, 7]]);
+/// assert_eq!(piles.lis_len(), 4);
+/// assert_eq!(piles.lis(), vec![1, 2, 4, 7]); // [1, 4, 5, 6]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Piles {
+    piles: Vec<Vec<usize>>,
+    prev:  Vec<Option<usize>>,
+}
+
+impl Piles {
+    /// Deal the elements of `v` into piles, comparing them with the [`Ordering`] returned
+    /// by the `compare` function.
+    ///
+    /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+    pub fn new<T, F: FnMut(&T, &T) -> Ordering>(v: &[T], mut compare: F) -> Self {
+        Self::deal(v, &mut |a, b| compare(a, b) == Ordering::Less)
+    }
+
+    fn deal<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> Self {
+        let mut piles: Vec<Vec<usize>> = Vec::new();
+        let mut prev = Vec::with_capacity(v.len());
+
+        for (i, x) in v.iter().enumerate() {
+            // The tops are sorted, so the pile can be found with a binary search.
+            let pile = piles.partition_point(|p| is_less(&v[p[p.len() - 1]], x));
+            prev.push(pile.checked_sub(1).map(|p| piles[p][piles[p].len() - 1]));
+
+            match piles.get_mut(pile) {
+                Some(p) => p.push(i),
+                None => piles.push(vec![i]),
+            }
+        }
+
+        Piles { piles, prev }
+    }
+
+    /// Returns the piles, each one with the indexes of its elements, from the bottom to
+    /// the top.
+    pub fn piles(&self) -> &[Vec<usize>] { &self.piles }
+
+    /// Returns the number of piles.
+    pub fn len(&self) -> usize { self.piles.len() }
+
+    /// Returns `true` if there is no piles, i.e. the slice was empty.
+    pub fn is_empty(&self) -> bool { self.piles.is_empty() }
+
+    /// Returns the length of the longest strictly increasing subsequence, that is the
+    /// number of piles.
+    pub fn lis_len(&self) -> usize { self.len() }
+
+    /// Returns the indexes of one of the longest strictly increasing subsequences, in
+    /// crescent order.
+    pub fn lis(&self) -> Vec<usize> {
+        let mut lis = Vec::with_capacity(self.len());
+        let mut next = self.piles.last().and_then(|p| p.last().copied());
+        while let Some(i) = next {
+            lis.push(i);
+            next = self.prev[i];
+        }
+        lis.reverse();
+        lis
+    }
+
+    /// Merges the piles, returning the indexes of the elements in sorted order.
+    fn merge<T, F: FnMut(&T, &T) -> bool>(mut self, v: &[T], is_less: &mut F) -> Vec<usize> {
+        let mut order = Vec::with_capacity(v.len());
+
+        // The piles, by their top.
+        let mut heap = RunHeap::new((0..self.piles.len()).collect(), &mut |a, b| {
+            is_less(top(&self.piles, v, a), top(&self.piles, v, b))
+        });
+
+        while let Some(pile) = heap.first() {
+            order.extend(self.piles[pile].pop());
+            let piles = &self.piles;
+            heap.update_first(piles[pile].is_empty(), &mut |a, b| {
+                is_less(top(piles, v, a), top(piles, v, b))
+            });
+        }
+
+        order
+    }
+}
+
+/// Returns the element at the top of the `pile`.
+fn top<'v, T>(piles: &[Vec<usize>], v: &'v [T], pile: usize) -> &'v T {
+    &v[piles[pile][piles[pile].len() - 1]]
+}
+
+/// **Patience Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It deals the elements into piles like in the patience card game, where each pile is
+/// sorted, and then does a k-way merge of the piles with a heap. The number of piles is
+/// the length of the longest increasing subsequence, so it is fast when it is short, like
+/// in slices in decrescent order. Use [`Piles`] to get the piles and the longest
+/// increasing subsequence.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** The time complexity is O(nlog(k)), where k is the number of piles.
+///
+/// [`Piles`]: ./struct.Piles.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::patience(&mut v, &|v, b| v < b);
+/// ```
+pub fn patience<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    patience_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Patience Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`patience`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`patience`]: ./fn.patience.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::patience_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn patience_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    patience_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Patience Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`patience`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`patience`]: ./fn.patience.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::patience_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn patience_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    patience_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn patience_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let order = Piles::deal(v, is_less).merge(v, is_less);
+
+    let mut dest = vec![0; v.len()];
+    for (pos, &i) in order.iter().enumerate() {
+        dest[i] = pos;
+    }
+    permute(v, &mut dest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn patience_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        patience(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn patience_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        patience(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn patience_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 10, 100, 1000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            patience_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn lis_test() {
+        let piles = Piles::new(&[] as &[i32], |a, b| a.cmp(b));
+        assert!(piles.is_empty());
+        assert!(piles.lis().is_empty());
+
+        let v = [5, 5, 5];
+        assert_eq!(Piles::new(&v, |a, b| a.cmp(b)).lis_len(), 1);
+
+        let v = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+        let piles = Piles::new(&v, |a, b| a.cmp(b));
+        assert_eq!(piles.lis_len(), 6);
+        let lis: Vec<i32> = piles.lis().iter().map(|&i| v[i]).collect();
+        assert_eq!(lis.len(), 6);
+        assert!(lis.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn lis_random_test() {
+        let mut rng = thread_rng();
+        for _ in 0..20 {
+            let v: Vec<u32> = (0..200).map(|_| rng.gen_range(0..50)).collect();
+
+            // Quadratic dynamic programming LIS.
+            let mut best = vec![1; v.len()];
+            for i in 0..v.len() {
+                for j in 0..i {
+                    if v[j] < v[i] {
+                        best[i] = best[i].max(best[j] + 1);
+                    }
+                }
+            }
+            let expected = best.iter().copied().max().unwrap();
+
+            let piles = Piles::new(&v, |a, b| a.cmp(b));
+            let lis = piles.lis();
+            assert_eq!(piles.lis_len(), expected);
+            assert_eq!(lis.len(), expected);
+            assert!(lis.windows(2).all(|w| w[0] < w[1] && v[w[0]] < v[w[1]]));
+        }
+    }
+}
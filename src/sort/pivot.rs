@@ -0,0 +1,183 @@
+//! Quick sort with a choice of pivot strategy.
+
+use std::cmp::Ordering;
+
+use rand::prelude::{thread_rng, Rng};
+
+use super::partition_at;
+
+/// Slices with this size or larger use the ninther instead of the median of three in
+/// [`PivotStrategy::Ninther`].
+///
+/// [`PivotStrategy::Ninther`]: ./enum.PivotStrategy.html#variant.Ninther
+const NINTHER_THRESHOLD: usize = 40;
+
+/// Pivot strategies for [`quick_with`].
+///
+/// The pivot choice changes how balanced the partitions are. Every strategy but `Random`
+/// is deterministic, so the same input is always sorted with the same operations.
+///
+/// [`quick_with`]: ./fn.quick_with.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PivotStrategy {
+    /// A random element, the strategy of [`quick`](./fn.quick.html). The worst case is
+    /// very unlikely for any input.
+    #[default]
+    Random,
+    /// The first element. Sorted and reversed inputs hit the worst case.
+    First,
+    /// The last element. Sorted and reversed inputs hit the worst case.
+    Last,
+    /// The median of the first, middle and last elements. Sorted and reversed inputs hit
+    /// the best case.
+    MedianOfThree,
+    /// Tukey's ninther: the median of the medians of three groups of three elements
+    /// spread over the slice. It is a better estimate of the median for large slices.
+    Ninther,
+}
+
+impl PivotStrategy {
+    /// Returns the index of the pivot of `v`, that must not be empty.
+    fn pivot<T, F: FnMut(&T, &T) -> bool>(self, v: &[T], is_less: &mut F) -> usize {
+        let (len, last) = (v.len(), v.len() - 1);
+        match self {
+            PivotStrategy::Random => thread_rng().gen_range(0..len),
+            PivotStrategy::First => 0,
+            PivotStrategy::Last => last,
+            PivotStrategy::Ninther if len >= NINTHER_THRESHOLD => {
+                let (step, mid) = (len / 8, len / 2);
+                let a = median_of_three(v, 0, step, 2 * step, is_less);
+                let b = median_of_three(v, mid - step, mid, mid + step, is_less);
+                let c = median_of_three(v, last - 2 * step, last - step, last, is_less);
+                median_of_three(v, a, b, c, is_less)
+            },
+            PivotStrategy::MedianOfThree | PivotStrategy::Ninther => {
+                median_of_three(v, 0, len / 2, last, is_less)
+            },
+        }
+    }
+}
+
+/// Returns the index of the median of `v[a]`, `v[b]` and `v[c]`.
+fn median_of_three<T, F: FnMut(&T, &T) -> bool>(
+    v: &[T], a: usize, b: usize, c: usize, is_less: &mut F,
+) -> usize {
+    // Sorts the three indexes by their elements.
+    let (a, b) = if is_less(&v[b], &v[a]) { (b, a) } else { (a, b) };
+    if is_less(&v[c], &v[b]) {
+        if is_less(&v[c], &v[a]) {
+            a
+        } else {
+            c
+        }
+    } else {
+        b
+    }
+}
+
+/// **Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function, using the `strategy` to choose the pivots.
+///
+/// Same as [`quick_by`], but choosing the pivot strategy. It always recurses into the
+/// smaller partition, so the recursion depth is `O(log(n))` even in the worst case of
+/// the deterministic strategies.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`quick_by`]: ./fn.quick_by.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{self, PivotStrategy};
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// sort::quick_with(&mut v, |a, b| a.cmp(b), PivotStrategy::MedianOfThree);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::quick_with(&mut v, |a, b| b.cmp(a), PivotStrategy::First);
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn quick_with<T, F: FnMut(&T, &T) -> Ordering>(
+    v: &mut [T], mut compare: F, strategy: PivotStrategy,
+) {
+    quick_with_sort(v, strategy, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+fn quick_with_sort<T, F: FnMut(&T, &T) -> bool>(
+    mut v: &mut [T], strategy: PivotStrategy, is_less: &mut F,
+) {
+    while v.len() > 1 {
+        let pivot = strategy.pivot(v, is_less);
+        let mid = partition_at(v, pivot, is_less);
+        let (left, right) = v.split_at_mut(mid);
+        let right = &mut right[1..];
+        // Recurses into the smaller side and loops over the larger one.
+        if left.len() < right.len() {
+            quick_with_sort(left, strategy, is_less);
+            v = right;
+        } else {
+            quick_with_sort(right, strategy, is_less);
+            v = left;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRATEGIES: [PivotStrategy; 5] = [
+        PivotStrategy::Random,
+        PivotStrategy::First,
+        PivotStrategy::Last,
+        PivotStrategy::MedianOfThree,
+        PivotStrategy::Ninther,
+    ];
+
+    #[test]
+    fn quick_with_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        for &strategy in STRATEGIES.iter() {
+            let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+            quick_with(&mut v, |a, b| a.cmp(b), strategy);
+            assert_eq!(v, p, "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn quick_with_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        for &strategy in STRATEGIES.iter() {
+            let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+            quick_with(&mut v, |a: &f64, b| a.total_cmp(b), strategy);
+            assert_eq!(v, p, "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn quick_with_sorted_test() {
+        // The worst case of `First` and `Last`, which must not overflow the stack.
+        let p: Vec<u32> = (0..3000).collect();
+        for &strategy in STRATEGIES.iter() {
+            let mut v = p.clone();
+            quick_with(&mut v, |a, b| a.cmp(b), strategy);
+            assert_eq!(v, p, "{:?}", strategy);
+
+            v.reverse();
+            quick_with(&mut v, |a, b| a.cmp(b), strategy);
+            assert_eq!(v, p, "{:?}", strategy);
+        }
+    }
+
+    #[test]
+    fn median_of_three_test() {
+        let v = [1, 2, 3];
+        let is_less = &mut |a: &i32, b: &i32| a < b;
+        for &(a, b, c) in &[(0, 1, 2), (0, 2, 1), (1, 0, 2), (1, 2, 0), (2, 0, 1), (2, 1, 0)] {
+            assert_eq!(median_of_three(&v, a, b, c, is_less), 1);
+        }
+    }
+}
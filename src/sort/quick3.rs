@@ -0,0 +1,186 @@
+//! Three-way quick sort implementation.
+
+use std::cmp::Ordering;
+
+use rand::prelude::{thread_rng, Rng};
+
+/// **3-Way Quick Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Quick Sort.
+///
+/// It partitions the slice in three parts (Dutch national flag partitioning): the
+/// elements less than the pivot, the elements equal to the pivot and the elements greater
+/// than the pivot. The equal elements are already in their final position, so only the
+/// other two parts are sorted. That makes slices with many repeated elements sort in
+/// near linear time.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(n²)           | O(log(n))        |
+///
+/// **Obs.:** The best case is when all elements are equal. With k distinct elements the
+/// average case is O(nlog(k)).
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::quick3(&mut v, &|v, b| v < b);
+/// ```
+pub fn quick3<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    quick3_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **3-Way Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`quick3`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`quick3`]: ./fn.quick3.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::quick3_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn quick3_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    quick3_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **3-Way Quick Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`quick3`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`quick3`]: ./fn.quick3.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::quick3_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn quick3_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    quick3_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn quick3_sort<T, F: FnMut(&T, &T) -> bool>(mut v: &mut [T], is_less: &mut F) {
+    while v.len() > 1 {
+        let (lt, gt) = partition3(v, is_less);
+        let (left, rest) = std::mem::take(&mut v).split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+
+        // Recurse into the smaller part and loop on the bigger one, so the stack depth is
+        // at most log(n).
+        if left.len() < right.len() {
+            quick3_sort(left, is_less);
+            v = right;
+        } else {
+            quick3_sort(right, is_less);
+            v = left;
+        }
+    }
+}
+
+/// Partitions `v` around a random pivot into the elements less than the pivot, equal to
+/// the pivot and greater than the pivot, returning the range `lt..gt` of the equal ones.
+pub(super) fn partition3<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], is_less: &mut F,
+) -> (usize, usize) {
+    let pivot = thread_rng().gen_range(0..v.len());
+    partition3_at(v, pivot, is_less)
+}
+
+/// Same as `partition3`, but around `v[pivot]`.
+pub(crate) fn partition3_at<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], pivot: usize, is_less: &mut F,
+) -> (usize, usize) {
+    v.swap(0, pivot);
+
+    // Invariant: v[..lt] < pivot, v[lt..i] == pivot, v[gt..] > pivot, and the pivot is at
+    // v[lt].
+    let (mut lt, mut i, mut gt) = (0, 1, v.len());
+    while i < gt {
+        if is_less(&v[i], &v[lt]) {
+            v.swap(i, lt);
+            lt += 1;
+            i += 1;
+        } else if is_less(&v[lt], &v[i]) {
+            gt -= 1;
+            v.swap(i, gt);
+        } else {
+            i += 1;
+        }
+    }
+    (lt, gt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quick3_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        quick3(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn quick3_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        quick3(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn partition3_test() {
+        let mut v = [2, 1, 2, 3, 2, 0, 2];
+        let (lt, gt) = partition3(&mut v, &mut |a, b| a < b);
+        let pivot = v[lt];
+
+        assert!(v[..lt].iter().all(|&x| x < pivot));
+        assert!(v[lt..gt].iter().all(|&x| x == pivot));
+        assert!(v[gt..].iter().all(|&x| x > pivot));
+    }
+
+    #[test]
+    fn quick3_duplicates_test() {
+        let mut rng = thread_rng();
+        for &distinct in &[1, 2, 10, 1000] {
+            let v: Vec<u32> = (0..10_000).map(|_| rng.gen_range(0..distinct)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut comparisons = 0;
+            let mut t = v;
+            quick3_sort(&mut t, &mut |a, b| {
+                comparisons += 1;
+                a < b
+            });
+            assert_eq!(t, p);
+            if distinct == 1 {
+                // A single partition, with two comparisons per element.
+                assert!(comparisons <= 2 * t.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Dual-pivot quick sort implementation.
+
+use std::cmp::Ordering;
+
+use rand::prelude::{thread_rng, Rng};
+
+use super::insection_sort;
+
+/// Partitions with this size or smaller are sorted with insertion sort.
+const INSERTION_THRESHOLD: usize = 27;
+
+/// **Dual-Pivot Quick Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Quick Sort.
+///
+/// It uses Yaroslavskiy's scheme, choosing two pivots `p <= q` and partitioning the slice
+/// in three parts: the elements less than `p`, the elements between `p` and `q` and the
+/// elements greater than `q`. It does fewer swaps and has better cache behavior than the
+/// single-pivot quick sort. Small partitions are sorted with insertion sort.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(n²)           | O(log(n))        |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::quick_dual(&mut v, &|v, b| v < b);
+/// ```
+pub fn quick_dual<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    quick_dual_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Dual-Pivot Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by
+/// the `compare` function.
+///
+/// Same as [`quick_dual`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`quick_dual`]: ./fn.quick_dual.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::quick_dual_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn quick_dual_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    quick_dual_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Dual-Pivot Quick Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`quick_dual`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`quick_dual`]: ./fn.quick_dual.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::quick_dual_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn quick_dual_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    quick_dual_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+pub(super) fn quick_dual_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    if v.len() <= INSERTION_THRESHOLD {
+        insection_sort(v, is_less);
+        return;
+    }
+
+    let (p, q) = dual_partition(v, is_less);
+    let pivots_equal = !is_less(&v[p], &v[q]);
+    let (left, rest) = v.split_at_mut(p);
+    let (middle, right) = rest[1..].split_at_mut(q - p - 1);
+
+    quick_dual_sort(left, is_less);
+    // With equal pivots the middle part have only elements equal to them.
+    if !pivots_equal {
+        quick_dual_sort(middle, is_less);
+    }
+    quick_dual_sort(&mut right[1..], is_less);
+}
+
+/// Partitions `v` around two random pivots `p <= q`, returning their final positions.
+///
+/// After it, the elements before `p` are less than it, the elements after `q` are greater
+/// than it and the elements between them are in the `p..=q` range.
+fn dual_partition<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> (usize, usize) {
+    let last = v.len() - 1;
+    let mut rng = thread_rng();
+    v.swap(0, rng.gen_range(0..=last));
+    v.swap(last, rng.gen_range(1..=last));
+    if is_less(&v[last], &v[0]) {
+        v.swap(0, last);
+    }
+
+    // Invariant: v[1..lt] < p, v[lt..i] in p..=q, v[gt + 1..last] > q.
+    let (mut lt, mut i, mut gt) = (1, 1, last - 1);
+    while i <= gt {
+        if is_less(&v[i], &v[0]) {
+            v.swap(i, lt);
+            lt += 1;
+        } else if is_less(&v[last], &v[i]) {
+            while i < gt && is_less(&v[last], &v[gt]) {
+                gt -= 1;
+            }
+            v.swap(i, gt);
+            gt -= 1;
+            if is_less(&v[i], &v[0]) {
+                v.swap(i, lt);
+                lt += 1;
+            }
+        }
+        i += 1;
+    }
+
+    // Put the pivots in their final positions.
+    let (p, q) = (lt - 1, gt + 1);
+    v.swap(0, p);
+    v.swap(last, q);
+    (p, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quick_dual_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        quick_dual(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn quick_dual_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        quick_dual(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn dual_partition_test() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let mut v: Vec<u32> = (0..50).map(|_| rng.gen_range(0..20)).collect();
+            let (p, q) = dual_partition(&mut v, &mut |a, b| a < b);
+            let (pv, qv) = (v[p], v[q]);
+
+            assert!(p < q && pv <= qv);
+            assert!(v[..p].iter().all(|&x| x < pv));
+            assert!(v[p..=q].iter().all(|&x| pv <= x && x <= qv));
+            assert!(v[q + 1..].iter().all(|&x| x > qv));
+        }
+    }
+
+    #[test]
+    fn quick_dual_random_test() {
+        let mut rng = thread_rng();
+        for &(len, max) in &[(0, 1), (1, 1), (28, 10), (100, 2), (1000, 1000), (10_000, 5)] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..max)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            quick_dual_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+}
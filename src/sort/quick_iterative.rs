@@ -0,0 +1,143 @@
+//! Iterative quick sort implementation.
+
+use std::cmp::Ordering;
+
+use super::partition;
+
+/// **Iterative Quick Sort:** Sort `v` slice according to the way you define the `cmp`
+/// parameter. It's a variation of Quick Sort.
+///
+/// Instead of recursing, it keeps the partitions still to be sorted in an explicit
+/// stack. After each partition the larger side is pushed and the smaller side is sorted
+/// next, so the stack never has more than `log(n)` partitions, even in the worst case,
+/// and there is no risk of a stack overflow with adversarial inputs.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(n²)           | O(log(n))        |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::quick_iterative(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn quick_iterative<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    quick_iterative_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Iterative Quick Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`quick_iterative`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`quick_iterative`]: ./fn.quick_iterative.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::quick_iterative_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn quick_iterative_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    quick_iterative_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Iterative Quick Sort:** Sort `v` slice according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`quick_iterative`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`quick_iterative`]: ./fn.quick_iterative.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::quick_iterative_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn quick_iterative_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    quick_iterative_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn quick_iterative_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    // Partitions still to be sorted, as `(start, end)`. Since the smaller side is always
+    // sorted first, it never has more than `log(n)` partitions.
+    let mut stack = Vec::new();
+    let (mut start, mut end) = (0, v.len());
+    loop {
+        if end - start <= 1 {
+            match stack.pop() {
+                Some((s, e)) => {
+                    start = s;
+                    end = e;
+                    continue;
+                },
+                None => break,
+            }
+        }
+
+        let mid = start + partition(&mut v[start..end], is_less);
+        // Sorts the smaller side next and leaves the larger one for later.
+        if mid - start < end - (mid + 1) {
+            stack.push((mid + 1, end));
+            end = mid;
+        } else {
+            stack.push((start, mid));
+            start = mid + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn quick_iterative_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        quick_iterative(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn quick_iterative_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        quick_iterative(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn quick_iterative_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 100, 10_000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen()).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v.clone();
+            quick_iterative_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Radix sorts implementation.
+
+use super::{counting::permute, insection_sort};
+
+/// Buckets with this size or smaller are sorted with insertion sort by [`radix_msd`].
+///
+/// [`radix_msd`]: ./fn.radix_msd.html
+const MSD_INSERTION_THRESHOLD: usize = 16;
+
+/// Trait for types that can be used as a key of radix sorts.
+///
+/// The key is seen as a sequence of [`BYTES`] digits in base 256, where the byte `0` is
+/// the least significant one, ordered so that sorting by the digits gives the same order
+/// as the type natural order. Signed integers are biased by flipping the sign bit.
+///
+/// [`BYTES`]: #associatedconstant.BYTES
+pub trait RadixKey: Copy {
+    /// Number of bytes of the key.
+    const BYTES: usize;
+
+    /// Returns the `byte`-th byte of the key, where `0` is the least significant byte.
+    fn key_byte(&self, byte: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty)+) => {
+        $(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn key_byte(&self, byte: usize) -> u8 { (*self >> (byte * 8)) as u8 }
+        }
+        )+
+    };
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),+) => {
+        $(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn key_byte(&self, byte: usize) -> u8 {
+                // Flipping the sign bit puts the negative numbers before the positive ones.
+                let biased = (*self as $u) ^ (1 << (<$u>::BITS - 1));
+                (biased >> (byte * 8)) as u8
+            }
+        }
+        )+
+    };
+}
+
+impl_radix_key_unsigned!(u8 u16 u32 u64 u128 usize);
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// **LSD Radix Sort:** Sort `v` slice of integers in crescent order.
+///
+/// It does a stable counting sort for each byte of the elements, from the least
+/// significant to the most significant byte, without doing any comparison. Passes where
+/// all elements have the same byte are skipped.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n·k)          |                  |
+/// | Avrg:     | Θ(n·k)          |                  |
+/// | Worst:    | O(n·k)          | O(n+δ)           |
+///
+/// **Obs.:** k is the number of bytes of the elements and δ is the max size of u8.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, -3, 5, 7, -8, 7];
+/// sort::radix_lsd(&mut v);
+/// assert_eq!(v, [-8, -3, 5, 7, 7, 9]);
+/// ```
+pub fn radix_lsd<T: RadixKey>(v: &mut [T]) { radix_lsd_by_key(v, |&x| x); }
+
+/// **LSD Radix Sort:** Sort `v` slice according to the integer key extracted by the `key`
+/// function.
+///
+/// Same as [`radix_lsd`], but using the keys returned by `key`, like
+/// `slice::sort_by_key`. The passes sort the positions of the elements, and then each
+/// element is swapped to its final position only once.
+///
+/// [`radix_lsd`]: ./fn.radix_lsd.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [(9, 'a'), (3, 'b'), (5, 'c'), (3, 'd')];
+/// sort::radix_lsd_by_key(&mut v, |x| x.0 as u8);
+/// assert_eq!(v, [(3, 'b'), (3, 'd'), (5, 'c'), (9, 'a')]);
+/// ```
+pub fn radix_lsd_by_key<T, K: RadixKey, F: FnMut(&T) -> K>(v: &mut [T], key: F) {
+    if v.len() <= 1 {
+        return;
+    }
+
+    let keys: Vec<K> = v.iter().map(key).collect();
+    // The positions of the elements, in the order of the bytes sorted so far.
+    let mut src: Vec<usize> = (0..v.len()).collect();
+    let mut dst: Vec<usize> = vec![0; v.len()];
+
+    for byte in 0..K::BYTES {
+        let mut counts = [0_usize; 256];
+        for k in &keys {
+            counts[k.key_byte(byte) as usize] += 1;
+        }
+
+        // All elements are in the same bucket, so this pass changes nothing.
+        if counts.contains(&keys.len()) {
+            continue;
+        }
+
+        // Turn the counts into the starting position of each bucket.
+        let mut pos = 0;
+        for c in counts.iter_mut() {
+            let count = *c;
+            *c = pos;
+            pos += count;
+        }
+
+        for &i in &src {
+            let bucket = &mut counts[keys[i].key_byte(byte) as usize];
+            dst[*bucket] = i;
+            *bucket += 1;
+        }
+
+        std::mem::swap(&mut src, &mut dst);
+    }
+
+    // `src[r]` is the position of the element that goes to `r`.
+    for (r, &i) in src.iter().enumerate() {
+        dst[i] = r;
+    }
+    permute(v, &mut dst);
+}
+
+/// **MSD Radix Sort:** Sort `v` slice of byte strings in lexicographic order.
+///
+/// It distributes the strings into buckets by their most significant byte (the strings
+/// that end first goes to the first bucket), swapping them in place (American flag sort),
+/// and then sorts each bucket by the next byte, keeping the buckets left to sort in a
+/// stack instead of recursing. Small buckets are sorted with insertion sort.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n·k)          |                  |
+/// | Worst:    | O(n·k)          | O(n+δ)           |
+///
+/// **Obs.:** k is the length of the longest common prefix between the strings and δ is
+/// the max size of u8.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = ["banana", "apple", "cherry", "app", "ban"];
+/// sort::radix_msd(&mut v);
+/// assert_eq!(v, ["app", "apple", "ban", "banana", "cherry"]);
+///
+/// let mut v = vec![b"zz".to_vec(), b"a".to_vec(), b"".to_vec()];
+/// sort::radix_msd(&mut v);
+/// assert_eq!(v, vec![b"".to_vec(), b"a".to_vec(), b"zz".to_vec()]);
+/// ```
+pub fn radix_msd<T: AsRef<[u8]>>(v: &mut [T]) {
+    // The buckets left to sort, with the depth of the byte that splits them. It is not
+    // recursive, since strings with a long common prefix would need a frame per byte.
+    let mut stack = vec![(0, v.len(), 0)];
+    while let Some((start, end, depth)) = stack.pop() {
+        let counts = msd_distribute(&mut v[start..end], depth);
+
+        // The first bucket have only strings that ended, so they are all equal.
+        let mut pos = start + counts[0];
+        for &count in &counts[1..] {
+            if count > 1 {
+                stack.push((pos, pos + count, depth + 1));
+            }
+            pos += count;
+        }
+    }
+}
+
+/// Returns the bucket of `s` at `depth`, `0` means the string already ended.
+#[inline]
+fn msd_bucket<T: AsRef<[u8]>>(s: &T, depth: usize) -> usize {
+    s.as_ref().get(depth).map_or(0, |&b| b as usize + 1)
+}
+
+/// Swaps each string of `v` into its bucket by the byte at `depth`, returning the size of
+/// each bucket, or sorts `v` with insertion sort if it is small, returning no buckets.
+fn msd_distribute<T: AsRef<[u8]>>(v: &mut [T], depth: usize) -> [usize; 257] {
+    let mut counts = [0_usize; 257];
+    if v.len() <= MSD_INSERTION_THRESHOLD {
+        // Every string in a bucket have at least `depth` bytes, all of them equal.
+        insection_sort(v, &mut |a, b| a.as_ref()[depth..] < b.as_ref()[depth..]);
+        return counts;
+    }
+
+    for s in v.iter() {
+        counts[msd_bucket(s, depth)] += 1;
+    }
+
+    // Start and end of each bucket.
+    let mut starts = [0_usize; 257];
+    let mut ends = [0_usize; 257];
+    let mut pos = 0;
+    for (b, &count) in counts.iter().enumerate() {
+        starts[b] = pos;
+        pos += count;
+        ends[b] = pos;
+    }
+
+    // Swap each element into its bucket.
+    let mut next = starts;
+    for b in 0..257 {
+        while next[b] < ends[b] {
+            let target = msd_bucket(&v[next[b]], depth);
+            if target == b {
+                next[b] += 1;
+            } else {
+                v.swap(next[b], next[target]);
+                next[target] += 1;
+            }
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn radix_lsd_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        radix_lsd(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn radix_lsd_signed_test() {
+        let p = [i64::MIN, -300, -3, -1, 0, 1, 7, 256, i64::MAX];
+        let mut v = [7, -1, i64::MAX, 0, -300, 256, i64::MIN, 1, -3];
+
+        radix_lsd(&mut v);
+        assert_eq!(v, p);
+
+        let mut v: [i8; 5] = [0, -128, 127, -1, 1];
+        radix_lsd(&mut v);
+        assert_eq!(v, [-128, -1, 0, 1, 127]);
+    }
+
+    #[test]
+    fn radix_lsd_random_test() {
+        let mut rng = thread_rng();
+
+        let v: Vec<u64> = (0..1000).map(|_| rng.gen()).collect();
+        let mut p = v.clone();
+        p.sort();
+        let mut t = v;
+        radix_lsd(&mut t);
+        assert_eq!(t, p);
+
+        let v: Vec<i32> = (0..1000).map(|_| rng.gen()).collect();
+        let mut p = v.clone();
+        p.sort();
+        let mut t = v;
+        radix_lsd(&mut t);
+        assert_eq!(t, p);
+
+        let v: Vec<u16> = (0..1000).map(|_| rng.gen_range(0..300)).collect();
+        let mut p = v.clone();
+        p.sort();
+        let mut t = v;
+        radix_lsd(&mut t);
+        assert_eq!(t, p);
+    }
+
+    #[test]
+    fn radix_lsd_by_key_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..1000).map(|i| (rng.gen_range(0..50_000), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v;
+        radix_lsd_by_key(&mut t, |x| x.0);
+        assert_eq!(t, p);
+
+        // The elements don't need to be `Clone`.
+        struct Item(i16);
+        let mut t: Vec<Item> = (0..1000).map(|_| Item(rng.gen())).collect();
+        radix_lsd_by_key(&mut t, |x| x.0);
+        assert!(t.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn radix_msd_test() {
+        let p = ["", "a", "ab", "abc", "b", "ba", "bab", "z"];
+        let mut v = ["bab", "z", "ab", "", "ba", "abc", "a", "b"];
+
+        radix_msd(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn radix_msd_long_prefix_test() {
+        // One level for each byte of the common prefix, that used to overflow the stack.
+        let mut v = vec![vec![b'x'; 20_000]; 64];
+        radix_msd(&mut v);
+        assert!(v.iter().all(|s| s.len() == 20_000));
+
+        let mut v: Vec<Vec<u8>> = (0..64_u8)
+            .rev()
+            .map(|i| {
+                let mut s = vec![b'x'; 20_000];
+                s.push(i);
+                s
+            })
+            .collect();
+        let mut p = v.clone();
+        p.sort();
+        radix_msd(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn radix_msd_random_test() {
+        let mut rng = thread_rng();
+        let v: Vec<Vec<u8>> = (0..2000)
+            .map(|_| {
+                let len = rng.gen_range(0..8);
+                (0..len).map(|_| rng.gen_range(b'a'..=b'd')).collect()
+            })
+            .collect();
+        let mut p = v.clone();
+        p.sort();
+
+        let mut t = v;
+        radix_msd(&mut t);
+        assert_eq!(t, p);
+
+        let mut strings: Vec<String> = (0..500).map(|_| rng.gen::<u32>().to_string()).collect();
+        let mut p = strings.clone();
+        p.sort();
+        radix_msd(&mut strings);
+        assert_eq!(strings, p);
+    }
+}
@@ -0,0 +1,240 @@
+//! Quickselect and partial sort implementation.
+
+use std::cmp::Ordering;
+
+use super::{intro::intro_sort, quick3::partition3};
+
+/// **Quickselect:** Reorders `v` so that the element at the `n` position is the one that
+/// would be there if `v` was sorted according to the way you define the `cmp` parameter,
+/// like C++ `nth_element`.
+///
+/// After it, the elements before `n` are not greater than `v[n]` and the elements after
+/// it are not less than `v[n]`, in any order. It partitions the slice like a quick sort,
+/// but only keeps going into the part that has the `n` position.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n²)           | O(1)             |
+///
+/// # Panic
+/// This function panics if `n` is not smaller than the `v` size.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7, 1];
+/// // The median
+/// sort::select_nth(&mut v, 3, &|v, b| v < b);
+/// assert_eq!(v[3], 7);
+/// assert!(v[..3].iter().all(|&x| x <= 7) && v[4..].iter().all(|&x| x >= 7));
+/// ```
+pub fn select_nth<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], n: usize, cmp: &C) {
+    select_nth_sort(v, n, &mut |a, b| cmp(a, b));
+}
+
+/// **Quickselect:** Reorders `v` so that the element at the `n` position is the one that
+/// would be there if `v` was sorted according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`select_nth`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`select_nth`]: ./fn.select_nth.html
+///
+/// # Panic
+/// This function panics if `n` is not smaller than the `v` size.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // The second greatest element
+/// sort::select_nth_by(&mut v, 1, |a, b| b.cmp(a));
+/// assert_eq!(v[1], 8);
+/// ```
+pub fn select_nth_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], n: usize, mut compare: F) {
+    select_nth_sort(v, n, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Quickselect:** Reorders `v` so that the element at the `n` position is the one that
+/// would be there if `v` was sorted according to the key extracted by the `key` function.
+///
+/// Same as [`select_nth`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`select_nth`]: ./fn.select_nth.html
+///
+/// # Panic
+/// This function panics if `n` is not smaller than the `v` size.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // The element with the least absolute value
+/// sort::select_nth_by_key(&mut v, 0, |a: &i32| a.abs());
+/// assert_eq!(v[0], 3);
+/// ```
+pub fn select_nth_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], n: usize, mut key: F) {
+    select_nth_sort(v, n, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn select_nth_sort<T, F: FnMut(&T, &T) -> bool>(mut v: &mut [T], mut n: usize, is_less: &mut F) {
+    assert!(n < v.len(), "n is {} but the slice size is {}", n, v.len());
+
+    while v.len() > 1 {
+        let (lt, gt) = partition3(v, is_less);
+        if n < lt {
+            v = &mut std::mem::take(&mut v)[..lt];
+        } else if n >= gt {
+            v = &mut std::mem::take(&mut v)[gt..];
+            n -= gt;
+        } else {
+            // `n` is among the elements equal to the pivot.
+            return;
+        }
+    }
+}
+
+/// **Partial Sort:** Sorts only the first `k` positions of `v` according to the way you
+/// define the `cmp` parameter, like C++ `partial_sort`.
+///
+/// After it, `v[..k]` has the `k` least elements of `v` in order, and the rest of the
+/// elements are after them in any order. It uses [`select_nth`] to move the `k` least
+/// elements to the start and sorts only them. If `k` is not smaller than the `v` size,
+/// the whole slice is sorted.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity  | Space complexity |
+/// |:----------|:----------------:|:----------------:|
+/// | Best:     | Ω(n + klog(k))   |                  |
+/// | Avrg:     | Θ(n + klog(k))   |                  |
+/// | Worst:    | O(n² + klog(k))  | O(log(k))        |
+///
+/// [`select_nth`]: ./fn.select_nth.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting of the 3 least elements
+/// sort::partial(&mut v, 3, &|v, b| v < b);
+/// assert_eq!(v[..3], [3, 5, 7]);
+/// ```
+pub fn partial<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], k: usize, cmp: &C) {
+    partial_sort(v, k, &mut |a, b| cmp(a, b));
+}
+
+/// **Partial Sort:** Sorts only the first `k` positions of `v` according to the
+/// [`Ordering`] returned by the `compare` function.
+///
+/// Same as [`partial`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`partial`]: ./fn.partial.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting of the 3 greatest elements
+/// sort::partial_by(&mut v, 3, |a, b| b.cmp(a));
+/// assert_eq!(v[..3], [9, 8, 7]);
+/// ```
+pub fn partial_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], k: usize, mut compare: F) {
+    partial_sort(v, k, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Partial Sort:** Sorts only the first `k` positions of `v` according to the key
+/// extracted by the `key` function.
+///
+/// Same as [`partial`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`partial`]: ./fn.partial.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value of the 3 least elements
+/// sort::partial_by_key(&mut v, 3, |a: &i32| a.abs());
+/// assert_eq!(v[..3], [3, -5, -6]);
+/// ```
+pub fn partial_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], k: usize, mut key: F) {
+    partial_sort(v, k, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn partial_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], k: usize, is_less: &mut F) {
+    if k == 0 {
+        return;
+    }
+    let k = k.min(v.len());
+    if k < v.len() {
+        select_nth_sort(v, k - 1, is_less);
+    }
+    intro_sort(&mut v[..k], is_less);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn select_nth_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        for n in 0..p.len() {
+            let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+            select_nth(&mut v, n, &|a, b| a < b);
+            assert_eq!(v[n], p[n]);
+            assert!(v[..n].iter().all(|&x| x <= p[n]));
+            assert!(v[n + 1..].iter().all(|&x| x >= p[n]));
+        }
+    }
+
+    #[test]
+    fn select_nth_floats_test() {
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        select_nth(&mut v, 5, &|a, b| a < b);
+        assert_eq!(v[5], 9.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn select_nth_out_of_bounds_test() {
+        let mut v = [1, 2, 3];
+        select_nth(&mut v, 3, &|a, b| a < b);
+    }
+
+    #[test]
+    fn partial_test() {
+        let mut rng = thread_rng();
+        for &(len, max) in &[(0, 1), (1, 1), (100, 5), (1000, 1000)] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..max)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            for &k in &[0, 1, len / 2, len, len + 1] {
+                let mut t = v.clone();
+                partial_by(&mut t, k, |a, b| a.cmp(b));
+
+                let k = k.min(len);
+                assert_eq!(t[..k], p[..k]);
+                t[k..].sort();
+                assert_eq!(t, p);
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+//! Shell sort implementation.
+
+use std::cmp::Ordering;
+
+/// Known good gaps for shell sort found by Marcin Ciura.
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+/// Gap sequences for [`shell_with`].
+///
+/// The choice of the gap sequence changes the complexity of shell sort, the default is
+/// `Ciura`, that have the best known practical performance.
+///
+/// [`shell_with`]: ./fn.shell_with.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum GapSequence {
+    /// Shell's original sequence: `n/2, n/4, ..., 1`. Worst case is `O(n²)`.
+    Shell,
+    /// Knuth's sequence: `(3^k - 1)/2`, not greater than `n/3`. Worst case is
+    /// `O(n^(3/2))`.
+    Knuth,
+    /// Ciura's empirical sequence: `1, 4, 10, 23, 57, 132, 301, 701, 1750`, extended by
+    /// multiplying the last gap by 2.25.
+    #[default]
+    Ciura,
+    /// Tokuda's sequence: `⌈(9^k - 4^k) / (5·4^(k-1))⌉`.
+    Tokuda,
+    /// Gaps supplied by the caller, in any order. A final pass with gap `1` is always
+    /// done.
+    Custom(Vec<usize>),
+}
+
+impl GapSequence {
+    /// Returns the gaps to be used to sort a slice with `len` elements, in decreasing
+    /// order and always ending with `1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use algos::sort::GapSequence;
+    ///
+    /// assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+    /// assert_eq!(GapSequence::Knuth.gaps(100), vec![13, 4, 1]);
+    /// ```
+    pub fn gaps(&self, len: usize) -> Vec<usize> {
+        let mut gaps: Vec<usize> = match self {
+            GapSequence::Shell => {
+                let mut gaps = Vec::new();
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps
+            },
+            GapSequence::Knuth => {
+                let mut gaps = vec![1];
+                let mut gap = 4;
+                while gap <= len / 3 {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+                gaps
+            },
+            GapSequence::Ciura => {
+                let mut gaps: Vec<usize> =
+                    CIURA_GAPS.iter().copied().take_while(|&g| g < len).collect();
+                let mut gap = CIURA_GAPS[CIURA_GAPS.len() - 1];
+                while gaps.len() == CIURA_GAPS.len() && gap < len {
+                    gap = (gap as f64 * 2.25) as usize;
+                    if gap < len {
+                        gaps.push(gap);
+                    }
+                }
+                gaps
+            },
+            GapSequence::Tokuda => {
+                let mut gaps = Vec::new();
+                let mut k = 1;
+                loop {
+                    let gap = (9.0 * 2.25_f64.powi(k - 1) - 4.0) / 5.0;
+                    let gap = gap.ceil() as usize;
+                    if gap >= len.max(2) {
+                        break;
+                    }
+                    gaps.push(gap);
+                    k += 1;
+                }
+                gaps
+            },
+            GapSequence::Custom(gaps) => gaps.iter().copied().filter(|&g| g > 0).collect(),
+        };
+
+        gaps.sort_unstable_by(|a, b| b.cmp(a));
+        gaps.dedup();
+        if gaps.last() != Some(&1) {
+            gaps.push(1);
+        }
+        gaps
+    }
+}
+
+/// **Shell Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It's a generalization of insertion sort that sorts elements far apart from each other
+/// first, reducing the gap between them until it is 1, that is the insertion sort. It
+/// uses the [`GapSequence::Ciura`] gaps, use [`shell_with`] to choose the gap sequence.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(n^(4/3))      |                  |
+/// | Worst:    | O(n^(3/2))      | O(1)             |
+///
+/// **Obs.:** The average and worst cases depends on the gap sequence. There is no proven
+/// bound for Ciura's sequence, the values are the ones of similar sequences.
+///
+/// [`GapSequence::Ciura`]: ./enum.GapSequence.html#variant.Ciura
+/// [`shell_with`]: ./fn.shell_with.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::shell(&mut v, &|v, b| v < b);
+/// ```
+pub fn shell<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    shell_sort(v, &GapSequence::default(), &mut |a, b| cmp(a, b));
+}
+
+/// **Shell Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`shell`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`shell`]: ./fn.shell.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::shell_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn shell_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], compare: F) {
+    shell_with(v, &GapSequence::default(), compare);
+}
+
+/// **Shell Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`shell`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`shell`]: ./fn.shell.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::shell_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn shell_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    shell_sort(v, &GapSequence::default(), &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// **Shell Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function, using the `gaps` sequence.
+///
+/// Same as [`shell_by`], but choosing the gap sequence.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`shell_by`]: ./fn.shell_by.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{self, GapSequence};
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// sort::shell_with(&mut v, &GapSequence::Knuth, |a, b| a.cmp(b));
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// sort::shell_with(&mut v, &GapSequence::Custom(vec![5, 3]), |a, b| a.cmp(b));
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn shell_with<T, F: FnMut(&T, &T) -> Ordering>(
+    v: &mut [T], gaps: &GapSequence, mut compare: F,
+) {
+    shell_sort(v, gaps, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+fn shell_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], gaps: &GapSequence, is_less: &mut F) {
+    if v.len() <= 1 {
+        return;
+    }
+
+    for gap in gaps.gaps(v.len()) {
+        // Insertion sort of the elements `gap` apart.
+        for i in gap..v.len() {
+            let mut j = i;
+            while j >= gap && is_less(&v[j], &v[j - gap]) {
+                v.swap(j, j - gap);
+                j -= gap;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn shell_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        shell(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn shell_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        shell(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn gaps_test() {
+        assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+        assert_eq!(GapSequence::Knuth.gaps(1000), vec![121, 40, 13, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(5000), vec![
+            3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1
+        ]);
+        assert_eq!(GapSequence::Tokuda.gaps(100), vec![46, 20, 9, 4, 1]);
+        assert_eq!(GapSequence::Custom(vec![0, 3, 7, 3]).gaps(10), vec![7, 3, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(0), vec![1]);
+    }
+
+    #[test]
+    fn shell_with_test() {
+        let mut rng = thread_rng();
+        let sequences = [
+            GapSequence::Shell,
+            GapSequence::Knuth,
+            GapSequence::Ciura,
+            GapSequence::Tokuda,
+            GapSequence::Custom(vec![8, 2]),
+        ];
+
+        for gaps in sequences.iter() {
+            for &len in &[0, 1, 2, 10, 100, 5000] {
+                let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..1000)).collect();
+                let mut p = v.clone();
+                p.sort();
+
+                let mut t = v;
+                shell_with(&mut t, gaps, |a, b| a.cmp(b));
+                assert_eq!(t, p, "{:?}", gaps);
+            }
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! SIMD sort implementation.
+
+use super::intro::intro_sort;
+
+/// Numbers that can be sorted by [`simd`].
+///
+/// Each number is mapped to an `i32` key with the same order, so the vectorized kernels
+/// only need to sort `i32`s.
+///
+/// [`simd`]: ./fn.simd.html
+pub trait SimdKey: Copy {
+    /// Returns the key of the number, such that `a` goes before `b` if and only if
+    /// `a.to_key() < b.to_key()`.
+    fn to_key(self) -> i32;
+
+    /// Returns the number of the `key`, the inverse of [`to_key`](#tymethod.to_key).
+    fn from_key(key: i32) -> Self;
+}
+
+impl SimdKey for i32 {
+    #[inline]
+    fn to_key(self) -> i32 { self }
+
+    #[inline]
+    fn from_key(key: i32) -> Self { key }
+}
+
+impl SimdKey for u32 {
+    // Flipping the sign bit puts the numbers of the upper half after the lower half.
+    #[inline]
+    fn to_key(self) -> i32 { (self ^ (1 << 31)) as i32 }
+
+    #[inline]
+    fn from_key(key: i32) -> Self { (key as u32) ^ (1 << 31) }
+}
+
+impl SimdKey for f32 {
+    // The same trick of `f32::total_cmp`: flipping every bit but the sign of the negative
+    // numbers reverses their order. It keeps the sign, so it is its own inverse.
+    #[inline]
+    fn to_key(self) -> i32 {
+        let bits = self.to_bits() as i32;
+        bits ^ (((bits >> 31) as u32) >> 1) as i32
+    }
+
+    #[inline]
+    fn from_key(key: i32) -> Self {
+        f32::from_bits((key ^ (((key >> 31) as u32) >> 1) as i32) as u32)
+    }
+}
+
+/// **SIMD Sort:** Sort `v` slice of `i32`, `u32` or `f32` in crescent order, using vector
+/// instructions when the CPU supports them.
+///
+/// The numbers are mapped to `i32` keys and, on `x86_64` CPUs with AVX2, each block of 64
+/// keys is loaded in eight vectors of eight lanes and sorted without leaving the
+/// registers: a sorting network with vector `min`s and `max`s sorts the columns, a
+/// transpose turns them into rows and bitonic merges join the rows in runs of 16. Then
+/// the runs are merged bottom-up, with a bitonic merge of eight keys at a time. Other
+/// CPUs sort the keys with [`intro`].
+///
+/// The `f32`s are sorted in the IEEE 754 total order, like `f32::total_cmp`, so the
+/// negative `NaN`s go first and the positive `NaN`s go last.
+///
+/// This sort is unstable, but equal numbers can't be told apart.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// **Obs.:** This is only available with the `simd` feature.
+///
+/// [`intro`]: ./fn.intro.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// sort::simd(&mut v);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+///
+/// let mut v = [0.5, -1.0, f32::INFINITY, -0.0, 0.0];
+/// sort::simd(&mut v);
+/// assert_eq!(v, [-1.0, -0.0, 0.0, 0.5, f32::INFINITY]);
+/// ```
+pub fn simd<T: SimdKey>(v: &mut [T]) {
+    let mut keys: Vec<i32> = v.iter().map(|&x| x.to_key()).collect();
+    sort_keys(&mut keys);
+    for (x, &key) in v.iter_mut().zip(&keys) {
+        *x = T::from_key(key);
+    }
+}
+
+fn sort_keys(keys: &mut [i32]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // SAFETY: The CPU supports AVX2.
+            unsafe { avx2::sort(keys) };
+            return;
+        }
+    }
+    intro_sort(keys, &mut |a, b| a < b);
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    //! The AVX2 kernels. Every function must only be called if the CPU supports AVX2.
+
+    use std::arch::x86_64::*;
+
+    use super::{super::network::NETWORKS, intro_sort};
+
+    /// Number of `i32` lanes in a vector.
+    const LANES: usize = 8;
+    /// Size of the blocks sorted in the registers, in runs of `RUN` keys.
+    const BLOCK: usize = LANES * LANES;
+    const RUN: usize = 2 * LANES;
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn sort(v: &mut [i32]) {
+        let len = v.len();
+        let mut blocks = v.chunks_exact_mut(BLOCK);
+        for block in &mut blocks {
+            sort_block(block);
+        }
+        for run in blocks.into_remainder().chunks_mut(RUN) {
+            intro_sort(run, &mut |a, b| a < b);
+        }
+
+        // Bottom-up merges of the runs, back and forth between `v` and `buf`.
+        let mut buf = vec![0; len];
+        let mut in_buf = false;
+        let mut width = RUN;
+        while width < len {
+            let (src, dst): (&[i32], &mut [i32]) = if in_buf { (&buf, v) } else { (v, &mut buf) };
+            for (i, out) in dst.chunks_mut(2 * width).enumerate() {
+                let runs = &src[2 * width * i..][..out.len()];
+                let (left, right) = runs.split_at(width.min(runs.len()));
+                merge(left, right, out);
+            }
+            in_buf = !in_buf;
+            width *= 2;
+        }
+        if in_buf {
+            v.copy_from_slice(&buf);
+        }
+    }
+
+    /// Sorts the `BLOCK` keys of `block` in runs of `RUN` keys.
+    #[target_feature(enable = "avx2")]
+    unsafe fn sort_block(block: &mut [i32]) {
+        let mut rows = [_mm256_setzero_si256(); LANES];
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = _mm256_loadu_si256(block.as_ptr().add(i * LANES) as *const __m256i);
+        }
+
+        // Sorts the columns, then each row has one column.
+        for &(i, j) in NETWORKS[LANES] {
+            let (a, b) = (rows[i as usize], rows[j as usize]);
+            rows[i as usize] = _mm256_min_epi32(a, b);
+            rows[j as usize] = _mm256_max_epi32(a, b);
+        }
+        transpose(&mut rows);
+
+        for (i, pair) in rows.chunks_exact(2).enumerate() {
+            let (lo, hi) = merge_vectors(pair[0], pair[1]);
+            let out = block.as_mut_ptr().add(i * RUN);
+            _mm256_storeu_si256(out as *mut __m256i, lo);
+            _mm256_storeu_si256(out.add(LANES) as *mut __m256i, hi);
+        }
+    }
+
+    /// Transposes the 8x8 matrix of `rows`.
+    #[target_feature(enable = "avx2")]
+    unsafe fn transpose(rows: &mut [__m256i; LANES]) {
+        let [r0, r1, r2, r3, r4, r5, r6, r7] = *rows;
+
+        let t0 = _mm256_unpacklo_epi32(r0, r1);
+        let t1 = _mm256_unpackhi_epi32(r0, r1);
+        let t2 = _mm256_unpacklo_epi32(r2, r3);
+        let t3 = _mm256_unpackhi_epi32(r2, r3);
+        let t4 = _mm256_unpacklo_epi32(r4, r5);
+        let t5 = _mm256_unpackhi_epi32(r4, r5);
+        let t6 = _mm256_unpacklo_epi32(r6, r7);
+        let t7 = _mm256_unpackhi_epi32(r6, r7);
+
+        let s0 = _mm256_unpacklo_epi64(t0, t2);
+        let s1 = _mm256_unpackhi_epi64(t0, t2);
+        let s2 = _mm256_unpacklo_epi64(t1, t3);
+        let s3 = _mm256_unpackhi_epi64(t1, t3);
+        let s4 = _mm256_unpacklo_epi64(t4, t6);
+        let s5 = _mm256_unpackhi_epi64(t4, t6);
+        let s6 = _mm256_unpacklo_epi64(t5, t7);
+        let s7 = _mm256_unpackhi_epi64(t5, t7);
+
+        *rows = [
+            _mm256_permute2x128_si256(s0, s4, 0x20),
+            _mm256_permute2x128_si256(s1, s5, 0x20),
+            _mm256_permute2x128_si256(s2, s6, 0x20),
+            _mm256_permute2x128_si256(s3, s7, 0x20),
+            _mm256_permute2x128_si256(s0, s4, 0x31),
+            _mm256_permute2x128_si256(s1, s5, 0x31),
+            _mm256_permute2x128_si256(s2, s6, 0x31),
+            _mm256_permute2x128_si256(s3, s7, 0x31),
+        ];
+    }
+
+    /// Merges the sorted vectors `a` and `b`, returning the lower and the upper half.
+    #[target_feature(enable = "avx2")]
+    unsafe fn merge_vectors(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+        // `a` followed by the reverse of `b` is bitonic, so the minimums and maximums of
+        // each pair are the halves of the merge, each one also bitonic.
+        let b = _mm256_permutevar8x32_epi32(b, _mm256_setr_epi32(7, 6, 5, 4, 3, 2, 1, 0));
+        let lo = _mm256_min_epi32(a, b);
+        let hi = _mm256_max_epi32(a, b);
+        (bitonic_sort(lo), bitonic_sort(hi))
+    }
+
+    /// Sorts the bitonic vector `x`, comparing the lanes at distance 4, 2 and 1.
+    #[target_feature(enable = "avx2")]
+    unsafe fn bitonic_sort(x: __m256i) -> __m256i {
+        let p = _mm256_permute2x128_si256(x, x, 0x01);
+        let x = _mm256_blend_epi32(_mm256_min_epi32(x, p), _mm256_max_epi32(x, p), 0b1111_0000);
+        let p = _mm256_shuffle_epi32(x, 0b01_00_11_10);
+        let x = _mm256_blend_epi32(_mm256_min_epi32(x, p), _mm256_max_epi32(x, p), 0b1100_1100);
+        let p = _mm256_shuffle_epi32(x, 0b10_11_00_01);
+        _mm256_blend_epi32(_mm256_min_epi32(x, p), _mm256_max_epi32(x, p), 0b1010_1010)
+    }
+
+    /// Merges the sorted runs `a` and `b` into `out`, eight keys at a time.
+    ///
+    /// It keeps the greatest eight keys merged so far in a vector and merges it with the
+    /// next eight keys of the run with the smallest next key, so the lower half of the
+    /// merge is done. The runs are padded with `i32::MAX` to a multiple of eight, which
+    /// end up after the keys of the runs and are not written.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn merge(a: &[i32], b: &[i32], out: &mut [i32]) {
+        if b.is_empty() {
+            out.copy_from_slice(a);
+            return;
+        }
+
+        let mut top = load(a, 0);
+        let (mut i, mut j, mut k) = (LANES, 0, 0);
+        while i < a.len() || j < b.len() {
+            let next = if j >= b.len() || (i < a.len() && a[i] <= b[j]) {
+                i += LANES;
+                load(a, i - LANES)
+            } else {
+                j += LANES;
+                load(b, j - LANES)
+            };
+            let (lo, hi) = merge_vectors(top, next);
+            store(out, k, lo);
+            top = hi;
+            k += LANES;
+        }
+        store(out, k, top);
+    }
+
+    /// Loads the eight keys of `run` from `start`, padded with `i32::MAX`.
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(run: &[i32], start: usize) -> __m256i {
+        if start + LANES <= run.len() {
+            _mm256_loadu_si256(run.as_ptr().add(start) as *const __m256i)
+        } else {
+            let mut keys = [i32::MAX; LANES];
+            keys[..run.len() - start].copy_from_slice(&run[start..]);
+            _mm256_loadu_si256(keys.as_ptr() as *const __m256i)
+        }
+    }
+
+    /// Stores the eight keys of `x` in `out` from `start`, without passing its end.
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(out: &mut [i32], start: usize, x: __m256i) {
+        if start + LANES <= out.len() {
+            _mm256_storeu_si256(out.as_mut_ptr().add(start) as *mut __m256i, x);
+        } else if start < out.len() {
+            let mut keys = [0; LANES];
+            _mm256_storeu_si256(keys.as_mut_ptr() as *mut __m256i, x);
+            let rest = out.len() - start;
+            out[start..].copy_from_slice(&keys[..rest]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn simd_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        simd(&mut v);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn simd_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5_f32, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        simd(&mut v);
+        assert_eq!(v, p);
+
+        let mut v = [f32::NAN, 1.0, -f32::NAN, f32::NEG_INFINITY, -0.0, 0.0, f32::MIN];
+        simd(&mut v);
+        let bits: Vec<u32> = v.iter().map(|x| x.to_bits()).collect();
+        let mut p = [f32::NAN, 1.0, -f32::NAN, f32::NEG_INFINITY, -0.0, 0.0, f32::MIN];
+        p.sort_by(f32::total_cmp);
+        assert_eq!(bits, p.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn simd_random_test() {
+        let mut rng = thread_rng();
+        for len in (0..200).chain(vec![1000, 4099, 10000]) {
+            let v: Vec<i32> = (0..len).map(|_| rng.gen()).collect();
+            let mut p = v.clone();
+            p.sort();
+            let mut t = v;
+            simd(&mut t);
+            assert_eq!(t, p);
+
+            // Many equal keys, including the `i32::MAX` of the padding.
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..8) * (u32::MAX / 7)).collect();
+            let mut p = v.clone();
+            p.sort();
+            let mut t = v;
+            simd(&mut t);
+            assert_eq!(t, p);
+
+            let v: Vec<f32> = (0..len).map(|_| rng.gen_range(-1e6..1e6)).collect();
+            let mut p = v.clone();
+            p.sort_by(f32::total_cmp);
+            let mut t = v;
+            simd(&mut t);
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn simd_key_test() {
+        let v = [u32::MIN, 1, 1 << 31, u32::MAX];
+        assert!(v.windows(2).all(|w| w[0].to_key() < w[1].to_key()));
+        assert!(v.iter().all(|&x| u32::from_key(x.to_key()) == x));
+
+        let v = [-f32::NAN, f32::NEG_INFINITY, -1.0, -0.0, 0.0, 1.0, f32::INFINITY, f32::NAN];
+        assert!(v.windows(2).all(|w| w[0].to_key() < w[1].to_key()));
+        assert!(v.iter().all(|&x| f32::from_key(x.to_key()).to_bits() == x.to_bits()));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn avx2_merge_test() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let mut a: Vec<i32> =
+                (0..rng.gen_range(0..40)).map(|_| rng.gen_range(-50..50)).collect();
+            let mut b: Vec<i32> =
+                (0..rng.gen_range(0..40)).map(|_| rng.gen_range(-50..50)).collect();
+            a.sort();
+            b.sort();
+            let mut p = [&a[..], &b[..]].concat();
+            p.sort();
+
+            let mut out = vec![0; p.len()];
+            // SAFETY: The CPU supports AVX2.
+            unsafe { avx2::merge(&a, &b, &mut out) };
+            assert_eq!(out, p);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Smoothsort implementation.
+
+use std::cmp::Ordering;
+
+/// Leonardo numbers are bigger than any `usize` after this many orders.
+const MAX_ORDERS: usize = 96;
+
+/// **Smooth Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+/// It's a variation of Heap Sort.
+///
+/// It keeps the slice as a sequence of heaps with the size of Leonardo numbers (1, 1, 3,
+/// 5, 9, 15, ...), with the roots in crescent order. The greatest element is always at
+/// the end of the sequence, so it is removed and the heaps are fixed, like in heap sort.
+/// But in a sorted input nothing needs to be fixed, so it gets closer to linear time the
+/// more sorted the slice is.
+///
+/// This sort is unstable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::smooth(&mut v, &|v, b| v < b);
+/// ```
+pub fn smooth<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    smooth_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Smooth Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`smooth`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`smooth`]: ./fn.smooth.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::smooth_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn smooth_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    smooth_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Smooth Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`smooth`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`smooth`]: ./fn.smooth.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -6];
+/// // Crescent sorting by the absolute value
+/// sort::smooth_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, -6, 7, 8, -9]);
+/// ```
+pub fn smooth_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    smooth_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// The sequence of Leonardo heaps of the slice, from left to right.
+///
+/// Each heap is stored by its order `k`, so it has `L(k)` elements, the root is its last
+/// element, the right child heap has order `k - 2` and ends right before the root and the
+/// left child heap has order `k - 1` and ends right before the right one.
+struct Heaps {
+    leonardo: [usize; MAX_ORDERS],
+    orders:   [u8; MAX_ORDERS],
+    len:      usize,
+}
+
+impl Heaps {
+    fn new() -> Self {
+        let mut leonardo = [1_usize; MAX_ORDERS];
+        for k in 2..MAX_ORDERS {
+            leonardo[k] = leonardo[k - 1].saturating_add(leonardo[k - 2]).saturating_add(1);
+        }
+        Heaps { leonardo, orders: [0; MAX_ORDERS], len: 0 }
+    }
+
+    fn push(&mut self, order: usize) {
+        self.orders[self.len] = order as u8;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> usize {
+        self.len -= 1;
+        self.orders[self.len] as usize
+    }
+
+    fn order(&self, heap: usize) -> usize { self.orders[heap] as usize }
+
+    /// Returns the roots of the left and right child heaps of the heap rooted at `root`
+    /// with `order`, if it have children.
+    fn children(&self, root: usize, order: usize) -> Option<(usize, usize)> {
+        if order < 2 {
+            return None;
+        }
+        let right = root - 1;
+        Some((right - self.leonardo[order - 2], right))
+    }
+}
+
+fn smooth_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    if v.len() <= 1 {
+        return;
+    }
+    let mut heaps = Heaps::new();
+
+    // Add each element to the sequence of heaps.
+    for i in 0..v.len() {
+        let len = heaps.len;
+        if len >= 2 && heaps.order(len - 2) == heaps.order(len - 1) + 1 {
+            // The new element is the root of the two last heaps.
+            let order = heaps.order(len - 2) + 1;
+            heaps.len -= 2;
+            heaps.push(order);
+        } else if len >= 1 && heaps.order(len - 1) == 1 {
+            heaps.push(0);
+        } else {
+            heaps.push(1);
+        }
+        rectify(v, &heaps, heaps.len - 1, i, is_less);
+    }
+
+    // Remove the greatest element, the root of the last heap, exposing its children.
+    for root in (1..v.len()).rev() {
+        let order = heaps.pop();
+        if let Some((left, right)) = heaps.children(root, order) {
+            heaps.push(order - 1);
+            rectify(v, &heaps, heaps.len - 1, left, is_less);
+            heaps.push(order - 2);
+            rectify(v, &heaps, heaps.len - 1, right, is_less);
+        }
+    }
+}
+
+/// Moves the root of the `heap`-th heap, at `root`, to the left while the root of the
+/// previous heap is greater than it and its children, then sifts it down in the heap
+/// where it stopped.
+fn rectify<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], heaps: &Heaps, mut heap: usize, mut root: usize, is_less: &mut F,
+) {
+    while heap > 0 {
+        let order = heaps.order(heap);
+        let prev = root - heaps.leonardo[order];
+
+        let mut max = root;
+        if let Some((left, right)) = heaps.children(root, order) {
+            if is_less(&v[max], &v[left]) {
+                max = left;
+            }
+            if is_less(&v[max], &v[right]) {
+                max = right;
+            }
+        }
+        if !is_less(&v[max], &v[prev]) {
+            break;
+        }
+
+        v.swap(root, prev);
+        root = prev;
+        heap -= 1;
+    }
+
+    sift_down(v, root, heaps.order(heap), heaps, is_less);
+}
+
+/// Sifts down the root of the heap with `order` at `root`.
+fn sift_down<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], mut root: usize, mut order: usize, heaps: &Heaps, is_less: &mut F,
+) {
+    while let Some((left, right)) = heaps.children(root, order) {
+        let (child, child_order) =
+            if is_less(&v[left], &v[right]) { (right, order - 2) } else { (left, order - 1) };
+        if !is_less(&v[root], &v[child]) {
+            break;
+        }
+
+        v.swap(root, child);
+        root = child;
+        order = child_order;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn smooth_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        smooth(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn smooth_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        smooth(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn smooth_random_test() {
+        let mut rng = thread_rng();
+        for len in (0..50).chain([100, 1000, 5000].iter().copied()) {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v;
+            smooth_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn smooth_adaptive_test() {
+        // A sorted slice must need a linear number of comparisons.
+        let mut v: Vec<u32> = (0..10_000).collect();
+        let mut comparisons = 0;
+        smooth_sort(&mut v, &mut |a, b| {
+            comparisons += 1;
+            a < b
+        });
+        assert_eq!(v, (0..10_000).collect::<Vec<_>>());
+        assert!(comparisons < 10 * v.len(), "{} comparisons", comparisons);
+    }
+}
@@ -0,0 +1,154 @@
+//! Sortedness checks and sorted runs detection.
+
+use std::{cmp::Ordering, ops::Range};
+
+/// Returns `true` if `v` is sorted according to the way you define the `cmp` parameter.
+///
+/// That is, if no element must come before the element before it.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// assert!(sort::is_sorted(&[3, 5, 7, 7, 8, 9], &|v, b| v < b));
+/// assert!(!sort::is_sorted(&[9, 3, 5, 7, 8, 7], &|v, b| v < b));
+/// ```
+pub fn is_sorted<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> bool {
+    ascending_run_len(v, &mut |a, b| cmp(a, b)) == v.len()
+}
+
+/// Returns `true` if `v` is sorted according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// assert!(sort::is_sorted_by(&[9, 8, 7, 7, 5, 3], |a, b| b.cmp(a)));
+/// ```
+pub fn is_sorted_by<T, F: FnMut(&T, &T) -> Ordering>(v: &[T], mut compare: F) -> bool {
+    ascending_run_len(v, &mut |a, b| compare(a, b) == Ordering::Less) == v.len()
+}
+
+/// Returns `true` if `v` is sorted according to the key extracted by the `key` function.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// assert!(sort::is_sorted_by_key(&[3, -5, 7, -7, 8, -9], |a: &i32| a.abs()));
+/// ```
+pub fn is_sorted_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], mut key: F) -> bool {
+    ascending_run_len(v, &mut |a, b| key(a).lt(&key(b))) == v.len()
+}
+
+/// Returns the maximal sorted runs of `v`, according to the way you define the `cmp`
+/// parameter.
+///
+/// The runs are consecutive and cover the whole slice, and each one ends where the next
+/// element must come before the last element of the run. A sorted slice has a single
+/// run, so the number of runs is a measure of how sorted a slice is.
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [1, 4, 4, 2, 3, 0];
+/// assert_eq!(sort::runs(&v, &|v, b| v < b), [0..3, 3..5, 5..6]);
+/// ```
+pub fn runs<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &[T], cmp: &C) -> Vec<Range<usize>> {
+    find_runs(v, &mut |a, b| cmp(a, b))
+}
+
+/// Returns the maximal sorted runs of `v`, according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`runs`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`runs`]: ./fn.runs.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [1, 4, 4, 2, 3, 0];
+/// assert_eq!(sort::runs_by(&v, |a, b| b.cmp(a)), [0..1, 1..4, 4..6]);
+/// ```
+pub fn runs_by<T, F: FnMut(&T, &T) -> Ordering>(v: &[T], mut compare: F) -> Vec<Range<usize>> {
+    find_runs(v, &mut |a, b| compare(a, b) == Ordering::Less)
+}
+
+/// Returns the maximal sorted runs of `v`, according to the key extracted by the `key`
+/// function.
+///
+/// Same as [`runs`], but comparing the keys returned by `key`.
+///
+/// [`runs`]: ./fn.runs.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [-1, 4, -4, 2, -3, 0];
+/// assert_eq!(sort::runs_by_key(&v, |a: &i32| a.abs()), [0..3, 3..5, 5..6]);
+/// ```
+pub fn runs_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &[T], mut key: F) -> Vec<Range<usize>> {
+    find_runs(v, &mut |a, b| key(a).lt(&key(b)))
+}
+
+fn find_runs<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < v.len() {
+        let end = start + ascending_run_len(&v[start..], is_less);
+        runs.push(start..end);
+        start = end;
+    }
+    runs
+}
+
+/// Returns the length of the sorted run at the start of `v`.
+pub(super) fn ascending_run_len<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> usize {
+    if v.is_empty() {
+        return 0;
+    }
+    let mut end = 1;
+    while end < v.len() && !is_less(&v[end], &v[end - 1]) {
+        end += 1;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_sorted_test() {
+        assert!(is_sorted(&[3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99], &|a, b| a < b));
+        assert!(!is_sorted(&[9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12], &|a, b| a < b));
+        assert!(is_sorted::<i32, _>(&[], &|a, b| a < b));
+        assert!(is_sorted(&[1], &|a, b| a < b));
+    }
+
+    #[test]
+    fn is_sorted_floats_test() {
+        let v = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        assert!(is_sorted(&v, &|a, b| a < b));
+        assert!(!is_sorted(&v, &|a, b| a > b));
+    }
+
+    #[test]
+    fn runs_test() {
+        let v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+        assert_eq!(runs(&v, &|a, b| a < b), [0..1, 1..5, 5..7, 7..8, 8..9, 9..10, 10..11]);
+        assert_eq!(runs::<i32, _>(&[], &|a, b| a < b), []);
+
+        let equal = runs(&[2, 2, 2], &|a, b| a < b);
+        assert_eq!(equal.len(), 1);
+        assert_eq!(equal[0], 0..3);
+    }
+}
@@ -0,0 +1,261 @@
+//! Sorting algorithms as types, to choose them at compile time or at runtime.
+
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
+
+/// A sorting algorithm.
+///
+/// It is implemented by a zero-sized type for each comparison sorting algorithm of this
+/// module, like [`Quick`] and [`Merge`], so they can be used as generic parameters, and
+/// by [`Algorithm`], to choose the algorithm at runtime.
+///
+/// [`Quick`]: ./struct.Quick.html
+/// [`Merge`]: ./struct.Merge.html
+/// [`Algorithm`]: ./enum.Algorithm.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{Heap, Merge, Sorter};
+///
+/// fn sort_twice<S: Sorter>(sorter: S, a: &mut [i32], b: &mut [i32]) {
+///     sorter.sort(a);
+///     sorter.sort(b);
+/// }
+///
+/// let (mut a, mut b) = ([9, 3, 5], [7, 8, 7]);
+/// sort_twice(Heap, &mut a, &mut b);
+/// assert_eq!((a, b), ([3, 5, 9], [7, 7, 8]));
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// Merge.sort_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub trait Sorter {
+    /// Sorts `v` according to the [`Ordering`] returned by the `compare` function.
+    ///
+    /// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+    fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(&self, v: &mut [T], compare: F);
+
+    /// Sorts `v` in crescent order.
+    fn sort<T: Ord>(&self, v: &mut [T]) { self.sort_by(v, T::cmp) }
+
+    /// Sorts `v` according to the key extracted by the `key` function.
+    fn sort_by_key<T, K: Ord, F: FnMut(&T) -> K>(&self, v: &mut [T], mut key: F) {
+        self.sort_by(v, |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+macro_rules! sorters {
+    ($($sorter:ident => $by:ident, $name:literal, $title:literal, $stable:literal;)+) => {
+        $(
+        #[doc = concat!("**", $title, ":** The [`", $name, "`](./fn.", $name,
+                        ".html) sorting algorithm as a [`Sorter`](./trait.Sorter.html).")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $sorter;
+
+        impl Sorter for $sorter {
+            fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(&self, v: &mut [T], compare: F) {
+                super::$by(v, compare);
+            }
+        }
+        )+
+
+        /// The comparison sorting algorithms of this module, to choose one at runtime.
+        ///
+        /// It can be parsed from its [`name`], ignoring case and accepting `-` or spaces
+        /// in place of `_`.
+        ///
+        /// [`name`]: #method.name
+        ///
+        /// # Example
+        /// ```rust
+        /// use algos::sort::{Algorithm, Sorter};
+        ///
+        /// let algorithm: Algorithm = "Dual-Pivot Quick".parse().unwrap();
+        /// assert_eq!(algorithm, Algorithm::QuickDual);
+        ///
+        /// let mut v = [9, 3, 5, 7, 8, 7];
+        /// algorithm.sort(&mut v);
+        /// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Algorithm {
+            $(
+            #[doc = concat!("See [`", stringify!($sorter), "`](./struct.",
+                            stringify!($sorter), ".html).")]
+            $sorter,
+            )+
+        }
+
+        impl Algorithm {
+            /// Every algorithm.
+            pub const ALL: &'static [Algorithm] = &[$(Algorithm::$sorter,)+];
+
+            /// Returns the name of the algorithm, the same as the name of its sorting
+            /// function.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Algorithm::$sorter => $name,)+
+                }
+            }
+
+            /// Returns `true` if the algorithm keeps the order of equal elements.
+            pub fn is_stable(self) -> bool {
+                match self {
+                    $(Algorithm::$sorter => $stable,)+
+                }
+            }
+        }
+
+        impl Sorter for Algorithm {
+            fn sort_by<T, F: FnMut(&T, &T) -> Ordering>(&self, v: &mut [T], compare: F) {
+                match self {
+                    $(Algorithm::$sorter => $sorter.sort_by(v, compare),)+
+                }
+            }
+        }
+    };
+}
+
+sorters! {
+    Selection => selection_by, "selection", "Selection Sort", false;
+    Bubble => bubble_by, "bubble", "Bubble Sort", true;
+    Cocktail => cocktail_by, "cocktail", "Cocktail Sort", true;
+    Insertion => insection_by, "insection", "Insertion Sort", true;
+    Merge => merge_by, "merge", "Merge Sort", true;
+    Quick => quick_by, "quick", "Quick Sort", false;
+    Heap => heap_by, "heap", "Heap Sort", false;
+    Tim => tim_by, "tim", "Tim Sort", true;
+    Intro => intro_by, "intro", "Intro Sort", false;
+    Shell => shell_by, "shell", "Shell Sort", false;
+    Comb => comb_by, "comb", "Comb Sort", false;
+    Cycle => cycle_by, "cycle", "Cycle Sort", false;
+    Pancake => pancake_by, "pancake", "Pancake Sort", false;
+    Gnome => gnome_by, "gnome", "Gnome Sort", true;
+    OddEven => odd_even_by, "odd_even", "Odd-Even Sort", true;
+    Bitonic => bitonic_by, "bitonic", "Bitonic Sort", false;
+    Smooth => smooth_by, "smooth", "Smooth Sort", false;
+    Patience => patience_by, "patience", "Patience Sort", false;
+    Tree => tree_by, "tree", "Tree Sort", true;
+    Quick3 => quick3_by, "quick3", "3-Way Quick Sort", false;
+    QuickDual => quick_dual_by, "quick_dual", "Dual-Pivot Quick Sort", false;
+    BlockMerge => block_merge_by, "block_merge", "Block Merge Sort", true;
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.name()) }
+}
+
+impl FromStr for Algorithm {
+    type Err = ParseAlgorithmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .trim()
+            .chars()
+            .map(|c| if c == '-' || c == ' ' { '_' } else { c.to_ascii_lowercase() })
+            .collect();
+        // Also accept the names from the README, like "Dual-Pivot Quick Sort".
+        let name = name.trim_end_matches("_sort");
+        let name = match name {
+            "insertion" => "insection",
+            "3_way_quick" => "quick3",
+            "dual_pivot_quick" => "quick_dual",
+            _ => name,
+        };
+
+        Algorithm::ALL
+            .iter()
+            .copied()
+            .find(|algorithm| algorithm.name() == name)
+            .ok_or_else(|| ParseAlgorithmError { name: s.to_string() })
+    }
+}
+
+/// The error returned when parsing an unknown [`Algorithm`].
+///
+/// [`Algorithm`]: ./enum.Algorithm.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAlgorithmError {
+    name: String,
+}
+
+impl fmt::Display for ParseAlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown sorting algorithm: {:?}", self.name)
+    }
+}
+
+impl Error for ParseAlgorithmError {}
+
+/// Sort `v` slice with the chosen `algorithm`, according to the way you define the `cmp`
+/// parameter.
+///
+/// # Example
+/// ```rust
+/// use algos::sort::{self, Algorithm};
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::by_algorithm(Algorithm::Smooth, &mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn by_algorithm<T: PartialOrd, C: Fn(&T, &T) -> bool>(
+    algorithm: Algorithm, v: &mut [T], cmp: &C,
+) {
+    // The algorithms only check if the result is `Less`.
+    algorithm.sort_by(v, |a, b| if cmp(a, b) { Ordering::Less } else { Ordering::Greater });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn by_algorithm_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        for &algorithm in Algorithm::ALL {
+            let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+            by_algorithm(algorithm, &mut v, &|a, b| a < b);
+            assert_eq!(v, p, "{}", algorithm);
+        }
+    }
+
+    #[test]
+    fn by_algorithm_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        for &algorithm in Algorithm::ALL {
+            let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+            by_algorithm(algorithm, &mut v, &|a, b| a < b);
+            assert_eq!(v, p, "{}", algorithm);
+        }
+    }
+
+    #[test]
+    fn stable_algorithms_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u32, usize)> = (0..500).map(|i| (rng.gen_range(0..10), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        for algorithm in Algorithm::ALL.iter().filter(|a| a.is_stable()) {
+            let mut t = v.clone();
+            algorithm.sort_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p, "{}", algorithm);
+        }
+    }
+
+    #[test]
+    fn parse_test() {
+        for &algorithm in Algorithm::ALL {
+            assert_eq!(algorithm.name().parse(), Ok(algorithm));
+        }
+        assert_eq!("Insertion Sort".parse(), Ok(Algorithm::Insertion));
+        assert_eq!("odd-even".parse(), Ok(Algorithm::OddEven));
+        assert_eq!("3-Way Quick Sort".parse(), Ok(Algorithm::Quick3));
+        assert_eq!(" BLOCK_MERGE ".parse(), Ok(Algorithm::BlockMerge));
+        assert!("bogo".parse::<Algorithm>().is_err());
+    }
+}
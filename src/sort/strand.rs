@@ -0,0 +1,172 @@
+//! Strand sort implementation.
+
+use std::cmp::Ordering;
+
+use super::{counting::permute, kway::kway_merge_iter};
+
+/// **Strand Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It repeatedly pulls a "strand" out of the remaining elements: the first one and then
+/// every following element that is not less than the last one pulled, so each strand is
+/// sorted. The strands are then merged with a [k-way merge]. The number of strands is the
+/// length of the longest strictly decrescent subsequence, so it is fast for slices that
+/// are almost in crescent order.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n√n)          |                  |
+/// | Worst:    | O(n²)           | O(n)             |
+///
+/// **Obs.:** The time complexity is O(nk), where k is the number of strands, that is
+/// about 2√n for random slices.
+///
+/// [k-way merge]: ./fn.kway_merge_iter.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::strand(&mut v, &|v, b| v < b);
+/// assert_eq!(v, [3, 5, 7, 7, 8, 9]);
+/// ```
+pub fn strand<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    strand_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Strand Sort:** Sort `v` slice according to the [`Ordering`] returned by the
+/// `compare` function.
+///
+/// Same as [`strand`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`strand`]: ./fn.strand.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::strand_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn strand_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    strand_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Strand Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`strand`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`strand`]: ./fn.strand.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::strand_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn strand_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    strand_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn strand_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let strands = strands(v, is_less);
+    let slices: Vec<&[usize]> = strands.iter().map(|s| &s[..]).collect();
+    let order: Vec<usize> = kway_merge_iter(&slices, |&a, &b| {
+        if is_less(&v[a], &v[b]) {
+            Ordering::Less
+        } else if is_less(&v[b], &v[a]) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+    .copied()
+    .collect();
+
+    let mut dest = vec![0; v.len()];
+    for (pos, &i) in order.iter().enumerate() {
+        dest[i] = pos;
+    }
+    permute(v, &mut dest);
+}
+
+/// Pulls the strands out of `v`, each one with the indexes of its elements in order.
+///
+/// An element left behind is less than the last one of the strand, and so are the equal
+/// elements after it, so equal elements are in the strands in their original order.
+fn strands<T, F: FnMut(&T, &T) -> bool>(v: &[T], is_less: &mut F) -> Vec<Vec<usize>> {
+    let mut strands = Vec::new();
+    let mut rest: Vec<usize> = (0..v.len()).collect();
+
+    while let Some((&first, others)) = rest.split_first() {
+        let mut strand = vec![first];
+        let mut left = Vec::with_capacity(others.len());
+        for &i in others {
+            if is_less(&v[i], &v[strand[strand.len() - 1]]) {
+                left.push(i);
+            } else {
+                strand.push(i);
+            }
+        }
+        strands.push(strand);
+        rest = left;
+    }
+
+    strands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn strand_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        strand(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn strand_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        strand(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn strand_stable_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 10, 100, 1000] {
+            let mut v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            strand_by_key(&mut v, |x| x.0);
+            assert_eq!(v, p);
+        }
+    }
+
+    #[test]
+    fn strands_test() {
+        let v = [3, 1, 4, 1, 5, 9, 2, 6];
+        let is_less = &mut |a: &i32, b: &i32| a < b;
+        assert_eq!(strands(&v, is_less), [vec![0, 2, 4, 5], vec![1, 3, 6, 7]]);
+        assert!(strands(&[] as &[i32], is_less).is_empty());
+    }
+}
@@ -0,0 +1,420 @@
+//! TimSort implementation.
+
+use std::{cmp::Ordering, mem, ptr, slice};
+
+use super::MergeHole;
+
+/// Slices shorter than this are sorted only with binary insertion sort.
+const MIN_MERGE: usize = 64;
+
+/// Initial number of consecutive wins of a run needed to enter galloping mode.
+const MIN_GALLOP: usize = 7;
+
+/// **Tim Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It finds the natural runs of the slice (reversing the strictly descending ones),
+/// extends the short runs to a minimum length with binary insertion sort and then merges
+/// the runs, galloping when one of the runs keeps winning. So partially sorted data is
+/// sorted in near-linear time.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::tim(&mut v, &|v, b| v < b);
+/// ```
+pub fn tim<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    tim_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Tim Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`tim`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`tim`]: ./fn.tim.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::tim_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn tim_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    tim_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Tim Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`tim`], but comparing the keys returned by `key`, like `slice::sort_by_key`.
+///
+/// [`tim`]: ./fn.tim.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::tim_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn tim_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    tim_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+/// A sorted run in the slice being sorted.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    len:   usize,
+}
+
+pub(super) fn tim_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    let len = v.len();
+    // Zero-sized types have nothing to be sorted.
+    if len <= 1 || mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    if len < MIN_MERGE {
+        let run_len = count_run(v, is_less);
+        binary_insertion(v, run_len, is_less);
+        return;
+    }
+
+    // Auxiliar buffer, it is never bigger than `len` since only the left run is moved into
+    // it.
+    let mut buf: Vec<T> = Vec::with_capacity(len);
+    let mut runs: Vec<Run> = Vec::new();
+    let mut min_gallop = MIN_GALLOP;
+    let min_run = min_run_length(len);
+
+    let mut start = 0;
+    while start < len {
+        let mut run_len = count_run(&mut v[start..], is_less);
+
+        // Extend short runs to `min_run` elements.
+        if run_len < min_run {
+            let force = min_run.min(len - start);
+            binary_insertion(&mut v[start..start + force], run_len, is_less);
+            run_len = force;
+        }
+
+        runs.push(Run { start, len: run_len });
+        start += run_len;
+
+        while let Some(r) = collapse(&runs) {
+            merge_at(v, &mut runs, r, &mut buf, &mut min_gallop, is_less);
+        }
+    }
+
+    while runs.len() > 1 {
+        let r = runs.len() - 2;
+        merge_at(v, &mut runs, r, &mut buf, &mut min_gallop, is_less);
+    }
+}
+
+/// Computes the minimum run length, so that `len / min_run` is equal or slightly smaller
+/// than a power of two.
+fn min_run_length(mut len: usize) -> usize {
+    let mut r = 0;
+    while len >= MIN_MERGE {
+        r |= len & 1;
+        len >>= 1;
+    }
+    len + r
+}
+
+/// Returns the length of the run at the start of `v`, reversing it if it is strictly
+/// descending.
+pub(super) fn count_run<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) -> usize {
+    let len = v.len();
+    if len <= 1 {
+        return len;
+    }
+
+    let mut end = 2;
+    if is_less(&v[1], &v[0]) {
+        while end < len && is_less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+        v[..end].reverse();
+    } else {
+        while end < len && !is_less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// Sorts `v` knowing that `v[..sorted]` is already sorted, inserting each element after
+/// it in the position found by binary search.
+fn binary_insertion<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], sorted: usize, is_less: &mut F) {
+    for i in sorted.max(1)..v.len() {
+        let (head, tail) = v.split_at(i);
+        let pos = gallop_right(&tail[0], head, is_less);
+        v[pos..=i].rotate_right(1);
+    }
+}
+
+/// Returns the index of the run that must be merged with the next one to keep the run
+/// stack invariants, or `None` if they already hold.
+fn collapse(runs: &[Run]) -> Option<usize> {
+    let n = runs.len();
+    if n >= 2
+        && (runs[n - 2].len <= runs[n - 1].len
+            || (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
+            || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len))
+    {
+        if n >= 3 && runs[n - 3].len < runs[n - 1].len { Some(n - 3) } else { Some(n - 2) }
+    } else {
+        None
+    }
+}
+
+/// Merges the runs at `r` and `r + 1`.
+fn merge_at<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], runs: &mut Vec<Run>, r: usize, buf: &mut Vec<T>, min_gallop: &mut usize,
+    is_less: &mut F,
+) {
+    let (left, right) = (runs[r], runs[r + 1]);
+    runs[r].len += right.len;
+    runs.remove(r + 1);
+
+    let v = &mut v[left.start..right.start + right.len];
+    let mid = left.len;
+
+    // Elements of the left run smaller or equal to the first of the right run are already
+    // in place.
+    let start = gallop_right(&v[mid], &v[..mid], is_less);
+    if start == mid {
+        return;
+    }
+
+    // Elements of the right run greater than the last of the left run are already in
+    // place.
+    let end = mid + gallop_left(&v[mid - 1], &v[mid..], is_less);
+    if end == mid {
+        return;
+    }
+
+    merge_lo(&mut v[start..end], mid - start, buf, min_gallop, is_less);
+}
+
+/// Merges the sorted runs `v[..mid]` and `v[mid..]`, moving the left one into `buf` and
+/// galloping when one of the runs wins `min_gallop` times in a row.
+fn merge_lo<T, F: FnMut(&T, &T) -> bool>(
+    v: &mut [T], mid: usize, buf: &mut Vec<T>, min_gallop: &mut usize, is_less: &mut F,
+) {
+    let len = v.len();
+    debug_assert!(mid <= buf.capacity());
+
+    // SAFETY: Same as in `super::combine`: the left run is moved into `buf` and `hole`
+    // keeps the remaining elements of `buf` and the gap in `v` with the same size,
+    // filling the gap back when dropped, even if `is_less` panics.
+    unsafe {
+        let v_ptr = v.as_mut_ptr();
+        let buf_ptr = buf.as_mut_ptr();
+        ptr::copy_nonoverlapping(v_ptr, buf_ptr, mid);
+
+        let mut hole = MergeHole { start: buf_ptr, end: buf_ptr.add(mid), dest: v_ptr };
+        let mut right = v_ptr.add(mid);
+        let right_end = v_ptr.add(len);
+
+        'outer: while hole.start < hole.end && right < right_end {
+            // One element at a time mode.
+            let (mut left_wins, mut right_wins) = (0, 0);
+            while left_wins < *min_gallop && right_wins < *min_gallop {
+                if is_less(&*right, &*hole.start) {
+                    ptr::copy_nonoverlapping(right, hole.dest, 1);
+                    right = right.add(1);
+                    right_wins += 1;
+                    left_wins = 0;
+                } else {
+                    ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                    hole.start = hole.start.add(1);
+                    left_wins += 1;
+                    right_wins = 0;
+                }
+                hole.dest = hole.dest.add(1);
+
+                if hole.start == hole.end || right == right_end {
+                    break 'outer;
+                }
+            }
+
+            // Galloping mode.
+            loop {
+                let left_len = hole.end.offset_from(hole.start) as usize;
+                let left_run = slice::from_raw_parts(hole.start, left_len);
+                let count_left = gallop_right(&*right, left_run, is_less);
+                ptr::copy_nonoverlapping(hole.start, hole.dest, count_left);
+                hole.start = hole.start.add(count_left);
+                hole.dest = hole.dest.add(count_left);
+                if hole.start == hole.end {
+                    break 'outer;
+                }
+
+                let right_len = right_end.offset_from(right) as usize;
+                let right_run = slice::from_raw_parts(right, right_len);
+                let count_right = gallop_left(&*hole.start, right_run, is_less);
+                ptr::copy(right, hole.dest, count_right);
+                right = right.add(count_right);
+                hole.dest = hole.dest.add(count_right);
+                if right == right_end {
+                    break 'outer;
+                }
+
+                if count_left < MIN_GALLOP && count_right < MIN_GALLOP {
+                    *min_gallop += 1;
+                    break;
+                }
+                *min_gallop = min_gallop.saturating_sub(1).max(1);
+            }
+        }
+        // If the right run is over, `hole` moves the rest of the left run when dropped,
+        // otherwise the rest of the right run is already in place.
+    }
+}
+
+/// Returns how many elements of the sorted slice `v` are strictly smaller than `key`,
+/// using exponential search followed by a binary search.
+pub(super) fn gallop_left<T, F: FnMut(&T, &T) -> bool>(key: &T, v: &[T], is_less: &mut F) -> usize {
+    let (mut last, mut ofs) = (0, 1);
+    while ofs <= v.len() && is_less(&v[ofs - 1], key) {
+        last = ofs;
+        ofs = ofs.saturating_mul(2);
+    }
+
+    let (mut lo, mut hi) = (last, if ofs <= v.len() { ofs - 1 } else { v.len() });
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(&v[mid], key) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Returns how many elements of the sorted slice `v` are smaller or equal to `key`, using
+/// exponential search followed by a binary search.
+pub(super) fn gallop_right<T, F: FnMut(&T, &T) -> bool>(
+    key: &T, v: &[T], is_less: &mut F,
+) -> usize {
+    let (mut last, mut ofs) = (0, 1);
+    while ofs <= v.len() && !is_less(key, &v[ofs - 1]) {
+        last = ofs;
+        ofs = ofs.saturating_mul(2);
+    }
+
+    let (mut lo, mut hi) = (last, if ofs <= v.len() { ofs - 1 } else { v.len() });
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if is_less(key, &v[mid]) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn tim_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        tim(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn tim_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        tim(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn tim_random_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 63, 64, 65, 100, 1000, 5000] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            let mut t = v.clone();
+            tim_by(&mut t, |a, b| a.cmp(b));
+            assert_eq!(t, p);
+        }
+    }
+
+    #[test]
+    fn tim_runs_test() {
+        // Ascending and descending runs of random sizes, so it gallops.
+        let mut rng = thread_rng();
+        let mut v: Vec<i32> = Vec::new();
+        while v.len() < 10_000 {
+            let run_len = rng.gen_range(1..500);
+            let start = rng.gen_range(-1000..1000);
+            if rng.gen() {
+                v.extend(start..start + run_len);
+            } else {
+                v.extend((start..start + run_len).rev());
+            }
+        }
+        let mut p = v.clone();
+        p.sort();
+
+        tim(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn tim_stable_test() {
+        let mut rng = thread_rng();
+        let v: Vec<(u8, usize)> = (0..2000).map(|i| (rng.gen_range(0..10), i)).collect();
+        let mut p = v.clone();
+        p.sort_by_key(|x| x.0);
+
+        let mut t = v;
+        tim_by_key(&mut t, |x| x.0);
+        assert_eq!(t, p);
+    }
+
+    #[test]
+    fn tim_strings_test() {
+        let mut rng = thread_rng();
+        let v: Vec<String> = (0..300).map(|_| rng.gen_range(0..1000).to_string()).collect();
+        let mut p = v.clone();
+        p.sort();
+
+        let mut t = v;
+        tim(&mut t, &|a, b| a < b);
+        assert_eq!(t, p);
+    }
+}
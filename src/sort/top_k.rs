@@ -0,0 +1,142 @@
+//! Top-k selection implementation.
+
+use std::cmp::Ordering;
+
+use super::{heap_sort, heapify};
+
+/// **Top-k:** Returns the first `k` elements of `v` in the order you define with the
+/// `cmp` parameter, sorted, without changing `v`.
+///
+/// It keeps the best `k` elements seen so far in a max-heap, where the root is the worst
+/// of them, replacing it every time a better element is found. Only the elements that
+/// enter the heap are cloned. If `k` is not smaller than the `v` size, every element is
+/// returned.
+///
+/// It is not stable, ties with the `k`-th element may return any of the tied elements.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(nlog(k))      |                  |
+/// | Worst:    | O(nlog(k))      | O(k)             |
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let scores = [9, 3, 5, 7, 8, 7];
+/// // The 3 greatest
+/// assert_eq!(sort::top_k(&scores, 3, &|a, b| a > b), [9, 8, 7]);
+/// // The 2 least
+/// assert_eq!(sort::top_k(&scores, 2, &|a, b| a < b), [3, 5]);
+/// ```
+pub fn top_k<T, C>(v: &[T], k: usize, cmp: &C) -> Vec<T>
+where
+    T: PartialOrd + Clone,
+    C: Fn(&T, &T) -> bool,
+{
+    top_k_heap(v, k, &mut |a, b| cmp(a, b))
+}
+
+/// **Top-k:** Returns the first `k` elements of `v` in the order of the [`Ordering`]
+/// returned by the `compare` function, sorted, without changing `v`.
+///
+/// Same as [`top_k`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`top_k`]: ./fn.top_k.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let v = [9, 3, 5, 7, 8, 7];
+/// // The 3 greatest
+/// assert_eq!(sort::top_k_by(&v, 3, |a, b| b.cmp(a)), [9, 8, 7]);
+/// ```
+pub fn top_k_by<T: Clone, F: FnMut(&T, &T) -> Ordering>(
+    v: &[T], k: usize, mut compare: F,
+) -> Vec<T> {
+    top_k_heap(v, k, &mut |a, b| compare(a, b) == Ordering::Less)
+}
+
+/// **Top-k:** Returns the `k` elements of `v` with the least keys extracted by the `key`
+/// function, sorted, without changing `v`.
+///
+/// Same as [`top_k`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`top_k`]: ./fn.top_k.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let points = [(3, 4), (1, 1), (0, 2), (5, 5)];
+/// // The 2 nearest to the origin
+/// let nearest = sort::top_k_by_key(&points, 2, |&(x, y): &(i32, i32)| x * x + y * y);
+/// assert_eq!(nearest, [(1, 1), (0, 2)]);
+/// ```
+pub fn top_k_by_key<T: Clone, K: Ord, F: FnMut(&T) -> K>(v: &[T], k: usize, mut key: F) -> Vec<T> {
+    top_k_heap(v, k, &mut |a, b| key(a).lt(&key(b)))
+}
+
+fn top_k_heap<T: Clone, F: FnMut(&T, &T) -> bool>(v: &[T], k: usize, is_less: &mut F) -> Vec<T> {
+    let k = k.min(v.len());
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // Max-heap with the `k` least elements seen so far.
+    let mut heap: Vec<T> = v[..k].to_vec();
+    for node in (0..k / 2).rev() {
+        heapify(&mut heap, is_less, node);
+    }
+    for x in &v[k..] {
+        if is_less(x, &heap[0]) {
+            heap[0] = x.clone();
+            heapify(&mut heap, is_less, 0);
+        }
+    }
+
+    heap_sort(&mut heap, is_less);
+    heap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn top_k_test() {
+        let v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        assert_eq!(top_k(&v, 4, &|a, b| a < b), [3, 5, 7, 7]);
+        assert_eq!(top_k(&v, 4, &|a, b| a > b), [99, 30, 23, 15]);
+        assert_eq!(top_k(&v, 0, &|a, b| a < b), []);
+        assert_eq!(top_k(&v, 20, &|a, b| a < b), [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99]);
+    }
+
+    #[test]
+    fn top_k_floats_test() {
+        let v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        assert_eq!(top_k(&v, 3, &|a, b| a > b), [99.0, 30.9, 23.8]);
+    }
+
+    #[test]
+    fn top_k_random_test() {
+        let mut rng = thread_rng();
+        for &(len, max) in &[(0, 1), (1, 1), (100, 5), (10_000, 1_000_000)] {
+            let v: Vec<u32> = (0..len).map(|_| rng.gen_range(0..max)).collect();
+            let mut p = v.clone();
+            p.sort();
+
+            for &k in &[0, 1, 10, len / 2, len] {
+                let k = k.min(len);
+                assert_eq!(top_k_by(&v, k, |a, b| a.cmp(b)), p[..k]);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Tree sort implementation.
+
+use std::cmp::Ordering;
+
+use super::counting::permute;
+use crate::data_structures::AvlTree;
+
+/// **Tree Sort:** Sort `v` slice according to the way you define the `cmp` parameter.
+///
+/// It inserts the elements into a self-balancing binary search tree (an [`AvlTree`]) and
+/// writes them back in order. Equal elements are inserted after each other, so it keeps
+/// their order.
+///
+/// This sort is stable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nlog(n))      |                  |
+/// | Avrg:     | Θ(nlog(n))      |                  |
+/// | Worst:    | O(nlog(n))      | O(n)             |
+///
+/// [`AvlTree`]: ../data_structures/avl/struct.AvlTree.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Crescent sorting
+/// sort::tree(&mut v, &|v, b| v < b);
+/// ```
+pub fn tree<T: PartialOrd, C: Fn(&T, &T) -> bool>(v: &mut [T], cmp: &C) {
+    tree_sort(v, &mut |a, b| cmp(a, b));
+}
+
+/// **Tree Sort:** Sort `v` slice according to the [`Ordering`] returned by the `compare`
+/// function.
+///
+/// Same as [`tree`], but with a three-way comparator.
+///
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+/// [`tree`]: ./fn.tree.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [9, 3, 5, 7, 8, 7];
+/// // Decrescent sorting
+/// sort::tree_by(&mut v, |a, b| b.cmp(a));
+/// assert_eq!(v, [9, 8, 7, 7, 5, 3]);
+/// ```
+pub fn tree_by<T, F: FnMut(&T, &T) -> Ordering>(v: &mut [T], mut compare: F) {
+    tree_sort(v, &mut |a, b| compare(a, b) == Ordering::Less);
+}
+
+/// **Tree Sort:** Sort `v` slice according to the key extracted by the `key` function.
+///
+/// Same as [`tree`], but comparing the keys returned by `key`, like
+/// `slice::sort_by_key`.
+///
+/// [`tree`]: ./fn.tree.html
+///
+/// # Example
+/// ```rust
+/// use algos::sort;
+///
+/// let mut v = [-9, 3, -5, 7, 8, -7];
+/// // Crescent sorting by the absolute value
+/// sort::tree_by_key(&mut v, |a: &i32| a.abs());
+/// assert_eq!(v, [3, -5, 7, -7, 8, -9]);
+/// ```
+pub fn tree_by_key<T, K: Ord, F: FnMut(&T) -> K>(v: &mut [T], mut key: F) {
+    tree_sort(v, &mut |a, b| key(a).lt(&key(b)));
+}
+
+fn tree_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+    // The tree holds the indexes, so the elements are only moved once.
+    let mut tree = AvlTree::new();
+    for i in 0..v.len() {
+        tree.insert_by(
+            i,
+            |&a, &b| if is_less(&v[a], &v[b]) { Ordering::Less } else { Ordering::Greater },
+        );
+    }
+
+    let mut dest = vec![0; v.len()];
+    for (pos, i) in tree.into_sorted_vec().into_iter().enumerate() {
+        dest[i] = pos;
+    }
+    permute(v, &mut dest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn tree_test() {
+        let p = [3, 5, 7, 7, 8, 9, 12, 15, 23, 30, 99];
+        let mut v = [9, 3, 5, 7, 8, 7, 99, 30, 23, 15, 12];
+
+        tree(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn tree_floats_test() {
+        let p = [3.1, 5.2, 7.3, 7.3, 8.4, 9.5, 12.6, 15.7, 23.8, 30.9, 99.0];
+        let mut v = [9.5, 3.1, 5.2, 7.3, 8.4, 7.3, 99.0, 30.9, 23.8, 15.7, 12.6];
+
+        tree(&mut v, &|a, b| a < b);
+        assert_eq!(v, p);
+    }
+
+    #[test]
+    fn tree_stable_test() {
+        let mut rng = thread_rng();
+        for &len in &[0, 1, 2, 100, 1000] {
+            let v: Vec<(u32, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+            let mut p = v.clone();
+            p.sort_by_key(|x| x.0);
+
+            let mut t = v;
+            tree_by_key(&mut t, |x| x.0);
+            assert_eq!(t, p);
+        }
+    }
+}
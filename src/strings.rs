@@ -0,0 +1,22 @@
+//! Module for string algorithms that are not about searching a pattern, like edit
+//! distances.
+
+pub mod bwt;
+pub mod diff;
+pub mod distance;
+pub mod lcs;
+pub mod lyndon;
+pub mod ngram;
+pub mod palindrome;
+pub mod period;
+pub mod phonetic;
+pub mod rotation;
+pub mod similarity;
+pub mod substring;
+pub mod suffix_array;
+pub mod suffix_automaton;
+pub mod suffix_tree;
+
+mod rolling_hash;
+
+pub use rolling_hash::RollingHash;
@@ -0,0 +1,246 @@
+//! Burrows-Wheeler transform module.
+//!
+//! The transform groups equal bytes of a slice that come before equal contexts, so the
+//! output has long runs of equal bytes, which the move-to-front encoding turns into runs
+//! of zeros, to be compressed.
+
+use std::cmp::Ordering;
+
+use super::suffix_array::suffix_array;
+
+/// **Burrows-Wheeler Transform:** Returns the transform of `text`, with the index of the
+/// end of `text` in it.
+///
+/// The transform is the last column of the sorted rotations of `text` with a sentinel at
+/// the end that is smaller than any byte, without the sentinel, and the index is the row
+/// where the sentinel was. So the rows are the suffixes of `text` in order, after the
+/// empty one, which are sorted with the [`suffix_array`].
+///
+/// [`suffix_array`]: ../suffix_array/fn.suffix_array.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::bwt::{bwt, ibwt};
+///
+/// let (last, index) = bwt(b"banana");
+/// assert_eq!(last, b"annbaa");
+/// assert_eq!(index, 4);
+/// assert_eq!(ibwt(&last, index), b"banana");
+/// ```
+pub fn bwt(text: &[u8]) -> (Vec<u8>, usize) {
+    let n = text.len();
+    let mut last = Vec::with_capacity(n);
+    let mut index = 0;
+    // The first row is the empty suffix, that comes after the last byte.
+    if let Some(&c) = text.last() {
+        last.push(c);
+    }
+    for (row, i) in suffix_array(text).into_iter().enumerate() {
+        match i.checked_sub(1) {
+            Some(i) => last.push(text[i]),
+            None => index = row + 1,
+        }
+    }
+    (last, index)
+}
+
+/// **Inverse Burrows-Wheeler Transform:** Returns the slice that has `last` as its
+/// [`bwt`], with the end of the slice at `index`.
+///
+/// It uses the LF mapping: the occurrences of each byte are in the same order in the
+/// first and the last column of the rows, so the row that starts with the byte at the last
+/// column of a row is known, and it goes through the rows backwards from the first one.
+///
+/// [`bwt`]: ./fn.bwt.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Panics
+/// It panics if `index` is greater than the size of `last`, and if `index` is `0` while
+/// `last` is not empty, as no transform has that index.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::bwt::ibwt;
+///
+/// assert_eq!(ibwt(b"annbaa", 4), b"banana");
+/// ```
+pub fn ibwt(last: &[u8], index: usize) -> Vec<u8> {
+    let n = last.len();
+    assert!(index <= n, "index out of bounds");
+    assert!(index > 0 || n == 0, "the first row can not be the end of the slice");
+
+    // The byte at the last column of each row, `None` for the sentinel.
+    let byte = |row: usize| match row.cmp(&index) {
+        Ordering::Less => Some(last[row]),
+        Ordering::Equal => None,
+        Ordering::Greater => Some(last[row - 1]),
+    };
+
+    // Where the rows that start with each byte start, after the one of the sentinel.
+    let mut start = [0; 256];
+    for &c in last {
+        start[c as usize] += 1;
+    }
+    let mut sum = 1;
+    for count in start.iter_mut() {
+        let c = *count;
+        *count = sum;
+        sum += c;
+    }
+
+    // The row that starts with the byte at the last column of each row.
+    let mut lf = vec![0; n + 1];
+    for (row, lf) in lf.iter_mut().enumerate() {
+        if let Some(c) = byte(row) {
+            *lf = start[c as usize];
+            start[c as usize] += 1;
+        }
+    }
+
+    let mut text = vec![0; n];
+    let mut row = 0;
+    for k in (0..n).rev() {
+        text[k] = byte(row).unwrap_or_default();
+        row = lf[row];
+    }
+    text
+}
+
+/// **Move-To-Front Encoding:** Returns the index of each byte of `text` in a list of all
+/// the bytes, that starts in crescent order, where each byte moves to the front after it
+/// is used.
+///
+/// So a run of equal bytes becomes a run of zeros after the first one.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::bwt::{mtf_decode, mtf_encode};
+///
+/// let encoded = mtf_encode(b"aaabbbaaa");
+/// assert_eq!(encoded, [97, 0, 0, 98, 0, 0, 1, 0, 0]);
+/// assert_eq!(mtf_decode(&encoded), b"aaabbbaaa");
+/// ```
+pub fn mtf_encode(text: &[u8]) -> Vec<u8> {
+    let mut list = initial_list();
+    text.iter()
+        .map(|&c| {
+            let i = list.iter().position(|&x| x == c).unwrap_or(0);
+            list[..=i].rotate_right(1);
+            i as u8
+        })
+        .collect()
+}
+
+/// **Move-To-Front Decoding:** Returns the slice that has `encoded` as its
+/// [`mtf_encode`].
+///
+/// [`mtf_encode`]: ./fn.mtf_encode.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::bwt::mtf_decode;
+///
+/// assert_eq!(mtf_decode(&[98, 99, 0, 1]), b"bccb");
+/// ```
+pub fn mtf_decode(encoded: &[u8]) -> Vec<u8> {
+    let mut list = initial_list();
+    encoded
+        .iter()
+        .map(|&i| {
+            let i = i as usize;
+            let c = list[i];
+            list[..=i].rotate_right(1);
+            c
+        })
+        .collect()
+}
+
+/// Returns all the bytes in crescent order.
+fn initial_list() -> [u8; 256] {
+    let mut list = [0; 256];
+    for (i, x) in list.iter_mut().enumerate() {
+        *x = i as u8;
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Returns the transform by sorting the rotations with the sentinel.
+    fn naive(text: &[u8]) -> (Vec<u8>, usize) {
+        let with_end: Vec<Option<u8>> = text.iter().map(|&c| Some(c)).chain(Some(None)).collect();
+        let n = with_end.len();
+        let rotation = |i: usize| -> Vec<Option<u8>> {
+            with_end[i..].iter().chain(&with_end[..i]).copied().collect()
+        };
+        let mut rows: Vec<usize> = (0..n).collect();
+        rows.sort_by_key(|&i| rotation(i));
+        let last: Vec<Option<u8>> = rows.iter().map(|&i| with_end[(i + n - 1) % n]).collect();
+        let index = last.iter().position(Option::is_none).unwrap_or(0);
+        (last.into_iter().flatten().collect(), index)
+    }
+
+    #[test]
+    fn bwt_test() {
+        assert_eq!(bwt(b""), (vec![], 0));
+        assert_eq!(bwt(b"a"), (b"a".to_vec(), 1));
+        assert_eq!(ibwt(b"", 0), b"");
+        assert_eq!(ibwt(b"a", 1), b"a");
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..100)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let (last, index) = bwt(&text);
+            assert_eq!((last.clone(), index), naive(&text));
+            assert_eq!(ibwt(&last, index), text);
+        }
+
+        let text: Vec<u8> = (0..10_000).map(|_| rng.gen()).collect();
+        let (last, index) = bwt(&text);
+        assert_eq!(ibwt(&last, index), text);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ibwt_out_of_bounds() { ibwt(b"abc", 4); }
+
+    #[test]
+    fn mtf_test() {
+        assert_eq!(mtf_encode(b""), []);
+        assert_eq!(mtf_encode(b"\0\0\x01"), [0, 0, 1]);
+        assert_eq!(mtf_encode(b"\x02\x01\x02"), [2, 2, 1]);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> = (0..rng.gen_range(0..200)).map(|_| rng.gen()).collect();
+            assert_eq!(mtf_decode(&mtf_encode(&text)), text);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Diff module.
+//!
+//! A diff of two slices is an edit script: the elements of the first slice to keep or to
+//! delete, and the elements of the second slice to insert, that turn the first slice into
+//! the second.
+
+/// An operation of an edit script, with the element it refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edit<'a, T> {
+    /// The element is in both slices.
+    Keep(&'a T),
+    /// The element of the second slice is inserted.
+    Insert(&'a T),
+    /// The element of the first slice is deleted.
+    Delete(&'a T),
+}
+
+/// **Myers' Algorithm:** Returns the shortest edit script that turns `a` into `b`.
+///
+/// It searches for the path with fewer insertions and deletions in the edit graph, where
+/// each diagonal move is an element kept, so it finds, for each number of edits `d`, the
+/// furthest it can reach in each diagonal, following the equal elements for free, until
+/// it reaches the end. Then it goes back through the furthest points saved for each `d`
+/// to build the script. In the same position, the deletions come before the insertions.
+///
+/// It works with slices of any type that can be compared for equality, like lines,
+/// bytes or tokens.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ((n + m)D)     |                  |
+/// | Worst:    | O((n + m)D)     | O(n + m + D²)    |
+///
+/// Where `D` is the number of insertions and deletions of the script.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::diff::{diff, Edit};
+///
+/// let edits = diff(b"ABCABBA", b"CBABAC");
+/// let changes = edits.iter().filter(|edit| !matches!(edit, Edit::Keep(_))).count();
+/// assert_eq!(changes, 5);
+///
+/// use Edit::*;
+/// let script = diff(&["a", "b", "c"], &["a", "c", "d"]);
+/// assert_eq!(script, [Keep(&"a"), Delete(&"b"), Keep(&"c"), Insert(&"d")]);
+/// ```
+pub fn diff<'a, T: Eq>(a: &'a [T], b: &'a [T]) -> Vec<Edit<'a, T>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    // The furthest `x` reached in each diagonal `k = x - y`, at `v[k + offset]`.
+    let offset = max + 1;
+    let mut v = vec![0_isize; 2 * max as usize + 3];
+    // The values of `v` for the diagonals `-d..=d` after each `d`.
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    let at = |k: isize| (k + offset) as usize;
+    'search: for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                trace.push(v[at(-d)..=at(d)].to_vec());
+                break 'search;
+            }
+        }
+        trace.push(v[at(-d)..=at(d)].to_vec());
+    }
+
+    // Goes back from the end, each `d` is a diagonal run after an insertion or a deletion.
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..trace.len() as isize).rev() {
+        // The furthest points after the step before, for the diagonals `-(d-1)..=(d-1)`.
+        let prev = &trace[d as usize - 1];
+        let get = |k: isize| prev[(k + d - 1) as usize];
+        let k = x - y;
+        let insert = k == -d || (k != d && get(k - 1) < get(k + 1));
+        let prev_x = if insert { get(k + 1) } else { get(k - 1) + 1 };
+
+        while x > prev_x {
+            edits.push(Edit::Keep(&a[x as usize - 1]));
+            x -= 1;
+            y -= 1;
+        }
+        if insert {
+            edits.push(Edit::Insert(&b[y as usize - 1]));
+            y -= 1;
+        } else {
+            edits.push(Edit::Delete(&a[x as usize - 1]));
+            x -= 1;
+        }
+    }
+    while x > 0 {
+        edits.push(Edit::Keep(&a[x as usize - 1]));
+        x -= 1;
+    }
+    edits.reverse();
+    edits
+}
+
+/// Returns the diff of the lines of `old` and `new` in the unified format, with `context`
+/// lines kept around each change.
+///
+/// Each hunk starts with `@@ -l,s +l,s @@`, the first line and the number of lines of the
+/// hunk in each string, where the number is omitted if it is `1`, and then each line
+/// prefixed with `' '` if kept, `'-'` if deleted or `'+'` if inserted. It has no header
+/// with file names, and the lines always end with a line feed.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ((n + m)D)     |                  |
+/// | Worst:    | O((n + m)D)     | O(n + m + D²)    |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::diff::unified_diff;
+///
+/// let patch = unified_diff("a\nb\nc\nd\n", "a\nc\nd\ne\n", 1);
+/// assert_eq!(patch, "@@ -1,4 +1,4 @@\n a\n-b\n c\n d\n+e\n");
+/// ```
+pub fn unified_diff(old: &str, new: &str, context: usize) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Each edit with the index of its line in each string.
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for edit in diff(&old, &new) {
+        lines.push((edit, i, j));
+        match edit {
+            Edit::Keep(_) => {
+                i += 1;
+                j += 1;
+            },
+            Edit::Delete(_) => i += 1,
+            Edit::Insert(_) => j += 1,
+        }
+    }
+    let changes: Vec<usize> =
+        (0..lines.len()).filter(|&k| !matches!(lines[k].0, Edit::Keep(_))).collect();
+
+    let range = |start: usize, len: usize| match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    };
+    let mut patch = String::new();
+    let mut c = 0;
+    while c < changes.len() {
+        // Joins the changes while the lines kept between them fit in the context of both.
+        let first = changes[c];
+        while c + 1 < changes.len() && changes[c + 1] - changes[c] - 1 <= 2 * context {
+            c += 1;
+        }
+        let last = changes[c];
+        c += 1;
+
+        let hunk = &lines[first.saturating_sub(context)..lines.len().min(last + context + 1)];
+        let old_len = hunk.iter().filter(|(edit, ..)| !matches!(edit, Edit::Insert(_))).count();
+        let new_len = hunk.iter().filter(|(edit, ..)| !matches!(edit, Edit::Delete(_))).count();
+        let (_, i, j) = hunk[0];
+        patch.push_str(&format!("@@ -{} +{} @@\n", range(i, old_len), range(j, new_len)));
+        for (edit, ..) in hunk {
+            let (prefix, line) = match edit {
+                Edit::Keep(line) => (' ', line),
+                Edit::Delete(line) => ('-', line),
+                Edit::Insert(line) => ('+', line),
+            };
+            patch.push(prefix);
+            patch.push_str(line);
+            patch.push('\n');
+        }
+    }
+    patch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::lcs::lcs_len;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn diff_test() {
+        assert_eq!(diff::<u8>(b"", b""), []);
+        assert_eq!(diff(b"a", b""), [Edit::Delete(&b'a')]);
+        assert_eq!(diff(b"", b"a"), [Edit::Insert(&b'a')]);
+        assert_eq!(diff(b"ab", b"ab"), [Edit::Keep(&b'a'), Edit::Keep(&b'b')]);
+        assert_eq!(diff(b"a", b"b"), [Edit::Delete(&b'a'), Edit::Insert(&b'b')]);
+
+        let mut rng = thread_rng();
+        for _ in 0..300 {
+            let a: Vec<u8> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let edits = diff(&a, &b);
+
+            let (mut old, mut new) = (Vec::new(), Vec::new());
+            for edit in &edits {
+                match *edit {
+                    Edit::Keep(&c) => {
+                        old.push(c);
+                        new.push(c);
+                    },
+                    Edit::Delete(&c) => old.push(c),
+                    Edit::Insert(&c) => new.push(c),
+                }
+            }
+            assert_eq!(old, a);
+            assert_eq!(new, b);
+
+            let changes = edits.iter().filter(|edit| !matches!(edit, Edit::Keep(_))).count();
+            assert_eq!(changes, a.len() + b.len() - 2 * lcs_len(&a, &b));
+        }
+    }
+
+    #[test]
+    fn unified_diff_test() {
+        assert_eq!(unified_diff("a\nb", "a\nb\n", 3), "");
+        assert_eq!(unified_diff("a\nb\nc", "a\nx\nc", 0), "@@ -2 +2 @@\n-b\n+x\n");
+        assert_eq!(unified_diff("a", "a\nb", 0), "@@ -1,0 +2 @@\n+b\n");
+        assert_eq!(unified_diff("", "a", 2), "@@ -0,0 +1 @@\n+a\n");
+
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\nx\n4\n5\n6\n7\n8\ny\n";
+        let patch = unified_diff(old, new, 1);
+        assert_eq!(patch, "@@ -2,3 +2,3 @@\n 2\n-3\n+x\n 4\n@@ -8,2 +8,2 @@\n 8\n-9\n+y\n");
+        let patch = unified_diff(old, new, 3);
+        assert!(patch.starts_with("@@ -1,9 +1,9 @@\n 1\n"));
+    }
+}
@@ -0,0 +1,454 @@
+//! Edit distance module.
+//!
+//! Distances between two sequences, counted by the number of edits to change one into
+//! the other. They work with slices of any type that can be compared for equality, use
+//! `str::as_bytes` or collect the `char`s of a string to use them with strings.
+
+use std::{collections::HashMap, error::Error, fmt, hash::Hash};
+
+/// The error returned when computing the Hamming distance of slices of different sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    left:  usize,
+    right: usize,
+}
+
+impl LengthMismatch {
+    /// Returns the size of the first slice.
+    pub fn left(&self) -> usize { self.left }
+
+    /// Returns the size of the second slice.
+    pub fn right(&self) -> usize { self.right }
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "slices of different sizes: {} and {}", self.left, self.right)
+    }
+}
+
+impl Error for LengthMismatch {}
+
+/// Returns the Hamming distance between `a` and `b`: the number of positions where their
+/// elements are different.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Errors
+/// Returns a [`LengthMismatch`] if `a` and `b` have different sizes.
+///
+/// [`LengthMismatch`]: ./struct.LengthMismatch.html
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::hamming;
+///
+/// assert_eq!(hamming(b"karolin", b"kathrin"), Ok(3));
+/// assert_eq!(hamming(&[1, 0, 1, 1], &[1, 0, 0, 1]), Ok(1));
+/// assert!(hamming(b"abc", b"ab").is_err());
+/// ```
+pub fn hamming<T: Eq>(a: &[T], b: &[T]) -> Result<usize, LengthMismatch> {
+    if a.len() != b.len() {
+        return Err(LengthMismatch { left: a.len(), right: b.len() });
+    }
+    Ok(a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+/// Returns the index of the closest of the `codewords` to `word` under the Hamming
+/// distance, and its distance, or `None` if none of them has the size of `word`.
+///
+/// The codewords of another size are ignored. On ties the first one is returned. It stops
+/// comparing a codeword as soon as it is further than the closest one so far.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(k)            |                  |
+/// | Avrg:     | Θ(kn)           |                  |
+/// | Worst:    | O(kn)           | O(1)             |
+///
+/// **Obs.:** k is the number of codewords.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::nearest_hamming;
+///
+/// let kmers: [&[u8]; 4] = [b"ACGT", b"TTGA", b"ACG", b"CCGA"];
+///
+/// assert_eq!(nearest_hamming(b"CCGT", &kmers), Some((0, 1)));
+/// assert_eq!(nearest_hamming(b"GGCCA", &kmers), None);
+/// ```
+pub fn nearest_hamming<T: Eq, C: AsRef<[T]>>(
+    word: &[T], codewords: &[C],
+) -> Option<(usize, usize)> {
+    let mut nearest: Option<(usize, usize)> = None;
+    for (i, codeword) in codewords.iter().enumerate() {
+        let codeword = codeword.as_ref();
+        if codeword.len() != word.len() {
+            continue;
+        }
+
+        let max = nearest.map_or(usize::MAX, |(_, d)| d);
+        let mut d = 0;
+        for (x, y) in word.iter().zip(codeword) {
+            if x != y {
+                d += 1;
+                if d >= max {
+                    break;
+                }
+            }
+        }
+        if d < max {
+            nearest = Some((i, d));
+            if d == 0 {
+                break;
+            }
+        }
+    }
+    nearest
+}
+
+/// Returns the Levenshtein distance between `a` and `b`: the minimum number of insertions,
+/// deletions and substitutions of a single element to change `a` into `b`.
+///
+/// It uses the classic dynamic programming, keeping only two rows of the table, the size
+/// of the smallest slice.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(min(n, m))     |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::levenshtein;
+///
+/// assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+/// assert_eq!(levenshtein(b"flaw", b"lawn"), 2);
+/// ```
+pub fn levenshtein<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let cost = if x == y { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Returns the Levenshtein distance between `a` and `b`, if it is at most `max`.
+///
+/// It only computes the diagonal band of width `2*max+1` of the table, since the cells
+/// outside of it are greater than `max`, and stops as soon as a whole row is greater than
+/// `max`. So it's much faster than [`levenshtein`] when only small distances matter, like
+/// for spell checking.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(n·max)        |                  |
+/// | Worst:    | O(n·max)        | O(m)             |
+///
+/// [`levenshtein`]: ./fn.levenshtein.html
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::levenshtein_bounded;
+///
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 3), Some(3));
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 2), None);
+/// ```
+pub fn levenshtein_bounded<T: Eq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    let (n, m) = (a.len(), b.len());
+    if n.max(m) - n.min(m) > max {
+        return None;
+    }
+
+    // Any value greater than `max` is the same.
+    let inf = max.saturating_add(1);
+    let mut prev: Vec<usize> = (0..=m).map(|j| j.min(inf)).collect();
+    let mut cur = vec![inf; m + 1];
+    for i in 1..=n {
+        let (lo, hi) = (i.saturating_sub(max), m.min(i.saturating_add(max)));
+        // The cells next to the band are read by the next row.
+        if lo > 0 {
+            cur[lo - 1] = inf;
+        } else {
+            cur[0] = i.min(inf);
+        }
+        if hi < m {
+            cur[hi + 1] = inf;
+        }
+
+        let mut row_min = if lo == 0 { cur[0] } else { inf };
+        for j in lo.max(1)..=hi {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1).min(inf);
+            row_min = row_min.min(cur[j]);
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    Some(prev[m]).filter(|&d| d <= max)
+}
+
+/// Returns the restricted Damerau-Levenshtein distance between `a` and `b`, also known as
+/// optimal string alignment distance: the minimum number of insertions, deletions,
+/// substitutions of a single element and transpositions of two adjacent elements to
+/// change `a` into `b`, where no part of the slice is edited more than once.
+///
+/// Since a transposed pair can't be edited again, it's not a metric: `"ca"` to `"abc"` is
+/// 3 edits instead of 2, see [`damerau_levenshtein`] for that.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(m)             |
+///
+/// [`damerau_levenshtein`]: ./fn.damerau_levenshtein.html
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::{damerau_levenshtein_restricted, levenshtein};
+///
+/// assert_eq!(levenshtein(b"teh", b"the"), 2);
+/// assert_eq!(damerau_levenshtein_restricted(b"teh", b"the"), 1);
+/// assert_eq!(damerau_levenshtein_restricted(b"ca", b"abc"), 3);
+/// ```
+pub fn damerau_levenshtein_restricted<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let m = b.len();
+
+    // The rows of the table for the two previous elements of `a`.
+    let mut prev2 = vec![0; m + 1];
+    let mut prev: Vec<usize> = (0..=m).collect();
+    let mut cur = vec![0; m + 1];
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j - 1] + cost).min(prev[j] + 1).min(cur[j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cur[j] = cur[j].min(prev2[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[m]
+}
+
+/// Returns the Damerau-Levenshtein distance between `a` and `b`: the minimum number of
+/// insertions, deletions, substitutions of a single element and transpositions of two
+/// adjacent elements to change `a` into `b`.
+///
+/// Unlike [`damerau_levenshtein_restricted`], the elements between a transposed pair can
+/// be edited, so it is a metric. It keeps the last row where each element of `a` was
+/// found, so the elements must be hashable.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(nm)            |
+///
+/// [`damerau_levenshtein_restricted`]: ./fn.damerau_levenshtein_restricted.html
+///
+/// # Example
+/// ```rust
+/// use algos::strings::distance::damerau_levenshtein;
+///
+/// assert_eq!(damerau_levenshtein(b"teh", b"the"), 1);
+/// assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+/// ```
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let inf = n + m;
+
+    // The table has an extra row and column of `inf` before the usual ones, so `d[i][j]`
+    // is the distance between `a[..i-1]` and `b[..j-1]`.
+    let mut d = vec![vec![0; m + 2]; n + 2];
+    d[0][0] = inf;
+    for i in 0..=n {
+        d[i + 1][0] = inf;
+        d[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        d[0][j + 1] = inf;
+        d[1][j + 1] = j;
+    }
+
+    // The last row where each element of `b` was found in `a`.
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+    for i in 1..=n {
+        // The last column in this row where `a[i-1]` was found in `b`.
+        let mut last_col = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_col = j;
+                0
+            } else {
+                1
+            };
+            // The transposition of `a[k-1]` and `a[i-1]`, deleting what is between them in
+            // `a` and inserting what is between them in `b`.
+            let transposition = d[k][l] + (i - k - 1) + 1 + (j - l - 1);
+            d[i + 1][j + 1] =
+                (d[i][j] + cost).min(d[i + 1][j] + 1).min(d[i][j + 1] + 1).min(transposition);
+        }
+        last_row.insert(&a[i - 1], i);
+    }
+    d[n + 1][m + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// The full table, to compare against.
+    fn naive<T: Eq>(a: &[T], b: &[T]) -> usize {
+        let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..=a.len() {
+            for j in 0..=b.len() {
+                d[i][j] = if i == 0 || j == 0 {
+                    i + j
+                } else {
+                    let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                    (d[i - 1][j - 1] + cost).min(d[i - 1][j] + 1).min(d[i][j - 1] + 1)
+                };
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    #[test]
+    fn hamming_test() {
+        assert_eq!(hamming::<u8>(b"", b""), Ok(0));
+        assert_eq!(hamming(b"1011101", b"1001001"), Ok(2));
+        assert_eq!(hamming(b"abc", b"abc"), Ok(0));
+
+        let err = hamming(b"abc", b"abcd").unwrap_err();
+        assert_eq!((err.left(), err.right()), (3, 4));
+        assert_eq!(err.to_string(), "slices of different sizes: 3 and 4");
+    }
+
+    #[test]
+    fn nearest_hamming_test() {
+        let codes: [&[u8]; 4] = [b"0000", b"1111", b"0011", b"0110"];
+        assert_eq!(nearest_hamming(b"0000", &codes), Some((0, 0)));
+        assert_eq!(nearest_hamming(b"1110", &codes), Some((1, 1)));
+        assert_eq!(nearest_hamming(b"0101", &codes), Some((0, 2)));
+        assert_eq!(nearest_hamming(b"000", &codes), None);
+        assert_eq!(nearest_hamming::<u8, &[u8]>(b"000", &[]), None);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let word: Vec<u8> = (0..8).map(|_| rng.gen_range(0..2)).collect();
+            let codes: Vec<Vec<u8>> = (0..rng.gen_range(1..20))
+                .map(|_| (0..8).map(|_| rng.gen_range(0..2)).collect())
+                .collect();
+            let distances: Vec<usize> = codes.iter().map(|c| hamming(&word, c).unwrap()).collect();
+            let min = *distances.iter().min().unwrap();
+            let first = distances.iter().position(|&d| d == min).unwrap();
+            assert_eq!(nearest_hamming(&word, &codes), Some((first, min)));
+        }
+    }
+
+    #[test]
+    fn levenshtein_test() {
+        assert_eq!(levenshtein(b"", b""), 0);
+        assert_eq!(levenshtein(b"abc", b""), 3);
+        assert_eq!(levenshtein(b"", b"abc"), 3);
+        assert_eq!(levenshtein(b"abc", b"abc"), 0);
+        assert_eq!(levenshtein(b"sunday", b"saturday"), 3);
+        let a: Vec<char> = "näive".chars().collect();
+        let b: Vec<char> = "naïve".chars().collect();
+        assert_eq!(levenshtein(&a, &b), 2);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            assert_eq!(levenshtein(&a, &b), naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_restricted_test() {
+        assert_eq!(damerau_levenshtein_restricted(b"", b""), 0);
+        assert_eq!(damerau_levenshtein_restricted(b"abc", b""), 3);
+        assert_eq!(damerau_levenshtein_restricted(b"", b"abc"), 3);
+        assert_eq!(damerau_levenshtein_restricted(b"ab", b"ba"), 1);
+        assert_eq!(damerau_levenshtein_restricted(b"abcdef", b"badcfe"), 3);
+        assert_eq!(damerau_levenshtein_restricted(b"ca", b"abc"), 3);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let d = damerau_levenshtein_restricted(&a, &b);
+            // Each transposition is at most two edits of Levenshtein.
+            assert!(d <= levenshtein(&a, &b));
+            assert!(2 * d >= levenshtein(&a, &b));
+            assert!(d >= damerau_levenshtein(&a, &b));
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_test() {
+        assert_eq!(damerau_levenshtein(b"", b""), 0);
+        assert_eq!(damerau_levenshtein(b"abc", b""), 3);
+        assert_eq!(damerau_levenshtein(b"", b"abc"), 3);
+        assert_eq!(damerau_levenshtein(b"ab", b"ba"), 1);
+        assert_eq!(damerau_levenshtein(b"abcdef", b"badcfe"), 3);
+        assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+        assert_eq!(damerau_levenshtein(b"a cat", b"an act"), 2);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let c: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let d = damerau_levenshtein(&a, &b);
+            // It is a metric.
+            assert_eq!(d, damerau_levenshtein(&b, &a));
+            assert!(d <= damerau_levenshtein(&a, &c) + damerau_levenshtein(&c, &b));
+            assert!(d <= levenshtein(&a, &b));
+        }
+    }
+
+    #[test]
+    fn levenshtein_bounded_test() {
+        assert_eq!(levenshtein_bounded(b"", b"", 0), Some(0));
+        assert_eq!(levenshtein_bounded(b"abc", b"", 2), None);
+        assert_eq!(levenshtein_bounded(b"abc", b"", 3), Some(3));
+        assert_eq!(levenshtein_bounded(b"abc", b"abd", 0), None);
+        assert_eq!(levenshtein_bounded(b"abc", b"abc", 0), Some(0));
+        assert_eq!(levenshtein_bounded(b"abc", b"xy", usize::MAX), Some(3));
+
+        let mut rng = thread_rng();
+        for _ in 0..500 {
+            let a: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let max = rng.gen_range(0..20);
+            let d = naive(&a, &b);
+            assert_eq!(levenshtein_bounded(&a, &b, max), Some(d).filter(|&d| d <= max));
+        }
+    }
+}
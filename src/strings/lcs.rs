@@ -0,0 +1,160 @@
+//! Longest common subsequence module.
+//!
+//! A subsequence of a slice is what is left after removing any of its elements, keeping
+//! the order of the rest.
+
+/// Returns the size of the longest common subsequence of `a` and `b`.
+///
+/// It uses the classic dynamic programming, keeping only two rows of the table, the size
+/// of the smallest slice.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(min(n, m))     |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::lcs::lcs_len;
+///
+/// assert_eq!(lcs_len(b"ABCBDAB", b"BDCABA"), 4);
+/// ```
+pub fn lcs_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    last_row(a, b, false)[b.len()]
+}
+
+/// Returns one of the longest common subsequences of `a` and `b`.
+///
+/// See [`lcs_indices`] for how it is found.
+///
+/// [`lcs_indices`]: ./fn.lcs_indices.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(n + m)         |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::lcs::lcs;
+///
+/// let common = lcs(b"ABCBDAB", b"BDCABA");
+/// assert_eq!(common.len(), 4);
+/// assert_eq!(common, b"BCBA");
+/// ```
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    lcs_indices(a, b).into_iter().map(|(i, _)| a[i].clone()).collect()
+}
+
+/// Returns the indexes in `a` and in `b` of the elements of one of the longest common
+/// subsequences of `a` and `b`, in crescent order.
+///
+/// It uses Hirschberg's algorithm: it computes the last row of the table of the dynamic
+/// programming for the first half of `a`, and for the second half of `a` backwards, to
+/// find where the subsequence crosses the middle of `a`, and then does the same for each
+/// half. So it only keeps two rows of the table, even for long slices.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(nm)           |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(n + m)         |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::lcs::lcs_indices;
+///
+/// assert_eq!(lcs_indices(b"XMJYAUZ", b"MZJAWXU"), [(1, 0), (2, 2), (4, 3), (5, 6)]);
+/// ```
+pub fn lcs_indices<T: Eq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    hirschberg(a, b, (0, 0), &mut found);
+    found
+}
+
+/// Pushes to `found` the indexes of a longest common subsequence of `a` and `b`, that
+/// start at `offset` of the whole slices.
+fn hirschberg<T: Eq>(a: &[T], b: &[T], offset: (usize, usize), found: &mut Vec<(usize, usize)>) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    if a.len() == 1 {
+        if let Some(j) = b.iter().position(|y| *y == a[0]) {
+            found.push((offset.0, offset.1 + j));
+        }
+        return;
+    }
+
+    let mid = a.len() / 2;
+    let forward = last_row(&a[..mid], b, false);
+    let backward = last_row(&a[mid..], b, true);
+    // Where to split `b`, so the subsequences of both halves are the longest.
+    let split = (0..=b.len()).max_by_key(|&j| (forward[j] + backward[b.len() - j], j)).unwrap_or(0);
+
+    hirschberg(&a[..mid], &b[..split], offset, found);
+    hirschberg(&a[mid..], &b[split..], (offset.0 + mid, offset.1 + split), found);
+}
+
+/// Returns the last row of the table of the dynamic programming, where the value at `j` is
+/// the size of the longest common subsequence of `a` and the first `j` elements of `b`,
+/// or of both slices backwards if `rev` is `true`.
+fn last_row<T: Eq>(a: &[T], b: &[T], rev: bool) -> Vec<usize> {
+    let (n, m) = (a.len(), b.len());
+    let mut prev = vec![0; m + 1];
+    let mut cur = vec![0; m + 1];
+    for i in 0..n {
+        let x = if rev { &a[n - 1 - i] } else { &a[i] };
+        for j in 0..m {
+            let y = if rev { &b[m - 1 - j] } else { &b[j] };
+            cur[j + 1] = if x == y { prev[j] + 1 } else { prev[j + 1].max(cur[j]) };
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Returns `true` if `sub` is a subsequence of `v`.
+    fn is_subsequence<T: Eq>(sub: &[T], v: &[T]) -> bool {
+        let mut v = v.iter();
+        sub.iter().all(|x| v.any(|y| y == x))
+    }
+
+    #[test]
+    fn lcs_len_test() {
+        assert_eq!(lcs_len::<u8>(b"", b""), 0);
+        assert_eq!(lcs_len(b"ABC", b""), 0);
+        assert_eq!(lcs_len(b"ABC", b"ABC"), 3);
+        assert_eq!(lcs_len(b"ABC", b"DEF"), 0);
+        assert_eq!(lcs_len(b"AGGTAB", b"GXTXAYB"), 4);
+    }
+
+    #[test]
+    fn lcs_test() {
+        assert_eq!(lcs::<u8>(b"", b"ABC"), []);
+        assert_eq!(lcs(b"ABC", b"DEF"), []);
+        assert_eq!(lcs(b"AGGTAB", b"GXTXAYB"), b"GTAB");
+        assert_eq!(lcs(&[1, 2, 3, 4, 1], &[3, 4, 1, 2, 1, 3]), [1, 2, 3]);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let common = lcs(&a, &b);
+            assert_eq!(common.len(), lcs_len(&a, &b));
+            assert!(is_subsequence(&common, &a));
+            assert!(is_subsequence(&common, &b));
+
+            let indices = lcs_indices(&a, &b);
+            assert!(indices.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+            assert!(indices.iter().all(|&(i, j)| a[i] == b[j]));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Lyndon words module.
+//!
+//! A Lyndon word is a slice that is strictly smaller than all its rotations, and every
+//! slice is a unique concatenation of Lyndon words in non-increasing order.
+
+use std::ops::Range;
+
+/// **Duval's Algorithm:** Returns the ranges of the Lyndon factorization of `text`, the
+/// Lyndon words in non-increasing order that make `text`.
+///
+/// It keeps the start of a run of copies of the same Lyndon word, and extends it while
+/// the next element keeps it a prefix of a power of that word, or makes it a new longer
+/// Lyndon word, and when it does neither, the copies are factors.
+///
+/// It works with slices of any type that can be ordered.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::lyndon::lyndon_factorization;
+///
+/// let text = b"banana";
+/// let factors: Vec<&[u8]> = lyndon_factorization(text).into_iter().map(|r| &text[r]).collect();
+/// assert_eq!(factors, [&b"b"[..], b"an", b"an", b"a"]);
+/// ```
+pub fn lyndon_factorization<T: Ord>(text: &[T]) -> Vec<Range<usize>> {
+    let mut factors = Vec::new();
+    duval(text.len(), |i| &text[i], |range, _| {
+        factors.push(range);
+        false
+    });
+    factors
+}
+
+/// Returns the index where the lexicographically least rotation of `text` starts, the
+/// smallest one if there are many.
+///
+/// It is the start of the first copy of the last Lyndon factor of `text` concatenated
+/// with itself that starts in the first half, so it runs [`lyndon_factorization`] over
+/// it, without copying `text`, and stops there.
+///
+/// [`lyndon_factorization`]: ./fn.lyndon_factorization.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::lyndon::least_rotation;
+///
+/// assert_eq!(least_rotation(b"cabbca"), 1);
+/// assert_eq!(least_rotation(b"bbaabbaa"), 2);
+/// ```
+pub fn least_rotation<T: Ord>(text: &[T]) -> usize {
+    let n = text.len();
+    let mut start = 0;
+    duval(2 * n, |i| &text[i % n], |range, first| {
+        if range.start >= n {
+            return true;
+        }
+        if first {
+            start = range.start;
+        }
+        false
+    });
+    start
+}
+
+/// Runs Duval's algorithm over a slice of size `n` with the element at `i` in `at(i)`,
+/// calling `report` with the range of each factor, and if it is the first of the copies of
+/// the same word in a row, until it returns `true`.
+fn duval<'a, T: Ord + 'a, A, F>(n: usize, at: A, mut report: F)
+where
+    A: Fn(usize) -> &'a T,
+    F: FnMut(Range<usize>, bool) -> bool,
+{
+    let mut i = 0;
+    while i < n {
+        // `text[i..j]` is a prefix of a power of the Lyndon word of size `j - k`.
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && at(k) <= at(j) {
+            k = if at(k) < at(j) { i } else { k + 1 };
+            j += 1;
+        }
+        let first = i;
+        while i <= k {
+            if report(i..i + j - k, i == first) {
+                return;
+            }
+            i += j - k;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::rotation::minimal_rotation;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn is_lyndon<T: Ord>(word: &[T]) -> bool {
+        !word.is_empty() && (1..word.len()).all(|k| word[k..].iter().chain(&word[..k]).gt(word))
+    }
+
+    #[test]
+    fn lyndon_factorization_test() {
+        assert_eq!(lyndon_factorization::<u8>(b""), []);
+        assert_eq!(lyndon_factorization(b"aaa"), [0..1, 1..2, 2..3]);
+        assert_eq!(lyndon_factorization(b"abab"), [0..2, 2..4]);
+        assert_eq!(lyndon_factorization(b"abca"), [0..3, 3..4]);
+        assert_eq!(lyndon_factorization(&[3, 2, 1]), [0..1, 1..2, 2..3]);
+
+        let mut rng = thread_rng();
+        for _ in 0..300 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..60)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let factors = lyndon_factorization(&text);
+            assert_eq!(factors.iter().map(|r| r.len()).sum::<usize>(), text.len());
+            assert!(factors.windows(2).all(|w| w[0].end == w[1].start));
+            assert!(factors.iter().all(|r| is_lyndon(&text[r.clone()])));
+            assert!(factors.windows(2).all(|w| text[w[0].clone()] >= text[w[1].clone()]));
+        }
+    }
+
+    #[test]
+    fn least_rotation_test() {
+        assert_eq!(least_rotation::<u8>(b""), 0);
+        assert_eq!(least_rotation(b"aaaa"), 0);
+        assert_eq!(least_rotation(b"abab"), 0);
+        assert_eq!(least_rotation(b"baaa"), 1);
+
+        let mut rng = thread_rng();
+        for _ in 0..300 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(1..60)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            assert_eq!(least_rotation(&text), minimal_rotation(&text));
+        }
+    }
+}
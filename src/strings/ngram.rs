@@ -0,0 +1,214 @@
+//! N-gram module.
+//!
+//! The n-grams of a text are its substrings of `n` elements, and the profile of a text is
+//! how many times each n-gram occurs in it. Texts with similar profiles are likely to be
+//! similar, even if the words are in another order or misspelled, so the similarities of
+//! the profiles are a cheap approximation of how similar the texts are.
+
+use std::{collections::HashMap, hash::Hash, slice::Windows};
+
+/// How many times each n-gram occurs in a text.
+pub type Profile<K> = HashMap<K, usize>;
+
+/// Returns an iterator over the n-grams of bytes of `text`, in order, or an empty one if
+/// it is smaller than `n`.
+///
+/// # Panics
+/// It panics if `n` is `0`.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::ngram::byte_ngrams;
+///
+/// let bigrams: Vec<_> = byte_ngrams(b"abcd", 2).collect();
+/// assert_eq!(bigrams, [b"ab", b"bc", b"cd"]);
+/// ```
+pub fn byte_ngrams(text: &[u8], n: usize) -> Windows<'_, u8> {
+    assert!(n > 0, "n-grams of size zero");
+    text.windows(n)
+}
+
+/// Returns an iterator over the n-grams of `char`s of `text`, in order, or an empty one
+/// if it has less `char`s than `n`.
+///
+/// # Panics
+/// It panics if `n` is `0`.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::ngram::char_ngrams;
+///
+/// let trigrams: Vec<_> = char_ngrams("Café!", 3).collect();
+/// assert_eq!(trigrams, ["Caf", "afé", "fé!"]);
+/// ```
+pub fn char_ngrams(text: &str, n: usize) -> CharNgrams<'_> {
+    assert!(n > 0, "n-grams of size zero");
+    let end = text.char_indices().map(|(i, c)| i + c.len_utf8()).nth(n - 1);
+    CharNgrams { text, start: 0, end }
+}
+
+/// Iterator over the n-grams of `char`s of a string.
+///
+/// This struct is created by the [`char_ngrams`] function.
+///
+/// [`char_ngrams`]: ./fn.char_ngrams.html
+#[derive(Debug, Clone)]
+pub struct CharNgrams<'a> {
+    text:  &'a str,
+    start: usize,
+    /// The end of the next n-gram, `None` after the last one.
+    end:   Option<usize>,
+}
+
+impl<'a> Iterator for CharNgrams<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.end?;
+        let ngram = &self.text[self.start..end];
+        self.start += ngram.chars().next().map_or(0, char::len_utf8);
+        self.end = self.text[end..].chars().next().map(|c| end + c.len_utf8());
+        Some(ngram)
+    }
+}
+
+/// Returns the profile of `ngrams`, how many times each one of them occurs.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::ngram::{byte_ngrams, profile};
+///
+/// let profile = profile(byte_ngrams(b"abab", 2));
+/// assert_eq!(profile[&b"ab"[..]], 2);
+/// assert_eq!(profile[&b"ba"[..]], 1);
+/// ```
+pub fn profile<K: Hash + Eq, I: IntoIterator<Item = K>>(ngrams: I) -> Profile<K> {
+    let mut profile = HashMap::new();
+    for ngram in ngrams {
+        *profile.entry(ngram).or_insert(0) += 1;
+    }
+    profile
+}
+
+/// **Jaccard Similarity:** Returns the Jaccard similarity of the n-grams of the profiles
+/// `a` and `b`, the number of n-grams in both divided by the number of n-grams in any of
+/// them, without counting repetitions.
+///
+/// Two empty profiles have similarity `1.0`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ(n + m)        |                  |
+/// | Worst:    | O(n + m)        | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::ngram::{char_ngrams, jaccard, profile};
+///
+/// let a = profile(char_ngrams("night", 2));
+/// let b = profile(char_ngrams("nacht", 2));
+/// // Only "ht" of the 7 bigrams is in both.
+/// assert!((jaccard(&a, &b) - 1.0 / 7.0).abs() < 1e-9);
+/// ```
+pub fn jaccard<K: Hash + Eq>(a: &Profile<K>, b: &Profile<K>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let common = a.keys().filter(|ngram| b.contains_key(ngram)).count();
+    common as f64 / (a.len() + b.len() - common) as f64
+}
+
+/// **Cosine Similarity:** Returns the cosine similarity of the profiles `a` and `b`, the
+/// cosine of the angle between them as vectors of the counts of each n-gram.
+///
+/// Two empty profiles have similarity `1.0`, and an empty profile has similarity `0.0`
+/// with any other.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ(n + m)        |                  |
+/// | Worst:    | O(n + m)        | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::ngram::{char_ngrams, cosine, profile};
+///
+/// let a = profile(char_ngrams("night", 2));
+/// let b = profile(char_ngrams("nacht", 2));
+/// assert!((cosine(&a, &b) - 0.25).abs() < 1e-9);
+/// ```
+pub fn cosine<K: Hash + Eq>(a: &Profile<K>, b: &Profile<K>) -> f64 {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return 1.0,
+        (true, false) | (false, true) => return 0.0,
+        _ => {},
+    }
+    let dot: f64 =
+        a.iter().filter_map(|(ngram, &x)| b.get(ngram).map(|&y| x as f64 * y as f64)).sum();
+    let norm = |profile: &Profile<K>| profile.values().map(|&x| (x * x) as f64).sum::<f64>().sqrt();
+    dot / (norm(a) * norm(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+    use std::collections::HashSet;
+
+    #[test]
+    fn ngrams_test() {
+        assert_eq!(byte_ngrams(b"ab", 3).count(), 0);
+        assert_eq!(char_ngrams("", 1).count(), 0);
+        assert_eq!(char_ngrams("ab", 3).count(), 0);
+        assert_eq!(char_ngrams("ab", 2).collect::<Vec<_>>(), ["ab"]);
+        assert_eq!(char_ngrams("ñéü", 1).collect::<Vec<_>>(), ["ñ", "é", "ü"]);
+        let bigrams: Vec<_> = char_ngrams("aßç€𝄞b", 2).collect();
+        assert_eq!(bigrams, ["aß", "ßç", "ç€", "€𝄞", "𝄞b"]);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let chars: Vec<char> = (0..rng.gen_range(0..20))
+                .map(|_| ['a', 'é', '€', '𝄞'][rng.gen_range(0..4)])
+                .collect();
+            let text: String = chars.iter().collect();
+            let n = rng.gen_range(1..5);
+            let expected: Vec<String> = chars.windows(n).map(|w| w.iter().collect()).collect();
+            assert_eq!(char_ngrams(&text, n).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn char_ngrams_zero() { char_ngrams("abc", 0); }
+
+    #[test]
+    fn similarity_test() {
+        let empty: Profile<&[u8]> = Profile::new();
+        let a = profile(byte_ngrams(b"abc", 2));
+        assert_eq!(jaccard(&empty, &empty), 1.0);
+        assert_eq!(jaccard(&a, &empty), 0.0);
+        assert_eq!(cosine(&empty, &empty), 1.0);
+        assert_eq!(cosine(&empty, &a), 0.0);
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let x: Vec<u8> = (0..rng.gen_range(2..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let y: Vec<u8> = (0..rng.gen_range(2..30)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let (a, b) = (profile(byte_ngrams(&x, 2)), profile(byte_ngrams(&y, 2)));
+
+            let sa: HashSet<_> = x.windows(2).collect();
+            let sb: HashSet<_> = y.windows(2).collect();
+            let expected = sa.intersection(&sb).count() as f64 / sa.union(&sb).count() as f64;
+            assert!(close(jaccard(&a, &b), expected));
+            assert!(close(jaccard(&a, &a), 1.0));
+
+            let similarity = cosine(&a, &b);
+            assert!((0.0..=1.0 + 1e-9).contains(&similarity));
+            assert!(close(similarity, cosine(&b, &a)));
+            assert!(close(cosine(&a, &a), 1.0));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Module for algorithms about palindromes, the slices that are the same backwards.
+
+use std::ops::Range;
+
+/// **Manacher's Algorithm:** Returns the size of the longest palindrome centered at each
+/// position of `text`.
+///
+/// The centers are the elements and the gaps between them, including before the first
+/// and after the last, so there are `2n + 1` of them: the even position `2i` is the gap
+/// before the element `i`, and the odd position `2i + 1` is the element `i`. The
+/// palindrome centered at `c` with size `r` is `text[(c - r) / 2..(c + r) / 2]`, which is
+/// also the radius of it in `text` with a separator between each element.
+///
+/// It reuses the palindrome that ends the furthest to start the one of the next centers
+/// with the size of its mirror inside of it, so each element is compared only a constant
+/// number of times.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::palindrome::manacher;
+///
+/// assert_eq!(manacher(b"abaa"), [0, 1, 0, 3, 0, 1, 2, 1, 0]);
+/// ```
+pub fn manacher<T: Eq>(text: &[T]) -> Vec<usize> {
+    let size = 2 * text.len() + 1;
+    let mut radius = vec![0; size];
+
+    // The palindrome that ends the furthest, as its center and where it ends.
+    let (mut center, mut right) = (0, 0);
+    for i in 0..size {
+        let mut r = if i < right { radius[2 * center - i].min(right - i) } else { 0 };
+        // The positions at both sides have the same parity, so both are gaps, which are
+        // always equal, or both are elements.
+        while r < i && i + r + 1 < size {
+            let (before, after) = (i - r - 1, i + r + 1);
+            if after % 2 == 1 && text[before / 2] != text[after / 2] {
+                break;
+            }
+            r += 1;
+        }
+        radius[i] = r;
+        if i + r > right {
+            center = i;
+            right = i + r;
+        }
+    }
+    radius
+}
+
+/// Returns the range of the first of the longest palindromes of `text`.
+///
+/// It uses [`manacher`] to find them.
+///
+/// [`manacher`]: ./fn.manacher.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::palindrome::longest_palindrome;
+///
+/// let text = b"forgeeksskeegfor";
+/// let range = longest_palindrome(text);
+/// assert_eq!(range, 3..13);
+/// assert_eq!(&text[range], b"geeksskeeg");
+/// ```
+pub fn longest_palindrome<T: Eq>(text: &[T]) -> Range<usize> {
+    let radius = manacher(text);
+    let mut best = 0;
+    for (c, &r) in radius.iter().enumerate() {
+        if r > radius[best] {
+            best = c;
+        }
+    }
+    let r = radius[best];
+    (best - r) / 2..(best + r) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn is_palindrome<T: Eq>(v: &[T]) -> bool { v.iter().eq(v.iter().rev()) }
+
+    #[test]
+    fn manacher_test() {
+        assert_eq!(manacher::<u8>(b""), [0]);
+        assert_eq!(manacher(b"a"), [0, 1, 0]);
+        assert_eq!(manacher(b"aa"), [0, 1, 2, 1, 0]);
+        assert_eq!(manacher(b"ab"), [0, 1, 0, 1, 0]);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..60)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let radius = manacher(&text);
+            assert_eq!(radius.len(), 2 * text.len() + 1);
+            for (c, &r) in radius.iter().enumerate() {
+                let (start, end) = ((c - r) / 2, (c + r) / 2);
+                assert_eq!(end - start, r);
+                assert!(is_palindrome(&text[start..end]));
+                // It can not grow by one element at each side.
+                if start > 0 && end < text.len() {
+                    assert_ne!(text[start - 1], text[end]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn longest_palindrome_test() {
+        assert_eq!(longest_palindrome::<u8>(b""), 0..0);
+        assert_eq!(longest_palindrome(b"a"), 0..1);
+        assert_eq!(longest_palindrome(b"abc"), 0..1);
+        assert_eq!(longest_palindrome(b"babad"), 0..3);
+        assert_eq!(longest_palindrome(b"cbbd"), 1..3);
+        assert_eq!(longest_palindrome(&[1, 2, 3, 2, 1, 1]), 0..5);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..40)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let naive = (0..=text.len())
+                .flat_map(|i| (i..=text.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| is_palindrome(&text[i..j]))
+                .map(|(i, j)| j - i)
+                .max()
+                .unwrap_or(0);
+            let range = longest_palindrome(&text);
+            assert_eq!(range.len(), naive);
+            assert!(is_palindrome(&text[range]));
+        }
+    }
+}
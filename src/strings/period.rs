@@ -0,0 +1,142 @@
+//! Module for algorithms about the periods and borders of slices.
+//!
+//! A border of a slice is a proper prefix of it that is also a suffix, and a period is a
+//! size `p` such that every element is equal to the one `p` elements after it. They come
+//! in pairs: `text` has a border of size `b` if, and only if, it has the period
+//! `text.len() - b`.
+
+use crate::pattern::failure_function;
+
+/// Returns the sizes of all the non-empty borders of `text` in crescent order.
+///
+/// The longest border is the last value of the [`failure_function`], and each border of
+/// a border is also a border, so it follows the failure function from there.
+///
+/// [`failure_function`]: ../../pattern/fn.failure_function.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::period::borders;
+///
+/// assert_eq!(borders(b"abacaba"), [1, 3]);
+/// assert_eq!(borders(b"aaaa"), [1, 2, 3]);
+/// assert!(borders(b"abc").is_empty());
+/// ```
+pub fn borders<T: Eq>(text: &[T]) -> Vec<usize> {
+    let fail = failure_function(text);
+    let mut borders = Vec::new();
+    let mut b = fail.last().copied().unwrap_or(0);
+    while b > 0 {
+        borders.push(b);
+        b = fail[b - 1];
+    }
+    borders.reverse();
+    borders
+}
+
+/// Returns the smallest period of `text`, `text.len()` if it has no border and `0` if it
+/// is empty.
+///
+/// It is the size of `text` minus its longest border, the last value of the
+/// [`failure_function`].
+///
+/// [`failure_function`]: ../../pattern/fn.failure_function.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::period::smallest_period;
+///
+/// assert_eq!(smallest_period(b"abcabcab"), 3);
+/// assert_eq!(smallest_period(b"aaaa"), 1);
+/// assert_eq!(smallest_period(b"abcd"), 4);
+/// ```
+pub fn smallest_period<T: Eq>(text: &[T]) -> usize {
+    text.len() - failure_function(text).last().copied().unwrap_or(0)
+}
+
+/// Returns `true` if `text` is primitive, that is, it is not a repetition of a smaller
+/// slice two or more times. An empty slice is not primitive.
+///
+/// By the periodicity lemma, `text` is a repetition if, and only if, its
+/// [`smallest_period`] is smaller than it and divides its size.
+///
+/// [`smallest_period`]: ./fn.smallest_period.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::period::is_primitive;
+///
+/// assert!(is_primitive(b"abcab"));
+/// assert!(!is_primitive(b"abab"));
+/// assert!(!is_primitive(b""));
+/// ```
+pub fn is_primitive<T: Eq>(text: &[T]) -> bool {
+    let period = smallest_period(text);
+    period > 0 && (period == text.len() || !text.len().is_multiple_of(period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn naive_borders(text: &[u8]) -> Vec<usize> {
+        (1..text.len()).filter(|&b| text[..b] == text[text.len() - b..]).collect()
+    }
+
+    fn naive_period(text: &[u8]) -> usize {
+        (1..=text.len()).find(|&p| (p..text.len()).all(|i| text[i] == text[i - p])).unwrap_or(0)
+    }
+
+    fn naive_primitive(text: &[u8]) -> bool {
+        let n = text.len();
+        let repeats = |p: usize| text.chunks(p).all(|chunk| chunk == &text[..p]);
+        n > 0 && !(1..n).any(|p| n.is_multiple_of(p) && repeats(p))
+    }
+
+    #[test]
+    fn period_test() {
+        assert!(borders::<u8>(b"").is_empty());
+        assert_eq!(smallest_period::<u8>(b""), 0);
+        assert_eq!(smallest_period(b"a"), 1);
+        assert!(is_primitive(b"a"));
+        assert!(!is_primitive(b"aa"));
+        assert!(is_primitive(b"aab"));
+        assert!(!is_primitive(b"abcabcabc"));
+        assert!(is_primitive(&[1, 2, 1]));
+
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let text: Vec<u8> = if rng.gen_bool(0.3) {
+                // A repetition, maybe with a prefix of the root after it.
+                let root: Vec<u8> =
+                    (0..rng.gen_range(1..4)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+                let size = root.len() * rng.gen_range(1..5) + rng.gen_range(0..root.len());
+                root.iter().copied().cycle().take(size).collect()
+            } else {
+                (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'c')).collect()
+            };
+            assert_eq!(borders(&text), naive_borders(&text));
+            assert_eq!(smallest_period(&text), naive_period(&text));
+            assert_eq!(is_primitive(&text), naive_primitive(&text));
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Phonetic encoding module.
+//!
+//! Codes of how English names sound, so names spelled differently that sound alike have
+//! the same code. Only the ASCII letters of the names are used, everything else is
+//! ignored, and the codes are in uppercase.
+//!
+//! Only the original Metaphone is implemented, not Double Metaphone, which has many more
+//! rules for names of other languages and returns two codes.
+
+/// **Soundex:** Returns the American Soundex code of `name`, or an empty string if it has
+/// no letters.
+///
+/// The code is the first letter followed by three digits of the next consonants, where
+/// the consonants that sound alike have the same digit, the same digits in a row are
+/// only one, even if separated by `H` or `W`, and the vowels are dropped. It is padded
+/// with zeros if there are not enough consonants.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(1)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(1)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::phonetic::soundex;
+///
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Rupert"), "R163");
+/// assert_eq!(soundex("Ashcraft"), "A261");
+/// assert_eq!(soundex("Lee"), "L000");
+/// ```
+pub fn soundex(name: &str) -> String {
+    let mut letters =
+        name.bytes().filter(u8::is_ascii_alphabetic).map(|c| c.to_ascii_uppercase());
+    let first = match letters.next() {
+        Some(c) => c,
+        None => return String::new(),
+    };
+
+    let mut code = String::with_capacity(4);
+    code.push(first as char);
+    let mut last = soundex_digit(first);
+    for c in letters {
+        if c == b'H' || c == b'W' {
+            continue;
+        }
+        let digit = soundex_digit(c);
+        if digit != b'0' && digit != last {
+            code.push(digit as char);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        last = digit;
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code
+}
+
+/// Returns the Soundex digit of the uppercase letter `c`, `'0'` for the vowels.
+fn soundex_digit(c: u8) -> u8 {
+    match c {
+        b'B' | b'F' | b'P' | b'V' => b'1',
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => b'2',
+        b'D' | b'T' => b'3',
+        b'L' => b'4',
+        b'M' | b'N' => b'5',
+        b'R' => b'6',
+        _ => b'0',
+    }
+}
+
+/// **Metaphone:** Returns the Metaphone code of `name`, or an empty string if it has no
+/// letters.
+///
+/// It follows the rules of the original Metaphone to turn the letters into the sounds of
+/// English, like `PH` to `F` and `TH` to `0` (for theta), drops the silent letters, like
+/// the first one of `KN` at the start, and all the vowels but the first letter. The code
+/// has no size limit.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::phonetic::metaphone;
+///
+/// assert_eq!(metaphone("Knight"), "NT");
+/// assert_eq!(metaphone("Smith"), "SM0");
+/// assert_eq!(metaphone("Philip"), "FLP");
+/// assert_eq!(metaphone("Catherine"), metaphone("Kathryn"));
+/// ```
+pub fn metaphone(name: &str) -> String {
+    // The letters without the same letter in a row, but `C`.
+    let mut word: Vec<u8> = Vec::with_capacity(name.len());
+    for c in name.bytes().filter(u8::is_ascii_alphabetic).map(|c| c.to_ascii_uppercase()) {
+        if c == b'C' || word.last() != Some(&c) {
+            word.push(c);
+        }
+    }
+    // The first letter is silent in these.
+    if let [b'K', b'N', ..]
+    | [b'G', b'N', ..]
+    | [b'P', b'N', ..]
+    | [b'A', b'E', ..]
+    | [b'W', b'R', ..] = word[..]
+    {
+        word.remove(0);
+    }
+
+    let n = word.len();
+    let at = |i: usize| word.get(i).copied().unwrap_or(0);
+    let is_vowel = |c: u8| matches!(c, b'A' | b'E' | b'I' | b'O' | b'U');
+    let mut code = String::with_capacity(n);
+    let mut i = 0;
+    while i < n {
+        let prev = if i > 0 { at(i - 1) } else { 0 };
+        let (c, next, after) = (at(i), at(i + 1), at(i + 2));
+        let ends_with_ned = after == b'E' && at(i + 3) == b'D' && i + 4 == n;
+        let sound: &[u8] = match c {
+            b'A' | b'E' | b'I' | b'O' | b'U' if i > 0 => b"",
+            b'B' if i + 1 == n && prev == b'M' => b"",
+            b'C' if next == b'I' && after == b'A' => b"X",
+            b'C' if next == b'H' && prev == b'S' => b"K",
+            b'C' if next == b'H' => b"X",
+            b'C' if matches!(next, b'I' | b'E' | b'Y') => b"S",
+            b'C' if next == b'K' => b"",
+            b'C' => b"K",
+            b'D' if next == b'G' && matches!(after, b'E' | b'Y' | b'I') => b"J",
+            b'D' => b"T",
+            b'G' if next == b'H' && i + 2 < n && !is_vowel(after) => b"",
+            b'G' if next == b'N' && (i + 2 == n || ends_with_ned) => b"",
+            b'G' if prev == b'D' && matches!(next, b'I' | b'E' | b'Y') => b"",
+            b'G' if matches!(next, b'I' | b'E' | b'Y') => b"J",
+            b'G' => b"K",
+            b'H' if matches!(prev, b'C' | b'S' | b'P' | b'T' | b'G') => b"",
+            b'H' if is_vowel(prev) && !is_vowel(next) => b"",
+            b'P' if next == b'H' => b"F",
+            b'Q' => b"K",
+            b'S' if next == b'H' || (next == b'I' && matches!(after, b'O' | b'A')) => b"X",
+            b'T' if next == b'I' && matches!(after, b'O' | b'A') => b"X",
+            b'T' if next == b'H' => b"0",
+            b'T' if next == b'C' && after == b'H' => b"",
+            b'V' => b"F",
+            b'W' if i == 0 && next == b'H' => {
+                // The `H` is silent.
+                i += 1;
+                b"W"
+            },
+            b'W' | b'Y' if !is_vowel(next) => b"",
+            b'X' if i == 0 => b"S",
+            b'X' => b"KS",
+            b'Z' => b"S",
+            _ => &word[i..=i],
+        };
+        code.extend(sound.iter().map(|&c| c as char));
+        i += 1;
+    }
+    code
+}
+
+/// Returns `true` if `a` and `b` have the same non-empty code with `encode`, like
+/// [`soundex`] or [`metaphone`].
+///
+/// [`soundex`]: ./fn.soundex.html
+/// [`metaphone`]: ./fn.metaphone.html
+///
+/// # Example
+/// ```rust
+/// use algos::strings::phonetic::{metaphone, sounds_alike, soundex};
+///
+/// assert!(sounds_alike("Robert", "Rupert", soundex));
+/// assert!(!sounds_alike("Robert", "Rubin", soundex));
+/// assert!(sounds_alike("Steven", "Stephen", metaphone));
+/// assert!(!sounds_alike("", "", soundex));
+/// ```
+pub fn sounds_alike<F: Fn(&str) -> String>(a: &str, b: &str, encode: F) -> bool {
+    let code = encode(a);
+    !code.is_empty() && code == encode(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soundex_test() {
+        assert_eq!(soundex(""), "");
+        assert_eq!(soundex("123"), "");
+        assert_eq!(soundex("a"), "A000");
+        assert_eq!(soundex("Rubin"), "R150");
+        assert_eq!(soundex("Rupert"), "R163");
+        assert_eq!(soundex("Tymczak"), "T522");
+        assert_eq!(soundex("Pfister"), "P236");
+        assert_eq!(soundex("Honeyman"), "H555");
+        assert_eq!(soundex("Jackson"), "J250");
+        assert_eq!(soundex("O'Hara"), "O600");
+        assert_eq!(soundex("Washington"), "W252");
+    }
+
+    #[test]
+    fn metaphone_test() {
+        assert_eq!(metaphone(""), "");
+        assert_eq!(metaphone("Aebersold"), "EBRSLT");
+        assert_eq!(metaphone("Gnagy"), "NJ");
+        assert_eq!(metaphone("Wright"), "RT");
+        assert_eq!(metaphone("White"), "WT");
+        assert_eq!(metaphone("Xavier"), "SFR");
+        assert_eq!(metaphone("Thumb"), "0M");
+        assert_eq!(metaphone("Science"), "SSNS");
+        assert_eq!(metaphone("Judge"), "JJ");
+        assert_eq!(metaphone("Nation"), "NXN");
+        assert_eq!(metaphone("Campbell"), "KMPBL");
+        assert_eq!(metaphone("Dixon"), "TKSN");
+    }
+
+    #[test]
+    fn sounds_alike_test() {
+        assert!(sounds_alike("Smith", "Smyth", soundex));
+        assert!(sounds_alike("Smith", "Smyth", metaphone));
+        assert!(sounds_alike("Knight", "Night", metaphone));
+        assert!(!sounds_alike("Smith", "Jones", metaphone));
+        assert!(!sounds_alike("!", "?", metaphone));
+    }
+}
@@ -0,0 +1,209 @@
+//! Rolling hash implementation.
+
+use std::ops::Range;
+
+use rand::prelude::{thread_rng, Rng};
+
+/// The moduli of the two hashes, both primes below 2^30, so the products fit in `u64`.
+const MODULI: [u64; 2] = [1_000_000_007, 998_244_353];
+
+/// The bases used by [`RollingHash::new`].
+///
+/// [`RollingHash::new`]: ./struct.RollingHash.html#method.new
+const BASES: [u64; 2] = [131, 137];
+
+/// The polynomial hashes of all the prefixes of a slice, to get the hash of any substring
+/// of it in constant time.
+///
+/// It uses two hashes with different moduli and bases, so two different substrings of the
+/// same size have the same hash with a chance of about 10^-18. The hash of
+/// `text[i..j]` is `text[i] * b^(j-i-1) + ... + text[j-1]`, modulo each modulus.
+///
+/// The bases may be chosen at random with [`RollingHash::randomized`], so no input can
+/// be crafted to make a collision.
+///
+/// [`RollingHash::randomized`]: ./struct.RollingHash.html#method.randomized
+///
+/// # Example
+/// ```rust
+/// use algos::strings::RollingHash;
+///
+/// let hash = RollingHash::new(b"abracadabra");
+/// assert!(hash.equal(0..4, 7..11));
+/// assert!(!hash.equal(0..4, 1..5));
+/// assert_eq!(hash.hash_of(0..4), RollingHash::new(b"abra").hash_of(0..4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RollingHash {
+    bases:  [u64; 2],
+    /// The hashes of the prefixes of each size.
+    prefix: Vec<[u64; 2]>,
+    /// The powers of the bases, `powers[i]` is `b^i`.
+    powers: Vec<[u64; 2]>,
+}
+
+impl RollingHash {
+    /// Creates a new `RollingHash` for `text` with fixed bases.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(n)            |                  |
+    /// | Avrg:     | Θ(n)            |                  |
+    /// | Worst:    | O(n)            | O(n)             |
+    pub fn new(text: &[u8]) -> Self { Self::with_bases(text, BASES) }
+
+    /// Creates a new `RollingHash` for `text` with bases chosen at random.
+    ///
+    /// The hashes are only comparable with the ones of the same `RollingHash`, or of one
+    /// created with the same [`bases`].
+    ///
+    /// [`bases`]: #method.bases
+    pub fn randomized(text: &[u8]) -> Self {
+        let mut rng = thread_rng();
+        Self::with_bases(text, [rng.gen_range(256..MODULI[0]), rng.gen_range(256..MODULI[1])])
+    }
+
+    /// Creates a new `RollingHash` for `text` with the given `bases`.
+    ///
+    /// The bases are reduced modulo the moduli of the hashes, and should be greater than
+    /// the elements, otherwise there are collisions that are easy to find.
+    pub fn with_bases(text: &[u8], bases: [u64; 2]) -> Self {
+        let bases = [bases[0] % MODULI[0], bases[1] % MODULI[1]];
+        let mut prefix = Vec::with_capacity(text.len() + 1);
+        let mut powers = Vec::with_capacity(text.len() + 1);
+        prefix.push([0; 2]);
+        powers.push([1; 2]);
+        for (i, &c) in text.iter().enumerate() {
+            let (hash, power) = (prefix[i], powers[i]);
+            prefix.push([0, 1].map(|k| (hash[k] * bases[k] + u64::from(c)) % MODULI[k]));
+            powers.push([0, 1].map(|k| power[k] * bases[k] % MODULI[k]));
+        }
+        RollingHash { bases, prefix, powers }
+    }
+
+    /// Returns the bases of the hashes.
+    pub fn bases(&self) -> [u64; 2] { self.bases }
+
+    /// Returns the size of the slice.
+    pub fn len(&self) -> usize { self.prefix.len() - 1 }
+
+    /// Returns `true` if the slice is empty.
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the hash of the substring of the slice in `range`.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(1)            |                  |
+    /// | Worst:    | O(1)            | O(1)             |
+    ///
+    /// # Panics
+    /// It panics if `range` is out of the bounds of the slice or its start is greater
+    /// than its end.
+    pub fn hash_of(&self, range: Range<usize>) -> [u64; 2] {
+        assert!(range.start <= range.end && range.end <= self.len(), "range out of bounds");
+        let (start, end) = (self.prefix[range.start], self.prefix[range.end]);
+        let power = self.powers[range.len()];
+        [0, 1].map(|k| (end[k] + MODULI[k] - start[k] * power[k] % MODULI[k]) % MODULI[k])
+    }
+
+    /// Returns `true` if the substrings of the slice in `a` and in `b` have the same size
+    /// and the same hash, which means that they are equal with high probability.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(1)            |                  |
+    /// | Worst:    | O(1)            | O(1)             |
+    ///
+    /// # Panics
+    /// It panics if any of the ranges is out of the bounds of the slice or its start is
+    /// greater than its end.
+    pub fn equal(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        a.len() == b.len() && self.hash_of(a) == self.hash_of(b)
+    }
+
+    /// Returns the hash of `text` with the fixed bases, without keeping the prefixes, the
+    /// same as `RollingHash::new(text).hash_of(0..text.len())`.
+    pub(crate) fn fixed_hash(text: &[u8]) -> [u64; 2] {
+        text.iter().fold([0; 2], |hash, &c| {
+            [0, 1].map(|k| (hash[k] * BASES[k] + u64::from(c)) % MODULI[k])
+        })
+    }
+
+    /// Returns the powers of the fixed bases to `n`.
+    pub(crate) fn fixed_power(n: usize) -> [u64; 2] {
+        (0..n).fold([1; 2], |power, _| [0, 1].map(|k| power[k] * BASES[k] % MODULI[k]))
+    }
+
+    /// Returns the hash with the fixed bases of the window after the one with `hash`,
+    /// where `first` is the element that leaves the window, `next` is the one that enters
+    /// it, and `power` is [`fixed_power`] of the size of the window minus one.
+    ///
+    /// [`fixed_power`]: #method.fixed_power
+    pub(crate) fn fixed_roll(hash: [u64; 2], first: u8, next: u8, power: [u64; 2]) -> [u64; 2] {
+        [0, 1].map(|k| {
+            let rest = hash[k] + MODULI[k] - u64::from(first) * power[k] % MODULI[k];
+            (rest % MODULI[k] * BASES[k] + u64::from(next)) % MODULI[k]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_of_test() {
+        let empty = RollingHash::new(b"");
+        assert!(empty.is_empty());
+        assert_eq!(empty.hash_of(0..0), [0, 0]);
+
+        let hash = RollingHash::new(b"ab");
+        assert_eq!(hash.len(), 2);
+        assert_eq!(hash.hash_of(0..1), [97, 97]);
+        assert_eq!(hash.hash_of(0..2), [97 * 131 + 98, 97 * 137 + 98]);
+        assert_eq!(hash.hash_of(1..2), [98, 98]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn hash_of_out_of_bounds() { RollingHash::new(b"abc").hash_of(1..4); }
+
+    #[test]
+    fn fixed_roll_test() {
+        let mut rng = thread_rng();
+        let text: Vec<u8> = (0..200).map(|_| rng.gen()).collect();
+        let hash = RollingHash::new(&text);
+        for size in 1..10 {
+            let power = RollingHash::fixed_power(size - 1);
+            let mut window = RollingHash::fixed_hash(&text[..size]);
+            for i in 0..=text.len() - size {
+                assert_eq!(window, hash.hash_of(i..i + size));
+                if i + size < text.len() {
+                    window = RollingHash::fixed_roll(window, text[i], text[i + size], power);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn equal_test() {
+        let mut rng = thread_rng();
+        for _ in 0..50 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(1..100)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let hash = RollingHash::randomized(&text);
+            let again = RollingHash::with_bases(&text, hash.bases());
+            for _ in 0..100 {
+                let len = rng.gen_range(0..=text.len());
+                let i = rng.gen_range(0..=text.len() - len);
+                let j = rng.gen_range(0..=text.len() - len);
+                let equal = text[i..i + len] == text[j..j + len];
+                assert_eq!(hash.equal(i..i + len, j..j + len), equal);
+                assert_eq!(hash.hash_of(i..i + len), again.hash_of(i..i + len));
+            }
+        }
+    }
+}
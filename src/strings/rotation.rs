@@ -0,0 +1,88 @@
+//! Module for algorithms about the rotations of slices, the slices with a prefix moved to
+//! the end.
+
+/// **Booth's Algorithm:** Returns the index where the lexicographically least rotation of
+/// `text` starts, the smallest one if there are many.
+///
+/// It goes through `text` twice, keeping the start of the least rotation found and the
+/// failure function of it, like [`failure_function`], to move the start forward when a
+/// smaller element is found without comparing the rotation again.
+///
+/// [`failure_function`]: ../../pattern/fn.failure_function.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::rotation::minimal_rotation;
+///
+/// let text = b"cabbca";
+/// let k = minimal_rotation(text);
+/// assert_eq!(k, 1);
+///
+/// let mut rotated = text.to_vec();
+/// rotated.rotate_left(k);
+/// assert_eq!(rotated, b"abbcac");
+/// ```
+pub fn minimal_rotation<T: Ord>(text: &[T]) -> usize {
+    let n = text.len();
+    let at = |i: usize| &text[i % n];
+    // `fail[j]` is the size of the longest proper border of the first `j + 1` elements of
+    // the rotation at `k`, minus one, `None` if there is none.
+    let mut fail: Vec<Option<usize>> = vec![None; 2 * n];
+    let mut k = 0;
+    for j in 1..2 * n {
+        let c = at(j);
+        let mut i = fail[j - k - 1];
+        while let Some(b) = i {
+            if c == at(k + b + 1) {
+                break;
+            }
+            if c < at(k + b + 1) {
+                k = j - b - 1;
+            }
+            i = fail[b];
+        }
+        match i {
+            Some(b) => fail[j - k] = Some(b + 1),
+            None if c != at(k) => {
+                if c < at(k) {
+                    k = j;
+                }
+                fail[j - k] = None;
+            },
+            None => fail[j - k] = Some(0),
+        }
+    }
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    #[test]
+    fn minimal_rotation_test() {
+        assert_eq!(minimal_rotation::<u8>(b""), 0);
+        assert_eq!(minimal_rotation(b"a"), 0);
+        assert_eq!(minimal_rotation(b"aaaa"), 0);
+        assert_eq!(minimal_rotation(b"baaa"), 1);
+        assert_eq!(minimal_rotation(b"abab"), 0);
+        assert_eq!(minimal_rotation(b"bbaabbaa"), 2);
+        assert_eq!(minimal_rotation(&[3, 1, 2, 1, 1]), 3);
+
+        let mut rng = thread_rng();
+        for _ in 0..500 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(1..40)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let rotation = |k: usize| text[k..].iter().chain(&text[..k]).collect::<Vec<_>>();
+            let naive = (0..text.len()).min_by_key(|&k| rotation(k)).unwrap_or(0);
+            assert_eq!(minimal_rotation(&text), naive);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! String similarity module.
+//!
+//! Scores of how similar two sequences are, from `0.0` when they have nothing in common
+//! to `1.0` when they are equal. They work with slices of any type that can be compared
+//! for equality, use `str::as_bytes` or collect the `char`s of a string to use them with
+//! strings.
+
+/// **Jaro Similarity:** Returns the Jaro similarity of `a` and `b`.
+///
+/// The elements match if they are equal and not further apart than half the size of the
+/// longest slice minus one, each element matching at most one of the other slice. With
+/// `m` matches, of which `t` pairs are out of order, the similarity is the mean of
+/// `m / |a|`, `m / |b|` and `(m - t) / m`.
+///
+/// Two empty slices have similarity `1.0`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(n + m)         |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::similarity::jaro;
+///
+/// assert!((jaro(b"MARTHA", b"MARHTA") - 0.944).abs() < 1e-3);
+/// assert!((jaro(b"DIXON", b"DICKSONX") - 0.767).abs() < 1e-3);
+/// assert_eq!(jaro(b"ABC", b"XYZ"), 0.0);
+/// ```
+pub fn jaro<T: Eq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+    let mut matched_a = vec![false; a.len()];
+    let mut matched_b = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = b.len().min(i + window + 1);
+        for j in start..end {
+            if !matched_b[j] && b[j] == *x {
+                matched_a[i] = true;
+                matched_b[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // The matched elements of both slices in order, each one that differs is half a
+    // transposition.
+    let matched_b = b.iter().zip(&matched_b).filter(|(_, &matched)| matched).map(|(y, _)| y);
+    let half_transpositions = a
+        .iter()
+        .zip(&matched_a)
+        .filter(|(_, &matched)| matched)
+        .zip(matched_b)
+        .filter(|((x, _), y)| *x != *y)
+        .count();
+
+    let m = matches as f64;
+    let t = (half_transpositions / 2) as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// **Jaro-Winkler Similarity:** Returns the Jaro-Winkler similarity of `a` and `b`.
+///
+/// It is the [`jaro`] similarity `j`, increased for the slices with a common prefix of
+/// size `l`, up to 4: `j + l * prefix_weight * (1 - j)`. The usual `prefix_weight` is
+/// `0.1`, and it must be at most `0.25` so the similarity is at most `1.0`.
+///
+/// [`jaro`]: ./fn.jaro.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ(nm)           |                  |
+/// | Worst:    | O(nm)           | O(n + m)         |
+///
+/// # Panics
+/// It panics if `prefix_weight` is not between `0.0` and `0.25`.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::similarity::jaro_winkler;
+///
+/// assert!((jaro_winkler(b"MARTHA", b"MARHTA", 0.1) - 0.961).abs() < 1e-3);
+/// assert!((jaro_winkler(b"DIXON", b"DICKSONX", 0.1) - 0.813).abs() < 1e-3);
+/// ```
+pub fn jaro_winkler<T: Eq>(a: &[T], b: &[T], prefix_weight: f64) -> f64 {
+    assert!((0.0..=0.25).contains(&prefix_weight), "prefix weight out of 0.0..=0.25");
+    let j = jaro(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    j + prefix as f64 * prefix_weight * (1.0 - j)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn close(a: f64, b: f64) -> bool { (a - b).abs() < 1e-3 }
+
+    #[test]
+    fn jaro_test() {
+        assert_eq!(jaro::<u8>(b"", b""), 1.0);
+        assert_eq!(jaro(b"", b"A"), 0.0);
+        assert_eq!(jaro(b"ABC", b"ABC"), 1.0);
+        assert!(close(jaro(b"CRATE", b"TRACE"), 0.733));
+        assert!(close(jaro(b"DWAYNE", b"DUANE"), 0.822));
+        // The elements are too far apart to match.
+        assert_eq!(jaro(b"AB", b"BA"), 0.0);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let similarity = jaro(&a, &b);
+            assert!((0.0..=1.0).contains(&similarity));
+            assert!(close(similarity, jaro(&b, &a)));
+            assert_eq!(jaro(&a, &a), 1.0);
+        }
+    }
+
+    #[test]
+    fn jaro_winkler_test() {
+        assert!(close(jaro_winkler(b"DWAYNE", b"DUANE", 0.1), 0.840));
+        assert_eq!(jaro_winkler(b"MARTHA", b"MARHTA", 0.0), jaro(b"MARTHA", b"MARHTA"));
+        assert_eq!(jaro_winkler(b"ABCD", b"ABCD", 0.25), 1.0);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..20)).map(|_| rng.gen_range(b'a'..b'e')).collect();
+            let similarity = jaro_winkler(&a, &b, 0.25);
+            assert!(similarity >= jaro(&a, &b));
+            assert!(similarity <= 1.0 + 1e-9);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn jaro_winkler_weight_too_big() { jaro_winkler(b"A", b"A", 0.5); }
+}
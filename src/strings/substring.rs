@@ -0,0 +1,277 @@
+//! Module for algorithms about the substrings of slices, the runs of consecutive elements
+//! of them.
+
+use std::ops::Range;
+
+use super::{
+    suffix_array::{lcp_kasai, suffix_array},
+    suffix_automaton::SuffixAutomaton,
+};
+
+/// **Longest Common Substring:** Returns one of the longest common substrings of `a` and
+/// `b`, with the index where it starts in `a` and in `b`.
+///
+/// It builds the suffix automaton of `a`, and walks it with `b`, keeping the longest
+/// suffix of what was read of `b` that is a substring of `a`, and going to the suffix
+/// link of the state when there is no transition for the next element. So it only takes
+/// linear time and memory, unlike the dynamic programming, even for slices with millions
+/// of elements.
+///
+/// If there is more than one, it returns the one that ends first in `b`. If there is no
+/// common element, the substring is empty and both indexes are `0`.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n + m)        |                  |
+/// | Avrg:     | Θ((n + m)log σ) |                  |
+/// | Worst:    | O((n + m)log σ) | O(n)             |
+///
+/// Where `n` is the size of `a` and `σ` the number of distinct elements of `a`.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::substring::longest_common_substring;
+///
+/// let (common, i, j) = longest_common_substring(b"xabcdey", b"zzbcdez");
+/// assert_eq!(common, b"bcde");
+/// assert_eq!((i, j), (2, 2));
+///
+/// let (common, i, j) = longest_common_substring(b"GATTACA", b"TACATAG");
+/// assert_eq!(common, b"TACA");
+/// assert_eq!((i, j), (3, 0));
+/// ```
+pub fn longest_common_substring<'a, T: Ord + Clone>(
+    a: &'a [T], b: &[T],
+) -> (&'a [T], usize, usize) {
+    let sam = SuffixAutomaton::new(a);
+    let states = sam.states();
+
+    // The longest common substring found, as its size, its state and where it ends in `b`.
+    let (mut best, mut best_state, mut best_end) = (0, 0, 0);
+    let (mut state, mut len) = (0, 0);
+    for (j, c) in b.iter().enumerate() {
+        loop {
+            if let Some(next) = sam.next(state, c) {
+                state = next;
+                len += 1;
+                break;
+            }
+            match states[state].link {
+                Some(link) => {
+                    state = link;
+                    len = states[link].len;
+                },
+                None => break,
+            }
+        }
+        if len > best {
+            best = len;
+            best_state = state;
+            best_end = j;
+        }
+    }
+
+    if best == 0 {
+        return (&a[..0], 0, 0);
+    }
+    let start = states[best_state].first_end + 1 - best;
+    (&a[start..start + best], start, best_end + 1 - best)
+}
+
+/// **Longest Repeated Substring:** Returns the range of the first occurrence of one of the
+/// longest substrings that occur more than once in `text`, the occurrences may overlap.
+///
+/// It is the longest common prefix of two suffixes next to each other in the order of the
+/// suffixes, the maximum of the LCP array, so it uses the [`suffix_array`] and
+/// [`lcp_kasai`].
+///
+/// [`suffix_array`]: ../suffix_array/fn.suffix_array.html
+/// [`lcp_kasai`]: ../suffix_array/fn.lcp_kasai.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::substring::longest_repeated_substring;
+///
+/// let text = b"banana";
+/// assert_eq!(longest_repeated_substring(text), 1..4);
+/// assert_eq!(&text[1..4], b"ana");
+/// ```
+pub fn longest_repeated_substring(text: &[u8]) -> Range<usize> {
+    let sa = suffix_array(text);
+    let lcp = lcp_kasai(text, &sa);
+
+    // The pair of suffixes with the longest common prefix, with the smallest index.
+    let mut best = 0..0;
+    for k in 1..sa.len() {
+        let start = sa[k].min(sa[k - 1]);
+        if lcp[k] > best.len() || (lcp[k] == best.len() && lcp[k] > 0 && start < best.start) {
+            best = start..start + lcp[k];
+        }
+    }
+    best
+}
+
+/// Returns the range of the first occurrence of one of the longest substrings that occur
+/// more than once in `text` without overlapping.
+///
+/// If a substring occurs twice without overlapping, so do all its prefixes, so it does a
+/// binary search on the size of the substring. For each size, the suffixes that start
+/// with the same substring of that size are runs in the [`suffix_array`] where the
+/// [`lcp_kasai`] is at least the size, and the substring occurs twice without overlapping
+/// if the first and the last suffix of a run are at least that far apart.
+///
+/// [`suffix_array`]: ../suffix_array/fn.suffix_array.html
+/// [`lcp_kasai`]: ../suffix_array/fn.lcp_kasai.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n log n)      |                  |
+/// | Avrg:     | Θ(n log n)      |                  |
+/// | Worst:    | O(n log n)      | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::substring::longest_repeated_substring_non_overlapping;
+///
+/// let text = b"banana";
+/// let range = longest_repeated_substring_non_overlapping(text);
+/// assert_eq!(&text[range], b"an");
+///
+/// // "aaa" occurs twice, but overlapping.
+/// assert_eq!(longest_repeated_substring_non_overlapping(b"aaaa"), 0..2);
+/// ```
+pub fn longest_repeated_substring_non_overlapping(text: &[u8]) -> Range<usize> {
+    let sa = suffix_array(text);
+    let lcp = lcp_kasai(text, &sa);
+
+    // Returns the smallest index of a substring of size `len` that occurs twice without
+    // overlapping, if any.
+    let occurs_twice = |len: usize| -> Option<usize> {
+        let mut found: Option<usize> = None;
+        let (mut first, mut last) = (usize::MAX, 0);
+        for k in 0..sa.len() {
+            if k == 0 || lcp[k] < len {
+                first = sa[k];
+                last = sa[k];
+            } else {
+                first = first.min(sa[k]);
+                last = last.max(sa[k]);
+                if last - first >= len {
+                    found = Some(found.map_or(first, |i| i.min(first)));
+                }
+            }
+        }
+        found
+    };
+
+    // The size found so far, with its index, and the smallest size known not to occur.
+    let (mut best, mut start) = (0, 0);
+    let mut high = text.len() / 2 + 1;
+    while best + 1 < high {
+        let mid = best + (high - best) / 2;
+        match occurs_twice(mid) {
+            Some(i) => {
+                best = mid;
+                start = i;
+            },
+            None => high = mid,
+        }
+    }
+    start..start + best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    /// Returns the size of the longest common substring using the dynamic programming.
+    fn naive<T: Eq>(a: &[T], b: &[T]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        let mut best = 0;
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                if a[i] == b[j] {
+                    table[i + 1][j + 1] = table[i][j] + 1;
+                    best = best.max(table[i + 1][j + 1]);
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn longest_common_substring_test() {
+        assert_eq!(longest_common_substring::<u8>(b"", b""), (&b""[..], 0, 0));
+        assert_eq!(longest_common_substring(b"ABC", b""), (&b""[..], 0, 0));
+        assert_eq!(longest_common_substring(b"ABC", b"DEF"), (&b""[..], 0, 0));
+        assert_eq!(longest_common_substring(b"ABC", b"ABC"), (&b"ABC"[..], 0, 0));
+        assert_eq!(longest_common_substring(b"AAAA", b"BAAB"), (&b"AA"[..], 0, 1));
+        assert_eq!(longest_common_substring(&[1, 2, 3, 4], &[4, 2, 3]), (&[2, 3][..], 1, 1));
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let a: Vec<u8> = (0..rng.gen_range(0..60)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..60)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let (common, i, j) = longest_common_substring(&a, &b);
+            assert_eq!(common.len(), naive(&a, &b));
+            assert_eq!(&a[i..i + common.len()], common);
+            assert_eq!(&b[j..j + common.len()], common);
+        }
+    }
+
+    #[test]
+    fn longest_common_substring_long_test() {
+        let mut rng = thread_rng();
+        let mut a: Vec<u8> = (0..200_000).map(|_| rng.gen()).collect();
+        let b: Vec<u8> = (0..200_000).map(|_| rng.gen()).collect();
+        a[150_000..151_000].copy_from_slice(&b[10_000..11_000]);
+
+        let (common, i, j) = longest_common_substring(&a, &b);
+        assert!(common.len() >= 1000);
+        assert_eq!(&a[i..i + common.len()], common);
+        assert_eq!(&b[j..j + common.len()], common);
+    }
+
+    #[test]
+    fn longest_repeated_substring_test() {
+        assert_eq!(longest_repeated_substring(b""), 0..0);
+        assert_eq!(longest_repeated_substring(b"abc"), 0..0);
+        assert_eq!(longest_repeated_substring(b"aaaa"), 0..3);
+        assert_eq!(longest_repeated_substring(b"abcXabcYbc"), 0..3);
+        assert_eq!(longest_repeated_substring_non_overlapping(b""), 0..0);
+        assert_eq!(longest_repeated_substring_non_overlapping(b"abc"), 0..0);
+        assert_eq!(longest_repeated_substring_non_overlapping(b"abcabc"), 0..3);
+        assert_eq!(longest_repeated_substring_non_overlapping(b"aaaaa"), 0..2);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'c')).collect();
+            let n = text.len();
+            // The longest common prefix of the suffixes at `i` and `j`, `i < j`.
+            let common = |i: usize, j: usize| {
+                text[i..].iter().zip(&text[j..]).take_while(|(a, b)| a == b).count()
+            };
+            let pairs = || (0..n).flat_map(move |i| (i + 1..n).map(move |j| (i, j)));
+
+            let overlapping = pairs().map(|(i, j)| common(i, j)).max().unwrap_or(0);
+            let range = longest_repeated_substring(&text);
+            assert_eq!(range.len(), overlapping);
+            let sub = &text[range.clone()];
+            assert!(overlapping == 0 || text.windows(sub.len()).filter(|w| *w == sub).count() >= 2);
+
+            let non_overlapping = pairs().map(|(i, j)| common(i, j).min(j - i)).max().unwrap_or(0);
+            let range = longest_repeated_substring_non_overlapping(&text);
+            assert_eq!(range.len(), non_overlapping);
+            let sub = &text[range.clone()];
+            assert!(non_overlapping == 0 || text[range.end..].windows(sub.len()).any(|w| w == sub));
+        }
+    }
+}
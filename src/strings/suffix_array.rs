@@ -0,0 +1,348 @@
+//! Suffix array module.
+//!
+//! The suffix array of a slice has the indexes of all its suffixes, in the order of the
+//! suffixes, so every substring is a prefix of a run of consecutive suffixes of it.
+
+use std::ops::Range;
+
+/// The mark of a position of the suffix array that was not filled yet.
+const EMPTY: usize = usize::MAX;
+
+/// **SA-IS:** Returns the suffix array of `text`, the index of each suffix of it in the
+/// crescent order of the suffixes.
+///
+/// It uses the induced sorting: it classifies each suffix as S if it is smaller than the
+/// next one or L otherwise, sorts the substrings between the leftmost S suffixes (the LMS
+/// substrings), which sorts all the other suffixes from them, and if two of them are
+/// equal, sorts the LMS suffixes by solving the same problem for the slice of the names of
+/// the LMS substrings, which is at most half the size.
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Example
+/// ```rust
+/// use algos::strings::suffix_array::suffix_array;
+///
+/// // The suffixes in order are "a", "ana", "anana", "banana", "na" and "nana".
+/// assert_eq!(suffix_array(b"banana"), [5, 3, 1, 0, 4, 2]);
+/// ```
+pub fn suffix_array(text: &[u8]) -> Vec<usize> {
+    let text: Vec<usize> = text.iter().map(|&c| c as usize).collect();
+    sa_is(&text, u8::MAX as usize)
+}
+
+/// Returns the suffix array of `s`, where all the elements are at most `upper`.
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return Vec::new(),
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {},
+    }
+
+    // `is_s[i]` tells if the suffix at `i` is an S suffix, the last one is an L suffix.
+    let mut is_s = vec![false; n];
+    for i in (0..n - 1).rev() {
+        is_s[i] = if s[i] == s[i + 1] { is_s[i + 1] } else { s[i] < s[i + 1] };
+    }
+
+    // Where the L suffixes and the S suffixes of each element start in the suffix array.
+    let mut start_l = vec![0; upper + 2];
+    let mut start_s = vec![0; upper + 1];
+    for i in 0..n {
+        if is_s[i] {
+            start_l[s[i] + 1] += 1;
+        } else {
+            start_s[s[i]] += 1;
+        }
+    }
+    for c in 0..=upper {
+        start_s[c] += start_l[c];
+        start_l[c + 1] += start_s[c];
+    }
+
+    let is_lms = |i: usize| i > 0 && !is_s[i - 1] && is_s[i];
+    let induce = |lms: &[usize], sa: &mut Vec<usize>| {
+        sa.clear();
+        sa.resize(n, EMPTY);
+
+        // The LMS suffixes go to the start of the S suffixes of their element, in order.
+        let mut bucket = start_s.clone();
+        for &i in lms {
+            sa[bucket[s[i]]] = i;
+            bucket[s[i]] += 1;
+        }
+
+        // The L suffixes are sorted from left to right, after the suffix that follows it.
+        bucket.copy_from_slice(&start_l[..=upper]);
+        sa[bucket[s[n - 1]]] = n - 1;
+        bucket[s[n - 1]] += 1;
+        for k in 0..n {
+            let i = sa[k];
+            if i != EMPTY && i > 0 && !is_s[i - 1] {
+                sa[bucket[s[i - 1]]] = i - 1;
+                bucket[s[i - 1]] += 1;
+            }
+        }
+
+        // And the S suffixes from right to left, replacing the LMS suffixes.
+        bucket.copy_from_slice(&start_l[1..]);
+        for k in (0..n).rev() {
+            let i = sa[k];
+            if i != EMPTY && i > 0 && is_s[i - 1] {
+                bucket[s[i - 1]] -= 1;
+                sa[bucket[s[i - 1]]] = i - 1;
+            }
+        }
+    };
+
+    // The index of each LMS suffix in the order they are in `s`.
+    let mut lms_index = vec![EMPTY; n];
+    let lms: Vec<usize> = (1..n).filter(|&i| is_lms(i)).collect();
+    for (k, &i) in lms.iter().enumerate() {
+        lms_index[i] = k;
+    }
+
+    let mut sa = Vec::with_capacity(n);
+    induce(&lms, &mut sa);
+    if lms.is_empty() {
+        return sa;
+    }
+
+    // The LMS substrings sorted, each one goes to the next LMS suffix, or the end.
+    let sorted_lms: Vec<usize> = sa.iter().copied().filter(|&i| lms_index[i] != EMPTY).collect();
+    let end = |i: usize| lms.get(lms_index[i] + 1).copied().unwrap_or(n);
+    let mut names = vec![0; lms.len()];
+    let mut name = 0;
+    for w in sorted_lms.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        // A substring that goes to the end is smaller than any other, so never equal.
+        let same = end(a) < n && end(b) < n && s[a..=end(a)] == s[b..=end(b)];
+        if !same {
+            name += 1;
+        }
+        names[lms_index[b]] = name;
+    }
+
+    let sorted_lms: Vec<usize> = sa_is(&names, name).into_iter().map(|k| lms[k]).collect();
+    induce(&sorted_lms, &mut sa);
+    sa
+}
+
+/// **Kasai's Algorithm:** Returns the LCP array of `text`, where the value at `i` is the
+/// length of the longest common prefix of the suffixes at `sa[i - 1]` and at `sa[i]`, and
+/// the value at `0` is `0`.
+///
+/// `sa` must be the [`suffix_array`] of `text`. It goes through the suffixes from the
+/// longest, where the common prefix with the previous suffix in the order is at least the
+/// one of the suffix before it minus one, so it only increases the prefix at most `2n`
+/// times.
+///
+/// It works with slices of any type that can be compared for equality.
+///
+/// [`suffix_array`]: ./fn.suffix_array.html
+///
+/// |   Case    | Time complexity | Space complexity |
+/// |:----------|:---------------:|:----------------:|
+/// | Best:     | Ω(n)            |                  |
+/// | Avrg:     | Θ(n)            |                  |
+/// | Worst:    | O(n)            | O(n)             |
+///
+/// # Panics
+/// It may panic if `sa` is not a permutation of the indexes of `text`.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::suffix_array::{lcp_kasai, suffix_array};
+///
+/// let text = b"banana";
+/// let sa = suffix_array(text);
+/// assert_eq!(lcp_kasai(text, &sa), [0, 1, 3, 0, 0, 2]);
+/// ```
+pub fn lcp_kasai<T: Eq>(text: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut rank = vec![0; n];
+    for (k, &i) in sa.iter().enumerate() {
+        rank[i] = k;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && text[i + h] == text[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// A slice with its suffix array and LCP array, to search for substrings of it.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::suffix_array::SuffixArray;
+///
+/// let sa = SuffixArray::new(b"abracadabra");
+/// assert_eq!(sa.find_all(b"abra"), [0, 7]);
+/// assert_eq!(sa.find_all(b"a"), [0, 3, 5, 7, 10]);
+/// assert!(sa.contains(b"cad"));
+/// assert!(!sa.contains(b"dab!"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SuffixArray {
+    text: Vec<u8>,
+    sa:   Vec<usize>,
+    lcp:  Vec<usize>,
+}
+
+impl SuffixArray {
+    /// Creates a new `SuffixArray` for `text`, computing its [`suffix_array`] and
+    /// [`lcp_kasai`].
+    ///
+    /// [`suffix_array`]: ./fn.suffix_array.html
+    /// [`lcp_kasai`]: ./fn.lcp_kasai.html
+    pub fn new(text: &[u8]) -> Self {
+        let sa = suffix_array(text);
+        let lcp = lcp_kasai(text, &sa);
+        SuffixArray { text: text.to_vec(), sa, lcp }
+    }
+
+    /// Returns the slice.
+    pub fn text(&self) -> &[u8] { &self.text }
+
+    /// Returns the suffix array of the slice.
+    pub fn suffixes(&self) -> &[usize] { &self.sa }
+
+    /// Returns the LCP array of the slice.
+    pub fn lcp(&self) -> &[usize] { &self.lcp }
+
+    /// Returns the range of the suffix array with the suffixes that start with `pattern`.
+    ///
+    /// It does two binary searches, where each comparison takes up to `m` steps.
+    fn range(&self, pattern: &[u8]) -> Range<usize> {
+        let prefix = |i: usize| &self.text[i..self.text.len().min(i + pattern.len())];
+        let start = self.sa.partition_point(|&i| prefix(i) < pattern);
+        let end = start + self.sa[start..].partition_point(|&i| prefix(i) == pattern);
+        start..end
+    }
+
+    /// Returns `true` if `pattern` is a substring of the slice.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(m log n)      |                  |
+    /// | Worst:    | O(m log n)      | O(1)             |
+    pub fn contains(&self, pattern: &[u8]) -> bool { !self.range(pattern).is_empty() }
+
+    /// Search for all the occurrences of `pattern` in the slice, including the ones that
+    /// overlap.
+    ///
+    /// It returns the indexes of the first character of each one, in crescent order.
+    ///
+    /// |   Case    |   Time complexity    | Space complexity |
+    /// |:----------|:--------------------:|:----------------:|
+    /// | Best:     | Ω(1)                 |                  |
+    /// | Avrg:     | Θ(m log n + k log k) |                  |
+    /// | Worst:    | O(m log n + k log k) | O(k)             |
+    ///
+    /// Where `k` is the number of occurrences.
+    pub fn find_all(&self, pattern: &[u8]) -> Vec<usize> {
+        let mut found = self.sa[self.range(pattern)].to_vec();
+        found.sort_unstable();
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+
+    fn naive(text: &[u8]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..text.len()).collect();
+        sa.sort_by_key(|&i| &text[i..]);
+        sa
+    }
+
+    #[test]
+    fn suffix_array_test() {
+        assert_eq!(suffix_array(b""), []);
+        assert_eq!(suffix_array(b"a"), [0]);
+        assert_eq!(suffix_array(b"aaaa"), [3, 2, 1, 0]);
+        assert_eq!(suffix_array(b"abcd"), [0, 1, 2, 3]);
+        assert_eq!(suffix_array(b"mississippi"), [10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2]);
+
+        let mut rng = thread_rng();
+        for _ in 0..500 {
+            let high = rng.gen_range(b'a'..=b'z');
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..100)).map(|_| rng.gen_range(b'a'..=high)).collect();
+            assert_eq!(suffix_array(&text), naive(&text));
+        }
+
+        let text: Vec<u8> = (0..5000).map(|_| rng.gen()).collect();
+        assert_eq!(suffix_array(&text), naive(&text));
+    }
+
+    #[test]
+    fn lcp_kasai_test() {
+        assert_eq!(lcp_kasai::<u8>(b"", &[]), []);
+        assert_eq!(lcp_kasai(b"aaaa", &suffix_array(b"aaaa")), [0, 1, 2, 3]);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..100)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let sa = suffix_array(&text);
+            let lcp = lcp_kasai(&text, &sa);
+            for k in 1..sa.len() {
+                let common =
+                    text[sa[k - 1]..].iter().zip(&text[sa[k]..]).take_while(|(a, b)| a == b);
+                assert_eq!(lcp[k], common.count());
+            }
+        }
+    }
+
+    #[test]
+    fn find_all_test() {
+        let sa = SuffixArray::new(b"mississippi");
+        assert_eq!(sa.text(), b"mississippi");
+        assert_eq!(sa.find_all(b"ssi"), [2, 5]);
+        assert_eq!(sa.find_all(b"issi"), [1, 4]);
+        assert_eq!(sa.find_all(b"ippi"), [7]);
+        assert_eq!(sa.find_all(b"ppii"), []);
+        assert_eq!(sa.find_all(b"z"), []);
+        assert_eq!(sa.find_all(b""), (0..11).collect::<Vec<_>>());
+        assert_eq!(SuffixArray::new(b"").find_all(b"a"), []);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..200)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let expected: Vec<usize> = text
+                .windows(find.len())
+                .enumerate()
+                .filter(|(_, w)| *w == &find[..])
+                .map(|(i, _)| i)
+                .collect();
+            let sa = SuffixArray::new(&text);
+            assert_eq!(sa.find_all(&find), expected);
+            assert_eq!(sa.contains(&find), !expected.is_empty());
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! Suffix automaton module.
+
+/// The smallest automaton that accepts every suffix of a slice, where every substring of
+/// the slice is a path from the root.
+///
+/// Each state is the set of substrings that end at the same positions of the slice, and
+/// its suffix link goes to the state of the longest suffix of them that is not in it. It
+/// is built online, one element at a time, with at most `2n` states and `3n` transitions.
+///
+/// It works with slices of any type that can be ordered, the transitions of each state
+/// are sorted to find them with a binary search.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::suffix_automaton::SuffixAutomaton;
+///
+/// let mut sam = SuffixAutomaton::new(b"abcb");
+/// assert!(sam.contains(b"bcb"));
+/// assert!(!sam.contains(b"bb"));
+/// assert_eq!(sam.distinct_substrings(), 9);
+///
+/// sam.push(b'c');
+/// assert_eq!(sam.first_occurrence(b"bc"), Some(1));
+/// assert_eq!(sam.find_all(b"bc"), [1, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton<T = u8> {
+    states: Vec<State<T>>,
+    /// The state of the whole slice.
+    last:   usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct State<T> {
+    /// The size of the longest substring of the state.
+    pub(crate) len:       usize,
+    /// The state of the longest suffix of the substrings of the state that is in another
+    /// state. Only the root has none.
+    pub(crate) link:      Option<usize>,
+    /// The index of the last element of the first occurrence of the substrings of the
+    /// state.
+    pub(crate) first_end: usize,
+    /// If the state was split from another one, otherwise it is the state of a prefix of
+    /// the slice.
+    is_clone:             bool,
+    /// The transitions, sorted by the element.
+    next:                 Vec<(T, usize)>,
+}
+
+impl<T: Ord + Clone> SuffixAutomaton<T> {
+    /// Creates a new `SuffixAutomaton` for `text`.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(n)            |                  |
+    /// | Avrg:     | Θ(n log σ)      |                  |
+    /// | Worst:    | O(n log σ)      | O(n)             |
+    ///
+    /// Where `σ` is the number of distinct elements of `text`.
+    pub fn new(text: &[T]) -> Self {
+        let root = State { len: 0, link: None, first_end: 0, is_clone: false, next: Vec::new() };
+        let mut sam = SuffixAutomaton { states: Vec::with_capacity(2 * text.len() + 1), last: 0 };
+        sam.states.push(root);
+        for c in text {
+            sam.extend(c);
+        }
+        sam
+    }
+
+    /// Adds `c` to the end of the slice.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(log σ)        |                  |
+    /// | Worst:    | O(n log σ)      | O(1)             |
+    ///
+    /// The average is amortized over the elements of the slice.
+    pub fn push(&mut self, c: T) { self.extend(&c); }
+
+    /// Returns the size of the slice.
+    pub fn len(&self) -> usize { self.states[self.last].len }
+
+    /// Returns `true` if the slice is empty.
+    pub fn is_empty(&self) -> bool { self.last == 0 }
+
+    /// Returns the number of states of the automaton, including the root.
+    pub fn states_len(&self) -> usize { self.states.len() }
+
+    /// Returns the state reached from the root with `pattern`, if `pattern` is a substring
+    /// of the slice.
+    fn walk(&self, pattern: &[T]) -> Option<usize> {
+        pattern.iter().try_fold(0, |state, c| self.next(state, c))
+    }
+
+    /// Returns `true` if `pattern` is a substring of the slice.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(m log σ)      |                  |
+    /// | Worst:    | O(m log σ)      | O(1)             |
+    pub fn contains(&self, pattern: &[T]) -> bool { self.walk(pattern).is_some() }
+
+    /// Returns the number of distinct non-empty substrings of the slice.
+    ///
+    /// Each state has the suffixes of its longest substring that are longer than the
+    /// longest one of its suffix link.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(n)            |                  |
+    /// | Avrg:     | Θ(n)            |                  |
+    /// | Worst:    | O(n)            | O(1)             |
+    pub fn distinct_substrings(&self) -> usize {
+        let states = &self.states;
+        states.iter().filter_map(|st| st.link.map(|link| st.len - states[link].len)).sum()
+    }
+
+    /// Returns the index of the first occurrence of `pattern` in the slice, or `None` if
+    /// not find.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(m log σ)      |                  |
+    /// | Worst:    | O(m log σ)      | O(1)             |
+    pub fn first_occurrence(&self, pattern: &[T]) -> Option<usize> {
+        let state = self.walk(pattern)?;
+        if pattern.is_empty() {
+            return Some(0);
+        }
+        Some(self.states[state].first_end + 1 - pattern.len())
+    }
+
+    /// Search for all the occurrences of `pattern` in the slice, including the ones that
+    /// overlap.
+    ///
+    /// It returns the indexes of the first character of each one, in crescent order.
+    ///
+    /// Every prefix of the slice that ends with `pattern` has a state that reaches the one
+    /// of `pattern` by the suffix links, so it goes through the tree of the suffix links,
+    /// which is built for each call.
+    ///
+    /// |   Case    |   Time complexity    | Space complexity |
+    /// |:----------|:--------------------:|:----------------:|
+    /// | Best:     | Ω(m)                 |                  |
+    /// | Avrg:     | Θ(n + m log σ)       |                  |
+    /// | Worst:    | O(n + m log σ)       | O(n)             |
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize> {
+        let target = match self.walk(pattern) {
+            Some(state) => state,
+            None => return Vec::new(),
+        };
+        if pattern.is_empty() {
+            return (0..=self.len()).collect();
+        }
+
+        // The children of each state in the tree of the suffix links, as linked lists.
+        let mut first_child = vec![None; self.states.len()];
+        let mut next_sibling = vec![None; self.states.len()];
+        for (i, st) in self.states.iter().enumerate() {
+            if let Some(link) = st.link {
+                next_sibling[i] = first_child[link];
+                first_child[link] = Some(i);
+            }
+        }
+
+        let mut found = Vec::new();
+        let mut stack = vec![target];
+        while let Some(state) = stack.pop() {
+            if !self.states[state].is_clone {
+                found.push(self.states[state].first_end + 1 - pattern.len());
+            }
+            let mut child = first_child[state];
+            while let Some(c) = child {
+                stack.push(c);
+                child = next_sibling[c];
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// Returns the states, the root is the first one.
+    pub(crate) fn states(&self) -> &[State<T>] { &self.states }
+
+    /// Returns the state reached from `state` with `c`, if any.
+    pub(crate) fn next(&self, state: usize, c: &T) -> Option<usize> {
+        let next = &self.states[state].next;
+        next.binary_search_by(|(x, _)| x.cmp(c)).ok().map(|i| next[i].1)
+    }
+
+    /// Sets the transition from `state` with `c` to `to`.
+    fn set_next(&mut self, state: usize, c: &T, to: usize) {
+        let next = &mut self.states[state].next;
+        match next.binary_search_by(|(x, _)| x.cmp(c)) {
+            Ok(i) => next[i].1 = to,
+            Err(i) => next.insert(i, (c.clone(), to)),
+        }
+    }
+
+    /// Adds `c` to the end of the automaton.
+    fn extend(&mut self, c: &T) {
+        let cur = self.states.len();
+        let len = self.states[self.last].len + 1;
+        self.states.push(State {
+            len,
+            link: Some(0),
+            first_end: len - 1,
+            is_clone: false,
+            next: Vec::new(),
+        });
+
+        // Every suffix of the slice without a transition with `c` now has one to `cur`.
+        let mut p = Some(self.last);
+        while let Some(state) = p {
+            if self.next(state, c).is_some() {
+                break;
+            }
+            self.set_next(state, c, cur);
+            p = self.states[state].link;
+        }
+
+        if let Some(p) = p {
+            let q = self.next(p, c).unwrap_or(0);
+            if self.states[p].len + 1 == self.states[q].len {
+                self.states[cur].link = Some(q);
+            } else {
+                // `q` has substrings that are not suffixes of the slice with `c`, so it is
+                // split in two, the clone with the ones that are.
+                let clone = self.states.len();
+                let len = self.states[p].len + 1;
+                self.states.push(State { len, is_clone: true, ..self.states[q].clone() });
+                let mut p = Some(p);
+                while let Some(state) = p {
+                    if self.next(state, c) != Some(q) {
+                        break;
+                    }
+                    self.set_next(state, c, clone);
+                    p = self.states[state].link;
+                }
+                self.states[q].link = Some(clone);
+                self.states[cur].link = Some(clone);
+            }
+        }
+        self.last = cur;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn distinct_substrings_test() {
+        assert_eq!(SuffixAutomaton::<u8>::new(b"").distinct_substrings(), 0);
+        assert_eq!(SuffixAutomaton::new(b"aaaa").distinct_substrings(), 4);
+        assert_eq!(SuffixAutomaton::new(b"abcd").distinct_substrings(), 10);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let text: Vec<u8> =
+                (0..rng.gen_range(0..50)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let naive: BTreeSet<&[u8]> = (0..text.len())
+                .flat_map(|i| (i + 1..=text.len()).map(move |j| (i, j)))
+                .map(|(i, j)| &text[i..j])
+                .collect();
+            let sam = SuffixAutomaton::new(&text);
+            assert_eq!(sam.distinct_substrings(), naive.len());
+            assert!(sam.states_len() <= 2 * text.len().max(1));
+        }
+    }
+
+    #[test]
+    fn find_all_test() {
+        let sam = SuffixAutomaton::new(b"mississippi");
+        assert_eq!(sam.len(), 11);
+        assert!(sam.contains(b""));
+        assert!(sam.contains(b"sissi"));
+        assert!(!sam.contains(b"sis sippi"));
+        assert_eq!(sam.first_occurrence(b"ssi"), Some(2));
+        assert_eq!(sam.first_occurrence(b"pp"), Some(8));
+        assert_eq!(sam.first_occurrence(b"ms"), None);
+        assert_eq!(sam.find_all(b"ssi"), [2, 5]);
+        assert_eq!(sam.find_all(b"i"), [1, 4, 7, 10]);
+        assert_eq!(sam.find_all(b"x"), []);
+        assert_eq!(SuffixAutomaton::new(b"ab").find_all(b""), [0, 1, 2]);
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let mut sam = SuffixAutomaton::new(&[]);
+            let mut text = Vec::new();
+            for _ in 0..rng.gen_range(0..200) {
+                let c = rng.gen_range(b'a'..b'd');
+                text.push(c);
+                sam.push(c);
+            }
+            assert_eq!(sam.len(), text.len());
+            assert_eq!(sam.is_empty(), text.is_empty());
+
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let expected: Vec<usize> = text
+                .windows(find.len())
+                .enumerate()
+                .filter(|(_, w)| *w == &find[..])
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(sam.contains(&find), !expected.is_empty());
+            assert_eq!(sam.first_occurrence(&find), expected.first().copied());
+            assert_eq!(sam.find_all(&find), expected);
+        }
+    }
+}
@@ -0,0 +1,425 @@
+//! Suffix tree module.
+
+use std::ops::Range;
+
+use crate::pattern::{KmpPattern, Matcher};
+
+/// The compressed trie of all the suffixes of a slice, where every substring of the slice
+/// is a path from the root.
+///
+/// It is built online with Ukkonen's algorithm, one element at a time. There is no
+/// terminator at the end of the slice, so the suffixes that are also a prefix of another
+/// suffix end inside of an edge instead of in a leaf, and the others end in a leaf, that
+/// grows with the slice.
+///
+/// It works with slices of any type that can be ordered, the children of each node are
+/// sorted to find them with a binary search.
+///
+/// # Example
+/// ```rust
+/// use algos::strings::suffix_tree::SuffixTree;
+///
+/// let mut tree = SuffixTree::new(b"banana");
+/// tree.push(b's');
+/// assert!(tree.contains(b"nan"));
+/// assert_eq!(tree.find_all(b"an"), [1, 3]);
+/// assert_eq!(&tree.text()[tree.longest_repeated_substring()], b"ana");
+///
+/// let text = tree.text();
+/// let mut repeats: Vec<&[u8]> = tree.internal_nodes().map(|node| &text[node.range()]).collect();
+/// repeats.sort();
+/// assert_eq!(repeats, [&b"a"[..], b"ana", b"na"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixTree<T = u8> {
+    text:        Vec<T>,
+    nodes:       Vec<Node<T>>,
+    /// Where the longest suffix that is not in a leaf ends: `active_len` elements in the
+    /// edge of `active_node` that starts with the element at `active_edge`.
+    active_node: usize,
+    active_edge: usize,
+    active_len:  usize,
+    /// The number of suffixes that are not in a leaf.
+    remainder:   usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    /// The edge from the parent is `text[start..end]`, a leaf ends at the end of `text`.
+    start:    usize,
+    end:      Option<usize>,
+    /// The index of an occurrence of the path to the node, the suffix of a leaf.
+    suffix:   usize,
+    /// The size of the path to the node, for the nodes that are not leaves.
+    depth:    usize,
+    /// The node of the path without its first element.
+    link:     usize,
+    /// The children, sorted by the first element of their edge.
+    children: Vec<(T, usize)>,
+}
+
+impl<T: Ord + Clone> SuffixTree<T> {
+    /// Creates a new `SuffixTree` for `text`.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(n)            |                  |
+    /// | Avrg:     | Θ(n log σ)      |                  |
+    /// | Worst:    | O(n log σ)      | O(n)             |
+    ///
+    /// Where `σ` is the number of distinct elements of `text`.
+    pub fn new(text: &[T]) -> Self {
+        let mut tree = SuffixTree {
+            text:        Vec::with_capacity(text.len()),
+            nodes:       Vec::with_capacity(2 * text.len() + 1),
+            active_node: 0,
+            active_edge: 0,
+            active_len:  0,
+            remainder:   0,
+        };
+        tree.new_node(0, Some(0), 0, 0);
+        for c in text {
+            tree.push(c.clone());
+        }
+        tree
+    }
+
+    /// Returns the slice.
+    pub fn text(&self) -> &[T] { &self.text }
+
+    /// Adds `c` to the end of the slice.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(log σ)        |                  |
+    /// | Worst:    | O(n log σ)      | O(1)             |
+    ///
+    /// The average is amortized over the elements of the slice.
+    pub fn push(&mut self, c: T) {
+        let pos = self.text.len();
+        self.text.push(c);
+        self.remainder += 1;
+        // The last node created in this step, that still needs its suffix link.
+        let mut last_new: Option<usize> = None;
+
+        while self.remainder > 0 {
+            if self.active_len == 0 {
+                self.active_edge = pos;
+            }
+            let suffix = pos + 1 - self.remainder;
+            let edge = self.text[self.active_edge].clone();
+            match self.child(self.active_node, &edge) {
+                None => {
+                    let leaf = self.new_node(pos, None, suffix, 0);
+                    self.set_child(self.active_node, leaf);
+                    if let Some(node) = last_new.take() {
+                        self.nodes[node].link = self.active_node;
+                    }
+                },
+                Some(next) => {
+                    // Walks down to the node if the edge is not longer than the suffix.
+                    let len = self.edge_len(next);
+                    if self.active_len >= len {
+                        self.active_edge += len;
+                        self.active_len -= len;
+                        self.active_node = next;
+                        continue;
+                    }
+
+                    // The suffix is already in the tree, and so are all the shorter ones.
+                    if self.text[self.nodes[next].start + self.active_len] == self.text[pos] {
+                        if let Some(node) = last_new.take() {
+                            self.nodes[node].link = self.active_node;
+                        }
+                        self.active_len += 1;
+                        break;
+                    }
+
+                    // Splits the edge where the suffix ends, with a new leaf for it.
+                    let start = self.nodes[next].start;
+                    let depth = self.nodes[self.active_node].depth + self.active_len;
+                    let split = self.new_node(start, Some(start + self.active_len), suffix, depth);
+                    self.set_child(self.active_node, split);
+                    self.nodes[next].start += self.active_len;
+                    self.set_child(split, next);
+                    let leaf = self.new_node(pos, None, suffix, 0);
+                    self.set_child(split, leaf);
+                    if let Some(node) = last_new.replace(split) {
+                        self.nodes[node].link = split;
+                    }
+                },
+            }
+
+            self.remainder -= 1;
+            if self.active_node == 0 && self.active_len > 0 {
+                self.active_len -= 1;
+                self.active_edge = pos + 1 - self.remainder;
+            } else if self.active_node != 0 {
+                self.active_node = self.nodes[self.active_node].link;
+            }
+        }
+    }
+
+    /// Returns `true` if `pattern` is a substring of the slice.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(1)            |                  |
+    /// | Avrg:     | Θ(m log σ)      |                  |
+    /// | Worst:    | O(m log σ)      | O(1)             |
+    pub fn contains(&self, pattern: &[T]) -> bool { self.locate(pattern).is_some() }
+
+    /// Search for all the occurrences of `pattern` in the slice, including the ones that
+    /// overlap.
+    ///
+    /// It returns the indexes of the first character of each one, in crescent order.
+    ///
+    /// The occurrences are the leaves under where `pattern` ends in the tree, and the ones
+    /// in the suffixes that are not in a leaf, which are all suffixes of the longest one
+    /// of them, so they are searched there with [`KmpPattern`].
+    ///
+    /// [`KmpPattern`]: ../../pattern/struct.KmpPattern.html
+    ///
+    /// |   Case    |     Time complexity      | Space complexity |
+    /// |:----------|:------------------------:|:----------------:|
+    /// | Best:     | Ω(m)                     |                  |
+    /// | Avrg:     | Θ(m log σ + k log k + r) |                  |
+    /// | Worst:    | O(m log σ + k log k + r) | O(k + m)         |
+    ///
+    /// Where `k` is the number of occurrences and `r` the size of the longest suffix that
+    /// is not in a leaf.
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize> {
+        let node = match self.locate(pattern) {
+            Some(node) => node,
+            None => return Vec::new(),
+        };
+
+        let mut found = Vec::new();
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if node.end.is_none() {
+                found.push(node.suffix);
+            }
+            stack.extend(node.children.iter().map(|&(_, child)| child));
+        }
+        found.sort_unstable();
+
+        let implicit = self.text.len() - self.remainder;
+        let kmp = KmpPattern::new(pattern);
+        found.extend(kmp.find_all(&self.text[implicit..]).into_iter().map(|i| implicit + i));
+        found
+    }
+
+    /// Returns the range of one of the longest substrings that occur more than once in the
+    /// slice, the occurrences may overlap.
+    ///
+    /// It is the deepest node that is not a leaf, or the longest suffix that is not in a
+    /// leaf, which occurs before.
+    ///
+    /// |   Case    | Time complexity | Space complexity |
+    /// |:----------|:---------------:|:----------------:|
+    /// | Best:     | Ω(n)            |                  |
+    /// | Avrg:     | Θ(n)            |                  |
+    /// | Worst:    | O(n)            | O(1)             |
+    pub fn longest_repeated_substring(&self) -> Range<usize> {
+        let n = self.text.len();
+        let deepest = self.internal_nodes().max_by_key(InternalNode::depth);
+        match deepest {
+            Some(node) if node.depth() >= self.remainder => node.range,
+            _ => n - self.remainder..n,
+        }
+    }
+
+    /// Returns an iterator over the nodes of the tree that are not leaves, without the
+    /// root, in no particular order.
+    ///
+    /// Each one is a substring of the slice that is followed by at least two different
+    /// elements in the slice.
+    pub fn internal_nodes(&self) -> InternalNodes<'_, T> { InternalNodes { tree: self, next: 1 } }
+
+    /// Returns the node where the path of `pattern` ends, or the child of it if it ends in
+    /// an edge, if `pattern` is a substring of the slice.
+    fn locate(&self, pattern: &[T]) -> Option<usize> {
+        let (mut node, mut i) = (0, 0);
+        while i < pattern.len() {
+            node = self.child(node, &pattern[i])?;
+            let start = self.nodes[node].start;
+            let len = self.edge_len(node).min(pattern.len() - i);
+            if self.text[start..start + len] != pattern[i..i + len] {
+                return None;
+            }
+            i += len;
+        }
+        Some(node)
+    }
+
+    /// Returns the size of the edge from the parent of `node`.
+    fn edge_len(&self, node: usize) -> usize {
+        let node = &self.nodes[node];
+        node.end.unwrap_or(self.text.len()) - node.start
+    }
+
+    /// Returns the child of `node` with an edge that starts with `c`, if any.
+    fn child(&self, node: usize, c: &T) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        children.binary_search_by(|(x, _)| x.cmp(c)).ok().map(|i| children[i].1)
+    }
+
+    /// Sets `child` as the child of `node` with the first element of its edge.
+    fn set_child(&mut self, node: usize, child: usize) {
+        let c = self.text[self.nodes[child].start].clone();
+        let children = &mut self.nodes[node].children;
+        match children.binary_search_by(|(x, _)| x.cmp(&c)) {
+            Ok(i) => children[i].1 = child,
+            Err(i) => children.insert(i, (c, child)),
+        }
+    }
+
+    fn new_node(&mut self, start: usize, end: Option<usize>, suffix: usize, depth: usize) -> usize {
+        self.nodes.push(Node { start, end, suffix, depth, link: 0, children: Vec::new() });
+        self.nodes.len() - 1
+    }
+}
+
+/// A node of a [`SuffixTree`] that is not a leaf.
+///
+/// This `struct` is created by [`SuffixTree::internal_nodes`].
+///
+/// [`SuffixTree`]: ./struct.SuffixTree.html
+/// [`SuffixTree::internal_nodes`]: ./struct.SuffixTree.html#method.internal_nodes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InternalNode {
+    range:    Range<usize>,
+    children: usize,
+}
+
+impl InternalNode {
+    /// Returns the range of an occurrence of the path to the node in the slice.
+    pub fn range(&self) -> Range<usize> { self.range.clone() }
+
+    /// Returns the size of the path to the node, its string depth.
+    pub fn depth(&self) -> usize { self.range.len() }
+
+    /// Returns the number of children of the node, the number of different elements that
+    /// follow the path to the node in the slice.
+    pub fn children(&self) -> usize { self.children }
+}
+
+/// Iterator over the nodes of a [`SuffixTree`] that are not leaves.
+///
+/// This `struct` is created by [`SuffixTree::internal_nodes`].
+///
+/// [`SuffixTree`]: ./struct.SuffixTree.html
+/// [`SuffixTree::internal_nodes`]: ./struct.SuffixTree.html#method.internal_nodes
+#[derive(Debug, Clone)]
+pub struct InternalNodes<'a, T> {
+    tree: &'a SuffixTree<T>,
+    next: usize,
+}
+
+impl<'a, T> Iterator for InternalNodes<'a, T> {
+    type Item = InternalNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.tree.nodes.get(self.next) {
+            self.next += 1;
+            if node.end.is_some() {
+                let range = node.suffix..node.suffix + node.depth;
+                return Some(InternalNode { range, children: node.children.len() });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::{thread_rng, Rng};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn random_text(max: usize) -> Vec<u8> {
+        let mut rng = thread_rng();
+        (0..rng.gen_range(0..max)).map(|_| rng.gen_range(b'a'..b'd')).collect()
+    }
+
+    #[test]
+    fn find_all_test() {
+        let tree = SuffixTree::new(b"mississippi");
+        assert!(tree.contains(b""));
+        assert!(tree.contains(b"ssissi"));
+        assert!(!tree.contains(b"sss"));
+        assert_eq!(tree.find_all(b"ssi"), [2, 5]);
+        assert_eq!(tree.find_all(b"i"), [1, 4, 7, 10]);
+        assert_eq!(tree.find_all(b"x"), []);
+        assert_eq!(SuffixTree::new(b"aa").find_all(b""), [0, 1, 2]);
+        assert_eq!(SuffixTree::new(b"aaaa").find_all(b"aa"), [0, 1, 2]);
+
+        let mut rng = thread_rng();
+        for _ in 0..200 {
+            let text = random_text(200);
+            let find: Vec<u8> =
+                (0..rng.gen_range(1..6)).map(|_| rng.gen_range(b'a'..b'd')).collect();
+            let expected: Vec<usize> = text
+                .windows(find.len())
+                .enumerate()
+                .filter(|(_, w)| *w == &find[..])
+                .map(|(i, _)| i)
+                .collect();
+            let tree = SuffixTree::new(&text);
+            assert_eq!(tree.text(), &text[..]);
+            assert_eq!(tree.contains(&find), !expected.is_empty());
+            assert_eq!(tree.find_all(&find), expected);
+        }
+    }
+
+    #[test]
+    fn longest_repeated_substring_test() {
+        assert_eq!(SuffixTree::<u8>::new(b"").longest_repeated_substring(), 0..0);
+        assert_eq!(SuffixTree::new(b"abc").longest_repeated_substring().len(), 0);
+        assert_eq!(SuffixTree::new(b"aaaa").longest_repeated_substring(), 1..4);
+        let tree = SuffixTree::new(b"abcabd");
+        assert_eq!(&tree.text()[tree.longest_repeated_substring()], b"ab");
+
+        for _ in 0..200 {
+            let text = random_text(60);
+            let naive = (0..text.len())
+                .flat_map(|i| (i + 1..text.len()).map(move |j| (i, j)))
+                .map(|(i, j)| text[i..].iter().zip(&text[j..]).take_while(|(a, b)| a == b).count())
+                .max()
+                .unwrap_or(0);
+            let tree = SuffixTree::new(&text);
+            let range = tree.longest_repeated_substring();
+            assert_eq!(range.len(), naive);
+            assert!(tree.find_all(&text[range]).len() >= 2 || naive == 0);
+        }
+    }
+
+    #[test]
+    fn internal_nodes_test() {
+        for _ in 0..200 {
+            let text = random_text(60);
+            // Each substring with the different elements that follow it.
+            let mut followers: BTreeMap<&[u8], BTreeSet<u8>> = BTreeMap::new();
+            for i in 0..text.len() {
+                for j in i + 1..text.len() {
+                    followers.entry(&text[i..j]).or_default().insert(text[j]);
+                }
+            }
+            let expected: BTreeMap<&[u8], usize> = followers
+                .into_iter()
+                .filter(|(_, next)| next.len() >= 2)
+                .map(|(sub, next)| (sub, next.len()))
+                .collect();
+
+            let tree = SuffixTree::new(&text);
+            let nodes: BTreeMap<&[u8], usize> = tree
+                .internal_nodes()
+                .map(|node| (&text[node.range()], node.children()))
+                .collect();
+            assert_eq!(nodes, expected);
+        }
+    }
+}